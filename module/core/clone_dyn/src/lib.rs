@@ -37,25 +37,69 @@ pub( crate ) mod private
   #[ cfg( all( feature = "use_std", not( feature = "use_alloc" ) ) ) ]
   use std::boxed::Box;
 
+  /// Make a copy of the value on the heap and give pointer to the copy.
+  ///
+  /// Implemented for every type implementing [Clone]. Attribute `clone_dyn` adds the trait to supertraits of the trait it is applied to,
+  /// so copy of any trait object could be made even though size of the value behind the trait object is unknown at compile-time.
+  pub trait CloneDyn
+  {
+    /// Not intended to be used directly.
+    #[ doc( hidden ) ]
+    fn __clone_dyn( &self, _ : DontCallMe ) -> *mut ();
+  }
+
+  impl< T > CloneDyn for T
+  where
+    T : Clone,
+  {
+    #[ inline ]
+    fn __clone_dyn( &self, _ : DontCallMe ) -> *mut ()
+    {
+      Box::< T >::into_raw( Box::new( self.clone() ) ) as *mut ()
+    }
+  }
+
+  /// Token which makes method [CloneDyn::__clone_dyn] impossible to call outside of the crate.
+  #[ doc( hidden ) ]
+  #[ derive( Debug ) ]
+  pub struct DontCallMe( () );
+
   /// Clone boxed dyn.
   ///
   /// Not intended to be used directly.
   #[ inline ]
   pub fn _clone_boxed< T >( t : &T ) -> Box< T >
   where
-    T : ?Sized,
+    T : ?Sized + CloneDyn,
   {
+    let mut ptr = t as *const T;
+    let data_ptr = &mut ptr as *mut *const T as *mut *mut ();
+    // SAFETY : pointer to the copy replaces data part of the pointer keeping its metadata,
+    // the copy has the same type as the original so the metadata is valid for it too
     unsafe
     {
-      let mut ptr = t as *const T;
-      let data_ptr = &mut ptr as *mut *const T as *mut *mut ();
-      *data_ptr = Box::into_raw( Box::new( < &T >::clone( &t ) ) ) as *mut ();
+      *data_ptr = < T as CloneDyn >::__clone_dyn( t, DontCallMe( () ) );
       Box::from_raw( ptr as *mut T )
     }
   }
 
 }
 
+/// Pointers used by code generated by attribute `clone_dyn`, taken from `alloc` if `no_std`.
+///
+/// Not intended to be used directly.
+#[ doc( hidden ) ]
+#[ cfg( feature = "enabled" ) ]
+pub mod _pointer
+{
+  #[ cfg( feature = "no_std" ) ]
+  extern crate alloc;
+  #[ cfg( feature = "no_std" ) ]
+  pub use alloc::{ boxed::Box, rc::Rc, sync::Arc };
+  #[ cfg( not( feature = "no_std" ) ) ]
+  pub use std::{ boxed::Box, rc::Rc, sync::Arc };
+}
+
 /// Protected namespace of the module.
 #[ cfg( feature = "enabled" ) ]
 pub mod protected
@@ -98,5 +142,9 @@ pub mod prelude
   // #[ cfg( any( not( feature = "no_std" ), feature = "use_alloc" ) ) ]
   #[ doc( inline ) ]
   #[ allow( unused_imports ) ]
-  pub use super::private::_clone_boxed;
+  pub use super::private::
+  {
+    CloneDyn,
+    _clone_boxed,
+  };
 }
@@ -0,0 +1,10 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+#[ test_tools::nightly ]
+#[ test ]
+fn trybuild_tests()
+{
+  let t = test_tools::compiletime::TestCases::new();
+  t.compile_fail( "tests/inc/snipet/not_object_safe_fail.rs" );
+}
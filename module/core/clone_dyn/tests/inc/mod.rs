@@ -13,6 +13,8 @@ tests_impls!
   {
 
     trait Trait1
+    where
+      Self : TheModule::CloneDyn,
     {
     }
 
@@ -21,15 +23,9 @@ tests_impls!
     #[ inline ]
     pub fn _clone_boxed< T >( t : &T ) -> Box< T >
     where
-      T : ?Sized,
+      T : ?Sized + TheModule::CloneDyn,
     {
-      unsafe
-      {
-        let mut ptr = t as *const T;
-        let data_ptr = &mut ptr as *mut *const T as *mut *mut ();
-        *data_ptr = Box::into_raw( Box::new( t.clone() ) ) as *mut ();
-        Box::from_raw( ptr as *mut T )
-      }
+      TheModule::_clone_boxed( t )
     }
 
     //
@@ -141,6 +137,257 @@ tests_impls!
 
   }
 
+  //
+
+  fn values()
+  {
+    use TheModule::clone_dyn;
+
+    #[ clone_dyn ]
+    trait Trait1
+    {
+      fn val( &self ) -> String;
+    }
+
+    #[ derive( Clone ) ]
+    struct Text( String );
+    impl Trait1 for Text
+    {
+      fn val( &self ) -> String { self.0.clone() }
+    }
+
+    #[ derive( Clone ) ]
+    struct Numbers( Vec< i64 > );
+    impl Trait1 for Numbers
+    {
+      fn val( &self ) -> String { format!( "{:?}", self.0 ) }
+    }
+
+    let vec : Vec< Box< dyn Trait1 > > = vec![ Box::new( Text( "abc".to_string() ) ), Box::new( Numbers( vec![ 1, 2, 3 ] ) ) ];
+    let vec2 = vec.clone();
+    drop( vec );
+    let got : Vec< String > = vec2.iter().map( | e | e.val() ).collect();
+    a_id!( got, vec![ "abc".to_string(), "[1, 2, 3]".to_string() ] );
+
+    let src : Box< dyn Trait1 + Send + Sync > = Box::new( Text( "x".to_string() ) );
+    let got = src.clone();
+    a_id!( got.val(), "x".to_string() );
+
+    let src : Box< dyn Trait1 + Send > = Box::new( Text( "y".to_string() ) );
+    let got = src.clone();
+    a_id!( got.val(), "y".to_string() );
+
+  }
+
+  //
+
+  fn clone_box_rc_arc()
+  {
+    use TheModule::clone_dyn;
+    use std::rc::Rc;
+    use std::sync::Arc;
+    use std::cell::Cell;
+
+    #[ clone_dyn ]
+    trait Counter
+    {
+      fn get( &self ) -> i32;
+      fn inc( &self );
+    }
+
+    #[ derive( Clone ) ]
+    struct Cnt( Cell< i32 > );
+    impl Counter for Cnt
+    {
+      fn get( &self ) -> i32 { self.0.get() }
+      fn inc( &self ) { self.0.set( self.0.get() + 1 ) }
+    }
+
+    let src : Box< dyn Counter > = Box::new( Cnt( Cell::new( 1 ) ) );
+    let got = src.clone_box();
+    got.inc();
+    a_id!( src.get(), 1 );
+    a_id!( got.get(), 2 );
+
+    // `Rc::clone` shares the value, `clone_rc` copies it
+    let src : Rc< dyn Counter > = Rc::new( Cnt( Cell::new( 1 ) ) );
+    let shared = Rc::clone( &src );
+    let got = src.clone_rc();
+    got.inc();
+    shared.inc();
+    a_id!( src.get(), 2 );
+    a_id!( got.get(), 2 );
+    a_id!( Rc::strong_count( &src ), 2 );
+    a_id!( Rc::strong_count( &got ), 1 );
+
+    #[ clone_dyn ]
+    trait Named : Send + Sync
+    {
+      fn name( &self ) -> &str;
+    }
+
+    #[ derive( Clone ) ]
+    struct Name( String );
+    impl Named for Name
+    {
+      fn name( &self ) -> &str { &self.0 }
+    }
+
+    let src : Arc< dyn Named > = Arc::new( Name( "a".to_string() ) );
+    let got = src.clone_arc();
+    a_id!( got.name(), "a" );
+    a_id!( Arc::strong_count( &src ), 1 );
+    a_id!( Arc::strong_count( &got ), 1 );
+    let got = std::thread::spawn( move || got.name().to_string() ).join().unwrap();
+    a_id!( got, "a".to_string() );
+
+  }
+
+  //
+
+  fn supertraits()
+  {
+    use TheModule::clone_dyn;
+
+    #[ clone_dyn ]
+    trait Shape : std::fmt::Debug
+    {
+      fn area( &self ) -> f64;
+    }
+
+    #[ clone_dyn ]
+    trait Solid : Shape
+    {
+      fn volume( &self ) -> f64;
+    }
+
+    #[ derive( Clone, Debug ) ]
+    struct Cube( f64 );
+    impl Shape for Cube
+    {
+      fn area( &self ) -> f64 { 6.0 * self.0 * self.0 }
+    }
+    impl Solid for Cube
+    {
+      fn volume( &self ) -> f64 { self.0 * self.0 * self.0 }
+    }
+
+    let src : Box< dyn Solid > = Box::new( Cube( 2.0 ) );
+    let got = src.clone();
+    a_id!( got.area(), 24.0 );
+    a_id!( got.volume(), 8.0 );
+    a_id!( format!( "{:?}", got ), "Cube(2.0)".to_string() );
+
+  }
+
+  //
+
+  fn generic_methods()
+  {
+    use TheModule::clone_dyn;
+
+    #[ clone_dyn ]
+    trait Visitor
+    {
+      fn visit( &mut self, val : i32 );
+      fn total( &self ) -> i32;
+      fn visit_all< I >( &mut self, iter : I )
+      where
+        Self : Sized,
+        I : IntoIterator< Item = i32 >,
+      {
+        for val in iter
+        {
+          self.visit( val );
+        }
+      }
+      fn new() -> Self
+      where
+        Self : Sized;
+    }
+
+    #[ derive( Clone ) ]
+    struct Sum( i32 );
+    impl Visitor for Sum
+    {
+      fn visit( &mut self, val : i32 ) { self.0 += val }
+      fn total( &self ) -> i32 { self.0 }
+      fn new() -> Self { Self( 0 ) }
+    }
+
+    let mut sum = Sum::new();
+    sum.visit_all( [ 1, 2, 3 ] );
+    let src : Box< dyn Visitor > = Box::new( sum );
+    let mut got = src.clone();
+    got.visit( 4 );
+    a_id!( src.total(), 6 );
+    a_id!( got.total(), 10 );
+
+  }
+
+  //
+
+  fn associated_types_and_lifetimes()
+  {
+    use TheModule::clone_dyn;
+
+    #[ clone_dyn ]
+    trait Source< 'a, T : Copy >
+    {
+      type Out : std::fmt::Debug;
+      fn src( &self ) -> &'a str;
+      fn out( &self, t : T ) -> Self::Out;
+    }
+
+    #[ derive( Clone ) ]
+    struct Slice< 'a >( &'a str );
+    impl< 'a > Source< 'a, usize > for Slice< 'a >
+    {
+      type Out = String;
+      fn src( &self ) -> &'a str { self.0 }
+      fn out( &self, t : usize ) -> String { self.0[ .. t ].to_string() }
+    }
+
+    let text = String::from( "abcdef" );
+    let src : Box< dyn Source< '_, usize, Out = String > > = Box::new( Slice( &text ) );
+    let got = src.clone();
+    a_id!( got.src(), "abcdef" );
+    a_id!( got.out( 2 ), "ab".to_string() );
+    let got = got.clone_rc();
+    a_id!( got.out( 3 ), "abc".to_string() );
+
+  }
+
+  //
+
+  fn lifetime_c_and_crate_path()
+  {
+
+    mod facade
+    {
+      pub use ::clone_dyn as tools;
+    }
+
+    #[ TheModule::clone_dyn( crate = facade::tools ) ]
+    trait Named< 'c >
+    {
+      fn name( &self ) -> &'c str;
+    }
+
+    #[ derive( Clone ) ]
+    struct Name< 'c >( &'c str );
+    impl< 'c > Named< 'c > for Name< 'c >
+    {
+      fn name( &self ) -> &'c str { self.0 }
+    }
+
+    let src : Box< dyn Named< '_ > > = Box::new( Name( "a" ) );
+    let got = src.clone();
+    a_id!( got.name(), "a" );
+    a_id!( got.clone_arc().name(), "a" );
+
+  }
+
 }
 
 //
@@ -152,4 +399,12 @@ tests_index!
   prelude,
   parametrized,
   sample,
+  values,
+  clone_box_rc_arc,
+  supertraits,
+  generic_methods,
+  associated_types_and_lifetimes,
+  lifetime_c_and_crate_path,
 }
+
+mod compiletime_test;
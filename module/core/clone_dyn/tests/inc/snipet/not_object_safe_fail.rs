@@ -0,0 +1,11 @@
+use clone_dyn::clone_dyn;
+
+#[ clone_dyn ]
+trait Trait1
+{
+  fn make< T : Default >( &self ) -> T;
+}
+
+fn main()
+{
+}
@@ -19,14 +19,21 @@
 mod meta_impl;
 
 ///
-/// Derive macro to generate former for a structure. Former is variation of Builder Pattern.
+/// Attribute to make trait objects of the trait clonable.
+///
+/// Implements `Clone` for `Box< dyn Trait >`, including its `Send` and `Sync` variants, and adds methods `clone_box`,
+/// `clone_rc`, `clone_arc` to the trait object to make its deep copy. Parameters, lifetimes, supertraits and associated types
+/// of the trait are supported. If the trait is not object safe, an error explains why.
+///
+/// Path to crate `clone_dyn` used by generated code could be given by option `crate`, for example `#[ clone_dyn( crate = my_facade::clone_dyn ) ]`,
+/// by default `::clone_dyn`.
 ///
 
 #[ cfg( feature = "enabled" ) ]
 #[ proc_macro_attribute ]
-pub fn clone_dyn( _attr : proc_macro::TokenStream, item : proc_macro::TokenStream ) -> proc_macro::TokenStream
+pub fn clone_dyn( attr : proc_macro::TokenStream, item : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
-  let result = meta_impl::clone_dyn( _attr, item );
+  let result = meta_impl::clone_dyn( attr, item );
   match result
   {
     Ok( stream ) => stream.into(),
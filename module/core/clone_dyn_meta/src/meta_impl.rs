@@ -1,66 +1,266 @@
 
 use macro_tools::prelude::*;
+use macro_tools::quote::ToTokens;
 pub type Result< T > = std::result::Result< T, syn::Error >;
 
 //
 
-pub fn clone_dyn( _attr : proc_macro::TokenStream, item : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+pub fn clone_dyn( attr : proc_macro::TokenStream, item : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
 {
 
-  let item_parsed = match syn::parse::< syn::ItemTrait >( item )
+  let krate = syn::parse::Parser::parse( crate_path, attr )?;
+
+  let mut item_parsed = match syn::parse::< syn::ItemTrait >( item )
   {
     Ok( original ) => original,
     Err( err ) => return Err( err ),
   };
 
+  object_safety_check( &item_parsed )?;
+
+  // cloning of trait object is possible only if the trait requires `CloneDyn`
+  item_parsed.colon_token.get_or_insert_with( Default::default );
+  item_parsed.supertraits.push( parse_qt!{ #krate::CloneDyn } );
+
   let name_ident = &item_parsed.ident;
-  // let generics = &item_parsed.generics;
-  let generics_analyzed = item_parsed.generics_analyze();
-  let generics_params = &generics_analyzed.generics.params;
-  let generics_where = &generics_analyzed.generics.where_clause;
-  let generics_names = &generics_analyzed.names;
 
-  let result = qt!
+  // `dyn Trait< 'a, T, Assoc = __Assoc >` : parameters of the trait are passed through,
+  // each associated type is fixed by a parameter of the impl having the same bounds
+  let mut generics = item_parsed.generics.clone();
+  generics.params.insert( 0, parse_qt!{ '__clone_dyn } );
+  let mut args : Vec< proc_macro2::TokenStream > = item_parsed.generics.params.iter().map( | param | match param
   {
-    #item_parsed
-
-    impl < 'c, #generics_params > Clone
-    for Box< dyn #name_ident< #( #generics_names ),* > + 'c >
-    // where
-      #generics_where
+    syn::GenericParam::Type( type_param ) => type_param.ident.to_token_stream(),
+    syn::GenericParam::Lifetime( lifetime_def ) => lifetime_def.lifetime.to_token_stream(),
+    syn::GenericParam::Const( const_param ) => const_param.ident.to_token_stream(),
+  }).collect();
+  for item in &item_parsed.items
+  {
+    if let syn::TraitItem::Type( assoc ) = item
     {
-      #[ inline ]
-      fn clone( &self ) -> Self { clone_dyn::_clone_boxed( &**self ) }
+      let ident = &assoc.ident;
+      let param = macro_tools::format_ident!( "__{}", ident );
+      let bounds = &assoc.bounds;
+      generics.params.push( parse_qt!{ #param : #bounds } );
+      args.push( qt!{ #ident = #param } );
     }
+  }
+  let args = if args.is_empty() { qt!{} } else { qt!{ < #( #args ),* > } };
+  let ( impl_generics, _, where_clause ) = generics.split_for_impl();
 
-    impl < 'c, #generics_params > Clone
-    for Box< dyn #name_ident< #( #generics_names ),* > + Send + 'c >
-    // where
-      #generics_where
+  let impls = [ qt!{}, qt!{ + Send }, qt!{ + Sync }, qt!{ + Send + Sync } ].into_iter().map( | markers |
+  {
+    let object = qt!{ dyn #name_ident #args #markers + '__clone_dyn };
+    qt!
     {
-      #[ inline ]
-      fn clone( &self ) -> Self { clone_dyn::_clone_boxed( &**self ) }
+
+      impl #impl_generics ::core::clone::Clone
+      for #krate::_pointer::Box< #object >
+      #where_clause
+      {
+        #[ inline ]
+        fn clone( &self ) -> Self { #krate::_clone_boxed( &**self ) }
+      }
+
+      impl #impl_generics #object
+      #where_clause
+      {
+
+        /// Make a deep copy of the trait object on the heap.
+        #[ inline ]
+        #[ allow( dead_code ) ]
+        pub fn clone_box( &self ) -> #krate::_pointer::Box< Self >
+        {
+          #krate::_clone_boxed( self )
+        }
+
+        /// Make a deep copy of the trait object owned by a new `Rc`. Unlike `Rc::clone`, the value itself is copied.
+        #[ inline ]
+        #[ allow( dead_code ) ]
+        pub fn clone_rc( &self ) -> #krate::_pointer::Rc< Self >
+        {
+          #krate::_pointer::Rc::from( #krate::_clone_boxed( self ) )
+        }
+
+        /// Make a deep copy of the trait object owned by a new `Arc`. Unlike `Arc::clone`, the value itself is copied.
+        #[ inline ]
+        #[ allow( dead_code ) ]
+        pub fn clone_arc( &self ) -> #krate::_pointer::Arc< Self >
+        {
+          #krate::_pointer::Arc::from( #krate::_clone_boxed( self ) )
+        }
+
+      }
+
     }
+  });
 
-    impl < 'c, #generics_params > Clone
-    for Box< dyn #name_ident< #( #generics_names ),* > + Sync + 'c >
-    // where
-      #generics_where
+  let result = qt!
+  {
+    #item_parsed
+    #( #impls )*
+  };
+
+  Ok( result )
+}
+
+///
+/// Path to crate `clone_dyn` used by generated code.
+///
+/// Given by option `crate` of the attribute, for example `#[ clone_dyn( crate = my_facade::clone_dyn ) ]`, by default `::clone_dyn`.
+///
+
+fn crate_path( input : syn::parse::ParseStream< '_ > ) -> Result< syn::Path >
+{
+  let result = macro_tools::crate_option( input )?;
+  if !input.is_empty() && result.is_none()
+  {
+    return Err( input.error( "Unknown option of attribute `clone_dyn`, expects `crate = path`" ) );
+  }
+  input.parse::< Option< syn::Token![ , ] > >()?;
+  Ok( result.unwrap_or_else( || macro_tools::crate_path_default( "clone_dyn" ) ) )
+}
+
+///
+/// Make sure trait object of the trait could exist, otherwise explain why it could not.
+///
+
+fn object_safety_check( item : &syn::ItemTrait ) -> Result< () >
+{
+  let name = &item.ident;
+  let error = | span : &dyn ToTokens, reason : String |
+  {
+    syn_err!( span, "Trait `{}` is not object safe, so `clone_dyn` could not be applied to it : {}", name, reason )
+  };
+
+  let sized = | bounds : &syn::punctuated::Punctuated< syn::TypeParamBound, syn::Token![ + ] > |
+  {
+    bounds.iter().any( | bound | matches!( bound, syn::TypeParamBound::Trait( bound ) if bound.path.is_ident( "Sized" ) ) )
+  };
+
+  if sized( &item.supertraits ) || where_self_sized( &item.generics.where_clause )
+  {
+    return Err( error( &item.ident, "it requires `Self : Sized`".to_string() ) );
+  }
+
+  for trait_item in &item.items
+  {
+    match trait_item
     {
-      #[ inline ]
-      fn clone( &self ) -> Self { clone_dyn::_clone_boxed( &**self ) }
+      syn::TraitItem::Const( constant ) =>
+      {
+        return Err( error( &constant.ident, format!( "it has associated constant `{}`", constant.ident ) ) );
+      },
+      syn::TraitItem::Type( assoc ) if !assoc.generics.params.is_empty() =>
+      {
+        return Err( error( &assoc.ident, format!( "associated type `{}` has generic parameters", assoc.ident ) ) );
+      },
+      syn::TraitItem::Method( method ) =>
+      {
+        // methods bounded by `Self : Sized` are not part of trait object
+        if where_self_sized( &method.sig.generics.where_clause )
+        {
+          continue;
+        }
+        let sig = &method.sig;
+        let hint = "add `where Self : Sized` to exclude it from trait object";
+        if sig.asyncness.is_some()
+        {
+          return Err( error( sig, format!( "method `{}` is async, {}", sig.ident, hint ) ) );
+        }
+        if let Some( param ) = sig.generics.params.iter().find( | param | !matches!( param, syn::GenericParam::Lifetime( _ ) ) )
+        {
+          return Err( error( param, format!( "method `{}` has generic parameters, {}", sig.ident, hint ) ) );
+        }
+        if sig.receiver().is_none()
+        {
+          return Err( error( sig, format!( "method `{}` has no receiver `self`, {}", sig.ident, hint ) ) );
+        }
+        let mut types : Vec< &syn::Type > = sig.inputs.iter().filter_map( | input | match input
+        {
+          syn::FnArg::Typed( typed ) if !matches!( &*typed.pat, syn::Pat::Ident( pat ) if pat.ident == "self" ) => Some( &*typed.ty ),
+          _ => None,
+        }).collect();
+        if let syn::ReturnType::Type( _, ty ) = &sig.output
+        {
+          types.push( ty );
+        }
+        for ty in types
+        {
+          if type_has_self( ty )
+          {
+            return Err( error( ty, format!( "method `{}` refers to `Self` in its parameters or return type, {}", sig.ident, hint ) ) );
+          }
+          if type_has_impl( ty )
+          {
+            return Err( error( ty, format!( "method `{}` uses `impl Trait`, {}", sig.ident, hint ) ) );
+          }
+        }
+      },
+      _ => {},
     }
+  }
+
+  Ok( () )
+}
+
+/// Does where clause have predicate `Self : Sized`.
+fn where_self_sized( where_clause : &Option< syn::WhereClause > ) -> bool
+{
+  let where_clause = match where_clause
+  {
+    Some( where_clause ) => where_clause,
+    None => return false,
+  };
+  where_clause.predicates.iter().any( | predicate | match predicate
+  {
+    syn::WherePredicate::Type( predicate ) =>
+    {
+      matches!( &predicate.bounded_ty, syn::Type::Path( path ) if path.qself.is_none() && path.path.is_ident( "Self" ) )
+      && predicate.bounds.iter().any( | bound | matches!( bound, syn::TypeParamBound::Trait( bound ) if bound.path.is_ident( "Sized" ) ) )
+    },
+    _ => false,
+  })
+}
+
+/// Does type refer to `Self` anywhere inside, except paths to associated types like `Self::Item`.
+fn type_has_self( ty : &syn::Type ) -> bool
+{
+  stream_has_self( ty.to_token_stream() )
+}
 
-    impl < 'c, #generics_params > Clone
-    for Box< dyn #name_ident< #( #generics_names ),* > + Send + Sync + 'c >
-    // where
-      #generics_where
+fn stream_has_self( stream : proc_macro2::TokenStream ) -> bool
+{
+  let mut tokens = stream.into_iter().peekable();
+  while let Some( token ) = tokens.next()
+  {
+    let found = match &token
+    {
+      proc_macro2::TokenTree::Ident( ident ) if ident == "Self" =>
+        !matches!( tokens.peek(), Some( proc_macro2::TokenTree::Punct( punct ) ) if punct.as_char() == ':' ),
+      proc_macro2::TokenTree::Group( group ) => stream_has_self( group.stream() ),
+      _ => false,
+    };
+    if found
     {
-      #[ inline ]
-      fn clone( &self ) -> Self { clone_dyn::_clone_boxed( &**self ) }
+      return true;
     }
+  }
+  false
+}
 
-  };
+/// Does type have `impl Trait` anywhere inside.
+fn type_has_impl( ty : &syn::Type ) -> bool
+{
+  stream_has_impl( ty.to_token_stream() )
+}
 
-  Ok( result )
+fn stream_has_impl( stream : proc_macro2::TokenStream ) -> bool
+{
+  stream.into_iter().any( | token | match token
+  {
+    proc_macro2::TokenTree::Ident( ident ) => ident == "impl",
+    proc_macro2::TokenTree::Group( group ) => stream_has_impl( group.stream() ),
+    _ => false,
+  })
 }
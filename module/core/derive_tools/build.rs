@@ -23,7 +23,12 @@ fn main()
         feature = "derive_from",
         feature = "derive_inner_from",
         feature = "derive_variadic_from",
-        feature = "derive_reflect"
+        feature = "derive_reflect",
+        feature = "derive_display",
+        feature = "derive_from_str",
+        feature = "derive_is_variant",
+        feature = "derive_unwrap",
+        feature = "derive_try_into"
       )
     },
     any_derive :
@@ -37,7 +42,12 @@ fn main()
         feature = "derive_from",
         feature = "derive_inner_from",
         feature = "derive_variadic_from",
-        feature = "derive_reflect"
+        feature = "derive_reflect",
+        feature = "derive_display",
+        feature = "derive_from_str",
+        feature = "derive_is_variant",
+        feature = "derive_unwrap",
+        feature = "derive_try_into"
       )
     },
   }
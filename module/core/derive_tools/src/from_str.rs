@@ -0,0 +1,82 @@
+//!
+//! Support of derive `FromStr`.
+//!
+
+/// Internal namespace.
+pub( crate ) mod private
+{
+
+  ///
+  /// Error of parsing text representation by derived `FromStr`.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct ParseError
+  {
+    /// Name of the type being parsed.
+    pub type_name : &'static str,
+    /// Why the text does not match format of the type.
+    pub reason : String,
+  }
+
+  impl ParseError
+  {
+    /// Constructor.
+    pub fn new( type_name : &'static str, reason : impl Into< String > ) -> Self
+    {
+      Self { type_name, reason : reason.into() }
+    }
+  }
+
+  impl core::fmt::Display for ParseError
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      write!( f, "Failed to parse {} : {}", self.type_name, self.reason )
+    }
+  }
+
+  impl std::error::Error for ParseError {}
+
+}
+
+#[ doc( inline ) ]
+#[ allow( unused_imports ) ]
+pub use protected::*;
+
+/// Protected namespace of the module.
+pub mod protected
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::orphan::*;
+}
+
+/// Orphan namespace of the module.
+pub mod orphan
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::exposed::*;
+  pub use super::private::
+  {
+    ParseError,
+  };
+}
+
+/// Exposed namespace of the module.
+pub mod exposed
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::prelude::*;
+}
+
+#[ doc( inline ) ]
+#[ allow( unused_imports ) ]
+pub use exposed::*;
+
+/// Prelude to use essentials: `use my_module::prelude::*`.
+pub mod prelude
+{
+}
@@ -18,6 +18,9 @@ pub mod wtools;
 #[ cfg( feature = "derive_reflect" ) ]
 pub mod reflect;
 
+#[ cfg( feature = "derive_from_str" ) ]
+pub mod from_str;
+
 // use derive_tools_meta::Deref;
 // use derive_tools_meta::VariadicFrom;
 
@@ -29,8 +32,6 @@ pub mod dependency
   pub use ::derive_more;
   #[ cfg( feature = "strum" ) ]
   pub use ::strum;
-  #[ cfg( feature = "parse_display" ) ]
-  pub use ::parse_display;
   #[ cfg( feature = "clone_dyn" ) ]
   pub use ::clone_dyn;
   #[ cfg( feature = "clone_dyn" ) ]
@@ -62,6 +63,11 @@ pub mod protected
   #[ doc( inline ) ]
   #[ allow( unused_imports ) ]
   pub use super::reflect::orphan::*;
+  // Explicitly, since glob of `strum` has its own `ParseError`.
+  #[ cfg( feature = "derive_from_str" ) ]
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::from_str::orphan::ParseError as FromStrParseError;
 }
 
 /// Orphan namespace of the module.
@@ -95,12 +101,12 @@ pub mod exposed
   #[ cfg( feature = "derive_display" ) ]
   #[ doc( inline ) ]
   #[ allow( unused_imports ) ]
-  pub use ::parse_display::Display;
+  pub use ::derive_tools_meta::Display;
 
   #[ cfg( feature = "derive_from_str" ) ]
   #[ doc( inline ) ]
   #[ allow( unused_imports ) ]
-  pub use ::parse_display::FromStr;
+  pub use ::derive_tools_meta::FromStr;
 
   #[ cfg( feature = "derive_clone_dyn" ) ]
   #[ doc( inline ) ]
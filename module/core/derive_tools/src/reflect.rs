@@ -65,12 +65,14 @@ pub( crate ) mod private
   /// let bytes = Primitive::binary(&[0xde, 0xad, 0xbe, 0xef]);
   /// ```
   #[ allow( non_camel_case_types ) ]
-  #[ derive( Debug, PartialEq, Default ) ]
+  #[ derive( Debug, Clone, PartialEq, PartialOrd, Default ) ]
   pub enum Primitive
   {
     /// None
     #[ default ]
     None,
+    /// Represents a boolean.
+    bool( bool ),
     /// Represents a signed 8-bit integer.
     i8( i8 ),
     /// Represents a signed 16-bit integer.
@@ -103,22 +105,39 @@ pub( crate ) mod private
     binary( &'static [ u8 ] ),
   }
 
-  #[ allow( non_camel_case_types ) ]
-  #[ derive( Debug, PartialEq ) ]
-  pub enum Data< const N : usize = 0 >
+  impl Primitive
   {
-    /// None
-    Primitive( Primitive ),
-    // /// Array
-    // array( &'a [ Data ; N ] ),
-  }
 
-  impl< const N : usize > Default for Data< N >
-  {
-    fn default() -> Self
+    /// Returns string slice if the primitive is a string.
+    pub fn as_str( &self ) -> Option< &str >
+    {
+      match self
+      {
+        Primitive::String( src ) => Some( src.as_str() ),
+        Primitive::str( src ) => Some( src ),
+        _ => None,
+      }
+    }
+
+    /// Returns index if the primitive is a non-negative integer.
+    pub fn as_usize( &self ) -> Option< usize >
     {
-      Data::Primitive( Primitive::None )
+      match *self
+      {
+        Primitive::i8( src ) => usize::try_from( src ).ok(),
+        Primitive::i16( src ) => usize::try_from( src ).ok(),
+        Primitive::i32( src ) => usize::try_from( src ).ok(),
+        Primitive::i64( src ) => usize::try_from( src ).ok(),
+        Primitive::isize( src ) => usize::try_from( src ).ok(),
+        Primitive::u8( src ) => Some( src as usize ),
+        Primitive::u16( src ) => Some( src as usize ),
+        Primitive::u32( src ) => usize::try_from( src ).ok(),
+        Primitive::u64( src ) => usize::try_from( src ).ok(),
+        Primitive::usize( src ) => Some( src ),
+        _ => None,
+      }
     }
+
   }
 
   ///
@@ -141,19 +160,6 @@ pub( crate ) mod private
     fn Reflect() -> Self::Entity;
   }
 
-  impl< T > Instance for T
-  where
-    EntityDescriptor< T > : Entity,
-    T : InstanceMarker,
-  {
-    type Entity = EntityDescriptor::< Self >;
-    #[ inline( always ) ]
-    fn Reflect() -> Self::Entity
-    {
-      EntityDescriptor::< Self >::new()
-    }
-  }
-
   // /// xxx
   // pub trait AnyInstance : core::any::Any + Instance {}
   // impl< T : core::any::Any + Instance > AnyInstance for T {}
@@ -178,12 +184,13 @@ pub( crate ) mod private
     }
   }
 
-  /// Auto-implement descriptor for this type.
+  /// Marker of scalar types, `EntityDescriptor` of a marked type is implemented as an entity which is not a container.
+  /// It doesn't implement `Instance`, each marked type implements it on its own.
   pub trait InstanceMarker {}
 
   impl< T > Entity for EntityDescriptor< T >
   where
-    T : InstanceMarker + 'static,
+    T : InstanceMarker + Instance + 'static,
   {
     #[ inline( always ) ]
     fn type_name( &self ) -> &'static str
@@ -320,35 +327,474 @@ pub( crate ) mod private
     }
   }
 
-  impl InstanceMarker for i8 {}
-  impl InstanceMarker for i16 {}
-  impl InstanceMarker for i32 {}
-  impl InstanceMarker for i64 {}
-  impl InstanceMarker for u8 {}
-  impl InstanceMarker for u16 {}
-  impl InstanceMarker for u32 {}
-  impl InstanceMarker for u64 {}
-  impl InstanceMarker for f32 {}
-  impl InstanceMarker for f64 {}
-  impl InstanceMarker for String {}
-  impl InstanceMarker for &'static str {}
+  ///
+  /// Error of access to a value of an instance through reflection.
+  ///
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub enum ReflectError
+  {
+    /// Path could not be parsed.
+    BadPath( String ),
+    /// Serialized tree could not be parsed.
+    BadFormat( String ),
+    /// There is no element with such key.
+    NoElement( Primitive ),
+    /// Value is not an enum, the enum has no variant with such name or fields of the variant have no default value.
+    NoVariant( String ),
+    /// Primitive could not be assigned to a value of the type.
+    TypeMismatch
+    {
+      /// Name of type of the value.
+      expected : &'static str,
+      /// Primitive which was attempted to assign.
+      got : Primitive,
+    },
+  }
+
+  impl core::fmt::Display for ReflectError
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      match self
+      {
+        ReflectError::BadPath( path ) => write!( f, "Bad path : {}", path ),
+        ReflectError::BadFormat( msg ) => write!( f, "Bad format : {}", msg ),
+        ReflectError::NoElement( key ) => write!( f, "No element {:?}", key ),
+        ReflectError::NoVariant( name ) => write!( f, "Can't switch to variant {}", name ),
+        ReflectError::TypeMismatch { expected, got } => write!( f, "Can't assign {:?} to {}", got, expected ),
+      }
+    }
+  }
+
+  impl std::error::Error for ReflectError {}
+
+  ///
+  /// Access to data of an instance through reflection.
+  ///
+  /// While `Entity` describes a type, `InstanceValue` reads and writes an actual instance.
+  /// Scalars are read and written as `Primitive`, containers give access to their elements by keys.
+  /// Keys are the same as keys of `KeyVal` of `Entity::elements`.
+  ///
+  pub trait InstanceValue : core::any::Any
+  {
+
+    /// Return the value as `Any` to downcast it to concrete type.
+    fn as_any( &self ) -> &dyn core::any::Any;
+
+    /// Return the value as mutable `Any` to downcast it to concrete type.
+    fn as_any_mut( &mut self ) -> &mut dyn core::any::Any;
+
+    /// Return the value as primitive if the value is a scalar.
+    #[ inline( always ) ]
+    fn primitive( &self ) -> Option< Primitive >
+    {
+      None
+    }
+
+    /// Assign primitive to the value if the value is a scalar of compatible type.
+    #[ inline( always ) ]
+    fn set_primitive( &mut self, src : Primitive ) -> Result< (), ReflectError >
+    {
+      Err( ReflectError::TypeMismatch { expected : core::any::type_name::< Self >(), got : src } )
+    }
+
+    /// Return element of the value by key.
+    #[ inline( always ) ]
+    fn element( &self, _key : &Primitive ) -> Option< &dyn InstanceValue >
+    {
+      None
+    }
+
+    /// Return mutable element of the value by key.
+    #[ inline( always ) ]
+    fn element_mut( &mut self, _key : &Primitive ) -> Option< &mut dyn InstanceValue >
+    {
+      None
+    }
+
+    /// Are elements of the value keyed by name, like fields of a structure or entries of a map, rather than by index.
+    #[ inline( always ) ]
+    fn is_keyed( &self ) -> bool
+    {
+      false
+    }
+
+    /// Return name of the current variant if the value is an enum.
+    #[ inline( always ) ]
+    fn variant( &self ) -> Option< &'static str >
+    {
+      None
+    }
+
+    /// Switch the value to the variant with default values of its fields if the value is an enum.
+    #[ inline( always ) ]
+    fn set_variant( &mut self, name : &str ) -> Result< (), ReflectError >
+    {
+      Err( ReflectError::NoVariant( name.to_string() ) )
+    }
+
+    /// Iterate keys and values of elements of the value.
+    #[ inline( always ) ]
+    fn values( &self ) -> Box< dyn Iterator< Item = ( Primitive, &dyn InstanceValue ) > + '_ >
+    {
+      Box::new( core::iter::empty() )
+    }
+
+    /// Return element by path, for example `a.b[3].c`.
+    fn get( &self, path : &str ) -> Result< &dyn InstanceValue, ReflectError >
+    {
+      let mut keys = path_parse( path )?.into_iter();
+      let key = keys.next().ok_or_else( || ReflectError::BadPath( path.to_string() ) )?;
+      let mut current = self.element( &key ).ok_or( ReflectError::NoElement( key ) )?;
+      for key in keys
+      {
+        current = current.element( &key ).ok_or( ReflectError::NoElement( key ) )?;
+      }
+      Ok( current )
+    }
+
+    /// Return mutable element by path, for example `a.b[3].c`.
+    fn get_mut( &mut self, path : &str ) -> Result< &mut dyn InstanceValue, ReflectError >
+    {
+      let mut keys = path_parse( path )?.into_iter();
+      let key = keys.next().ok_or_else( || ReflectError::BadPath( path.to_string() ) )?;
+      let mut current = match self.element_mut( &key )
+      {
+        Some( element ) => element,
+        None => return Err( ReflectError::NoElement( key ) ),
+      };
+      for key in keys
+      {
+        current = match current.element_mut( &key )
+        {
+          Some( element ) => element,
+          None => return Err( ReflectError::NoElement( key ) ),
+        };
+      }
+      Ok( current )
+    }
+
+    /// Assign primitive to a scalar element by path, for example `a.b[3].c`.
+    fn set( &mut self, path : &str, src : Primitive ) -> Result< (), ReflectError >
+    {
+      self.get_mut( path )?.set_primitive( src )
+    }
+
+  }
+
+  impl dyn InstanceValue
+  {
+
+    /// Downcast the value to concrete type.
+    pub fn downcast_ref< T : core::any::Any >( &self ) -> Option< &T >
+    {
+      self.as_any().downcast_ref::< T >()
+    }
+
+    /// Downcast the value to mutable reference on concrete type.
+    pub fn downcast_mut< T : core::any::Any >( &mut self ) -> Option< &mut T >
+    {
+      self.as_any_mut().downcast_mut::< T >()
+    }
+
+  }
+
+  ///
+  /// Parse path to a value into list of keys.
+  ///
+  /// Names of fields are separated by `.`, indices are enclosed in brackets : `a.b[3].c`.
+  ///
+  pub fn path_parse( path : &str ) -> Result< Vec< Primitive >, ReflectError >
+  {
+    let bad = || ReflectError::BadPath( path.to_string() );
+    let mut result = Vec::new();
+    if path.is_empty()
+    {
+      return Ok( result );
+    }
+    for segment in path.split( '.' )
+    {
+      let ( name, mut rest ) = match segment.find( '[' )
+      {
+        Some( i ) => ( &segment[ .. i ], &segment[ i .. ] ),
+        None => ( segment, "" ),
+      };
+      if !name.is_empty()
+      {
+        result.push( Primitive::String( name.to_string() ) );
+      }
+      else if rest.is_empty() || !result.is_empty()
+      {
+        return Err( bad() );
+      }
+      while !rest.is_empty()
+      {
+        let end = rest.find( ']' ).ok_or_else( bad )?;
+        if !rest.starts_with( '[' )
+        {
+          return Err( bad() );
+        }
+        let index = rest[ 1 .. end ].trim().parse::< usize >().map_err( | _ | bad() )?;
+        result.push( Primitive::usize( index ) );
+        rest = &rest[ end + 1 .. ];
+      }
+    }
+    Ok( result )
+  }
+
+  macro_rules! instance_value_integer
+  {
+    ( $( $Type : ident ),* $(,)? ) =>
+    {
+      $(
+        impl InstanceValue for $Type
+        {
+          #[ inline( always ) ]
+          fn as_any( &self ) -> &dyn core::any::Any
+          {
+            self
+          }
+          #[ inline( always ) ]
+          fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+          {
+            self
+          }
+          #[ inline( always ) ]
+          fn primitive( &self ) -> Option< Primitive >
+          {
+            Some( Primitive::$Type( *self ) )
+          }
+          fn set_primitive( &mut self, src : Primitive ) -> Result< (), ReflectError >
+          {
+            let value = match src
+            {
+              Primitive::i8( src ) => $Type::try_from( src ).ok(),
+              Primitive::i16( src ) => $Type::try_from( src ).ok(),
+              Primitive::i32( src ) => $Type::try_from( src ).ok(),
+              Primitive::i64( src ) => $Type::try_from( src ).ok(),
+              Primitive::isize( src ) => $Type::try_from( src ).ok(),
+              Primitive::u8( src ) => $Type::try_from( src ).ok(),
+              Primitive::u16( src ) => $Type::try_from( src ).ok(),
+              Primitive::u32( src ) => $Type::try_from( src ).ok(),
+              Primitive::u64( src ) => $Type::try_from( src ).ok(),
+              Primitive::usize( src ) => $Type::try_from( src ).ok(),
+              _ => None,
+            };
+            match value
+            {
+              Some( value ) => { *self = value; Ok( () ) },
+              None => Err( ReflectError::TypeMismatch { expected : stringify!( $Type ), got : src } ),
+            }
+          }
+        }
+      )*
+    };
+  }
+
+  instance_value_integer!( i8, i16, i32, i64, isize, u8, u16, u32, u64, usize );
+
+  macro_rules! instance_value_float
+  {
+    ( $( $Type : ident ),* $(,)? ) =>
+    {
+      $(
+        impl InstanceValue for $Type
+        {
+          #[ inline( always ) ]
+          fn as_any( &self ) -> &dyn core::any::Any
+          {
+            self
+          }
+          #[ inline( always ) ]
+          fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+          {
+            self
+          }
+          #[ inline( always ) ]
+          fn primitive( &self ) -> Option< Primitive >
+          {
+            Some( Primitive::$Type( *self ) )
+          }
+          fn set_primitive( &mut self, src : Primitive ) -> Result< (), ReflectError >
+          {
+            let value = match src
+            {
+              Primitive::f32( src ) => Some( src as $Type ),
+              Primitive::f64( src ) => Some( src as $Type ),
+              Primitive::i8( src ) => Some( src as $Type ),
+              Primitive::i16( src ) => Some( src as $Type ),
+              Primitive::i32( src ) => Some( src as $Type ),
+              Primitive::i64( src ) => Some( src as $Type ),
+              Primitive::isize( src ) => Some( src as $Type ),
+              Primitive::u8( src ) => Some( src as $Type ),
+              Primitive::u16( src ) => Some( src as $Type ),
+              Primitive::u32( src ) => Some( src as $Type ),
+              Primitive::u64( src ) => Some( src as $Type ),
+              Primitive::usize( src ) => Some( src as $Type ),
+              _ => None,
+            };
+            match value
+            {
+              Some( value ) => { *self = value; Ok( () ) },
+              None => Err( ReflectError::TypeMismatch { expected : stringify!( $Type ), got : src } ),
+            }
+          }
+        }
+      )*
+    };
+  }
+
+  instance_value_float!( f32, f64 );
+
+  impl InstanceValue for bool
+  {
+    #[ inline( always ) ]
+    fn as_any( &self ) -> &dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn primitive( &self ) -> Option< Primitive >
+    {
+      Some( Primitive::bool( *self ) )
+    }
+    fn set_primitive( &mut self, src : Primitive ) -> Result< (), ReflectError >
+    {
+      match src
+      {
+        Primitive::bool( src ) => { *self = src; Ok( () ) },
+        _ => Err( ReflectError::TypeMismatch { expected : "bool", got : src } ),
+      }
+    }
+  }
+
+  impl InstanceValue for String
+  {
+    #[ inline( always ) ]
+    fn as_any( &self ) -> &dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn primitive( &self ) -> Option< Primitive >
+    {
+      Some( Primitive::String( self.clone() ) )
+    }
+    fn set_primitive( &mut self, src : Primitive ) -> Result< (), ReflectError >
+    {
+      match src
+      {
+        Primitive::String( src ) => { *self = src; Ok( () ) },
+        Primitive::str( src ) => { *self = src.to_string(); Ok( () ) },
+        _ => Err( ReflectError::TypeMismatch { expected : "String", got : src } ),
+      }
+    }
+  }
+
+  impl InstanceValue for &'static str
+  {
+    #[ inline( always ) ]
+    fn as_any( &self ) -> &dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn primitive( &self ) -> Option< Primitive >
+    {
+      Some( Primitive::str( self ) )
+    }
+    fn set_primitive( &mut self, src : Primitive ) -> Result< (), ReflectError >
+    {
+      match src
+      {
+        Primitive::str( src ) => { *self = src; Ok( () ) },
+        _ => Err( ReflectError::TypeMismatch { expected : "&str", got : src } ),
+      }
+    }
+  }
+
+  impl< T, const N : usize > InstanceValue for [ T ; N ]
+  where
+    T : InstanceValue,
+  {
+    #[ inline( always ) ]
+    fn as_any( &self ) -> &dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+    {
+      self
+    }
+    fn element( &self, key : &Primitive ) -> Option< &dyn InstanceValue >
+    {
+      < [ T ] >::get( self, key.as_usize()? ).map( | e | e as &dyn InstanceValue )
+    }
+    fn element_mut( &mut self, key : &Primitive ) -> Option< &mut dyn InstanceValue >
+    {
+      < [ T ] >::get_mut( self, key.as_usize()? ).map( | e | e as &mut dyn InstanceValue )
+    }
+    fn values( &self ) -> Box< dyn Iterator< Item = ( Primitive, &dyn InstanceValue ) > + '_ >
+    {
+      Box::new( self.iter().enumerate().map( | ( k, e ) | ( Primitive::usize( k ), e as &dyn InstanceValue ) ) )
+    }
+  }
+
+  // Instance is implemented for each scalar rather than for any `InstanceMarker`, otherwise it would conflict with implementation for `Box`.
+  macro_rules! instance_scalar
+  {
+    ( $( $Type : ty ),* $(,)? ) =>
+    {
+      $(
+        impl InstanceMarker for $Type {}
+        impl IsScalar for $Type {}
+        impl Instance for $Type
+        {
+          type Entity = EntityDescriptor::< Self >;
+          #[ inline( always ) ]
+          fn Reflect() -> Self::Entity
+          {
+            EntityDescriptor::< Self >::new()
+          }
+        }
+      )*
+    };
+  }
+
+  instance_scalar!( bool, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64, String, &'static str );
 
   impl< T > InstanceMarker for &T
   where T : InstanceMarker
   {}
 
-  impl IsScalar for i8 {}
-  impl IsScalar for i16 {}
-  impl IsScalar for i32 {}
-  impl IsScalar for i64 {}
-  impl IsScalar for u8 {}
-  impl IsScalar for u16 {}
-  impl IsScalar for u32 {}
-  impl IsScalar for u64 {}
-  impl IsScalar for f32 {}
-  impl IsScalar for f64 {}
-  impl IsScalar for String {}
-  impl IsScalar for &'static str {}
+  impl< T > Instance for &T
+  where
+    Self : 'static,
+    T : InstanceMarker,
+  {
+    type Entity = EntityDescriptor::< Self >;
+    #[ inline( always ) ]
+    fn Reflect() -> Self::Entity
+    {
+      EntityDescriptor::< Self >::new()
+    }
+  }
 
   impl< T, const N : usize > Instance for [ T ; N ]
   where
@@ -412,8 +858,480 @@ pub( crate ) mod private
 
   }
 
+  ///
+  /// Descriptor of a container of the standard library.
+  ///
+  /// Unlike `EntityDescriptor` it holds number of elements and keys of elements of a reflected instance.
+  /// Descriptor made by `Instance::Reflect()` describes an empty container.
+  ///
+  #[ derive( PartialEq, Default ) ]
+  pub struct CollectionDescriptor< I >
+  {
+    /// Number of elements of the container.
+    pub len : usize,
+    /// Keys of elements of the container. `None` means elements are keyed by index.
+    pub keys : Option< Vec< Primitive > >,
+    _phantom : core::marker::PhantomData< I >,
+  }
+
+  impl< I > CollectionDescriptor< I >
+  {
+    /// Constructor of the descriptor.
+    #[ inline( always ) ]
+    pub fn new( len : usize, keys : Option< Vec< Primitive > > ) -> Self
+    {
+      let _phantom = core::marker::PhantomData::< I >;
+      Self { len, keys, _phantom }
+    }
+  }
+
+  impl< I > std::fmt::Debug for CollectionDescriptor< I >
+  where
+    CollectionDescriptor< I > : Entity,
+  {
+    fn fmt( &self, f: &mut std::fmt::Formatter< '_ > ) -> std::fmt::Result
+    {
+      f
+      .write_str( &format!( "{}#{:?}", self.type_name(), self.type_id() ) )
+    }
+  }
+
+  macro_rules! collection_entity
+  {
+    ( $( < $( $Param : ident ),* > $Type : ty => $Element : ident ; )* ) =>
+    {
+      $(
+        impl< $( $Param ),* > Entity for CollectionDescriptor< $Type >
+        where
+          $( $Param : 'static, )*
+          $Element : Instance,
+        {
+
+          #[ inline( always ) ]
+          fn is_container( &self ) -> bool
+          {
+            true
+          }
+
+          #[ inline( always ) ]
+          fn len( &self ) -> usize
+          {
+            self.len
+          }
+
+          #[ inline( always ) ]
+          fn type_name( &self ) -> &'static str
+          {
+            core::any::type_name::< $Type >()
+          }
+
+          #[ inline( always ) ]
+          fn type_id( &self ) -> core::any::TypeId
+          {
+            core::any::TypeId::of::< $Type >()
+          }
+
+          fn elements( &self ) -> Box< dyn Iterator< Item = KeyVal > >
+          {
+            let keys : Vec< Primitive > = match &self.keys
+            {
+              Some( keys ) => keys.clone(),
+              None => ( 0 .. self.len ).map( Primitive::usize ).collect(),
+            };
+            let result : Vec< KeyVal > = keys.into_iter()
+            .map( | key | KeyVal { key, val : Box::new( < $Element as Instance >::Reflect() ) } )
+            .collect();
+            Box::new( result.into_iter() )
+          }
+
+        }
+      )*
+    };
+  }
+
+  collection_entity!
+  {
+    < T > Vec< T > => T;
+    < T > Option< T > => T;
+    < T > std::collections::HashSet< T > => T;
+    < K, V > std::collections::HashMap< K, V > => V;
+    < K, V > std::collections::BTreeMap< K, V > => V;
+  }
+
+  impl< T > Instance for Vec< T >
+  where
+    CollectionDescriptor< Vec< T > > : Entity,
+  {
+    type Entity = CollectionDescriptor::< Self >;
+    #[ inline( always ) ]
+    fn reflect( &self ) -> Self::Entity
+    {
+      CollectionDescriptor::< Self >::new( self.len(), None )
+    }
+    #[ inline( always ) ]
+    fn Reflect() -> Self::Entity
+    {
+      CollectionDescriptor::< Self >::new( 0, None )
+    }
+  }
+
+  impl< T > Instance for Option< T >
+  where
+    CollectionDescriptor< Option< T > > : Entity,
+  {
+    type Entity = CollectionDescriptor::< Self >;
+    #[ inline( always ) ]
+    fn reflect( &self ) -> Self::Entity
+    {
+      CollectionDescriptor::< Self >::new( usize::from( self.is_some() ), None )
+    }
+    #[ inline( always ) ]
+    fn Reflect() -> Self::Entity
+    {
+      CollectionDescriptor::< Self >::new( 0, None )
+    }
+  }
+
+  impl< T > Instance for std::collections::HashSet< T >
+  where
+    CollectionDescriptor< std::collections::HashSet< T > > : Entity,
+  {
+    type Entity = CollectionDescriptor::< Self >;
+    #[ inline( always ) ]
+    fn reflect( &self ) -> Self::Entity
+    {
+      CollectionDescriptor::< Self >::new( self.len(), None )
+    }
+    #[ inline( always ) ]
+    fn Reflect() -> Self::Entity
+    {
+      CollectionDescriptor::< Self >::new( 0, None )
+    }
+  }
+
+  impl< K, V > Instance for std::collections::HashMap< K, V >
+  where
+    CollectionDescriptor< std::collections::HashMap< K, V > > : Entity,
+    K : InstanceValue,
+  {
+    type Entity = CollectionDescriptor::< Self >;
+    #[ inline( always ) ]
+    fn reflect( &self ) -> Self::Entity
+    {
+      // keys which are not primitives can't be addressed, so such entries are skipped
+      let keys : Vec< Primitive > = self.keys().filter_map( InstanceValue::primitive ).collect();
+      CollectionDescriptor::< Self >::new( keys.len(), Some( keys ) )
+    }
+    #[ inline( always ) ]
+    fn Reflect() -> Self::Entity
+    {
+      CollectionDescriptor::< Self >::new( 0, Some( vec![] ) )
+    }
+  }
+
+  impl< K, V > Instance for std::collections::BTreeMap< K, V >
+  where
+    CollectionDescriptor< std::collections::BTreeMap< K, V > > : Entity,
+    K : InstanceValue,
+  {
+    type Entity = CollectionDescriptor::< Self >;
+    #[ inline( always ) ]
+    fn reflect( &self ) -> Self::Entity
+    {
+      // keys which are not primitives can't be addressed, so such entries are skipped
+      let keys : Vec< Primitive > = self.keys().filter_map( InstanceValue::primitive ).collect();
+      CollectionDescriptor::< Self >::new( keys.len(), Some( keys ) )
+    }
+    #[ inline( always ) ]
+    fn Reflect() -> Self::Entity
+    {
+      CollectionDescriptor::< Self >::new( 0, Some( vec![] ) )
+    }
+  }
+
+  impl< T > Instance for Box< T >
+  where
+    T : Instance,
+  {
+    type Entity = T::Entity;
+    #[ inline( always ) ]
+    fn reflect( &self ) -> Self::Entity
+    {
+      ( **self ).reflect()
+    }
+    #[ inline( always ) ]
+    fn Reflect() -> Self::Entity
+    {
+      T::Reflect()
+    }
+  }
+
+  impl< T > IsContainer for Vec< T > where Vec< T > : Instance {}
+  impl< T > IsContainer for Option< T > where Option< T > : Instance {}
+  impl< T > IsContainer for std::collections::HashSet< T > where std::collections::HashSet< T > : Instance {}
+  impl< K, V > IsContainer for std::collections::HashMap< K, V > where std::collections::HashMap< K, V > : Instance {}
+  impl< K, V > IsContainer for std::collections::BTreeMap< K, V > where std::collections::BTreeMap< K, V > : Instance {}
+
+  /// Compare key of an element with key requested by path, string keys are compared by content.
+  /// Integer key matches string key which is its decimal representation, as keys of JSON objects.
+  fn key_eq( key : Option< Primitive >, requested : &Primitive ) -> bool
+  {
+    match key
+    {
+      Some( key ) => match ( key.as_str(), requested.as_str() )
+      {
+        ( Some( a ), Some( b ) ) => a == b,
+        ( None, Some( b ) ) => key.as_usize().is_some() && b.parse::< usize >().ok() == key.as_usize(),
+        _ => key == *requested || key.as_usize().is_some() && key.as_usize() == requested.as_usize(),
+      },
+      None => false,
+    }
+  }
+
+  impl< T > InstanceValue for Vec< T >
+  where
+    T : InstanceValue,
+  {
+    #[ inline( always ) ]
+    fn as_any( &self ) -> &dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+    {
+      self
+    }
+    fn element( &self, key : &Primitive ) -> Option< &dyn InstanceValue >
+    {
+      < [ T ] >::get( self, key.as_usize()? ).map( | e | e as &dyn InstanceValue )
+    }
+    fn element_mut( &mut self, key : &Primitive ) -> Option< &mut dyn InstanceValue >
+    {
+      < [ T ] >::get_mut( self, key.as_usize()? ).map( | e | e as &mut dyn InstanceValue )
+    }
+    fn values( &self ) -> Box< dyn Iterator< Item = ( Primitive, &dyn InstanceValue ) > + '_ >
+    {
+      Box::new( self.iter().enumerate().map( | ( k, e ) | ( Primitive::usize( k ), e as &dyn InstanceValue ) ) )
+    }
+  }
+
+  impl< T > InstanceValue for Option< T >
+  where
+    T : InstanceValue,
+  {
+    #[ inline( always ) ]
+    fn as_any( &self ) -> &dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+    {
+      self
+    }
+    fn element( &self, key : &Primitive ) -> Option< &dyn InstanceValue >
+    {
+      match key.as_usize()?
+      {
+        0 => self.as_ref().map( | e | e as &dyn InstanceValue ),
+        _ => None,
+      }
+    }
+    fn element_mut( &mut self, key : &Primitive ) -> Option< &mut dyn InstanceValue >
+    {
+      match key.as_usize()?
+      {
+        0 => self.as_mut().map( | e | e as &mut dyn InstanceValue ),
+        _ => None,
+      }
+    }
+    fn values( &self ) -> Box< dyn Iterator< Item = ( Primitive, &dyn InstanceValue ) > + '_ >
+    {
+      Box::new( self.iter().map( | e | ( Primitive::usize( 0 ), e as &dyn InstanceValue ) ) )
+    }
+  }
+
+  /// Elements of a set in order of their values, so indices of elements don't depend on order of iteration.
+  /// Values are compared as primitives. A set with an element which is not a primitive is listed in order of iteration.
+  /// Elements are sorted once, when the listing is built.
+  fn set_elements< 'a, T : InstanceValue >( iter : impl Iterator< Item = &'a T > + Clone ) -> Vec< &'a dyn InstanceValue >
+  {
+    match set_primitives( iter.clone() )
+    {
+      Some( mut elements ) =>
+      {
+        elements.sort_by( | ( a, _ ), ( b, _ ) | primitive_cmp( a, b ) );
+        elements.into_iter().map( | ( _, e ) | e ).collect()
+      },
+      None => iter.map( | e | e as &dyn InstanceValue ).collect(),
+    }
+  }
+
+  /// Element of a set at position `index` in order of its values, the same as `set_elements( iter ).nth( index )`.
+  /// The element is selected without sorting the set.
+  fn set_element< 'a, T : InstanceValue >( iter : impl Iterator< Item = &'a T > + Clone, index : usize ) -> Option< &'a dyn InstanceValue >
+  {
+    match set_primitives( iter.clone() )
+    {
+      Some( mut elements ) if index < elements.len() =>
+      {
+        let ( _, ( _, element ), _ ) = elements.select_nth_unstable_by( index, | ( a, _ ), ( b, _ ) | primitive_cmp( a, b ) );
+        Some( *element )
+      },
+      Some( _ ) => None,
+      None => iter.map( | e | e as &dyn InstanceValue ).nth( index ),
+    }
+  }
+
+  /// Elements of a set paired with their values, `None` if an element is not a primitive.
+  fn set_primitives< 'a, T : InstanceValue >( iter : impl Iterator< Item = &'a T > ) -> Option< Vec< ( Primitive, &'a dyn InstanceValue ) > >
+  {
+    iter
+    .map( | e | Some( ( e.primitive()?, e as &dyn InstanceValue ) ) )
+    .collect()
+  }
+
+  #[ inline( always ) ]
+  fn primitive_cmp( a : &Primitive, b : &Primitive ) -> core::cmp::Ordering
+  {
+    a.partial_cmp( b ).unwrap_or( core::cmp::Ordering::Equal )
+  }
+
+  impl< T > InstanceValue for std::collections::HashSet< T >
+  where
+    T : InstanceValue,
+  {
+    #[ inline( always ) ]
+    fn as_any( &self ) -> &dyn core::any::Any
+    {
+      self
+    }
+    #[ inline( always ) ]
+    fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+    {
+      self
+    }
+    fn element( &self, key : &Primitive ) -> Option< &dyn InstanceValue >
+    {
+      set_element( self.iter(), key.as_usize()? )
+    }
+    fn values( &self ) -> Box< dyn Iterator< Item = ( Primitive, &dyn InstanceValue ) > + '_ >
+    {
+      let elements = set_elements( self.iter() );
+      Box::new( elements.into_iter().enumerate().map( | ( k, e ) | ( Primitive::usize( k ), e ) ) )
+    }
+  }
+
+  macro_rules! instance_value_map
+  {
+    ( $( $Map : ty ),* $(,)? ) =>
+    {
+      $(
+        impl< K, V > InstanceValue for $Map
+        where
+          K : InstanceValue,
+          V : InstanceValue,
+        {
+          #[ inline( always ) ]
+          fn as_any( &self ) -> &dyn core::any::Any
+          {
+            self
+          }
+          #[ inline( always ) ]
+          fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+          {
+            self
+          }
+          fn element( &self, key : &Primitive ) -> Option< &dyn InstanceValue >
+          {
+            self.iter()
+            .find( | ( k, _ ) | key_eq( k.primitive(), key ) )
+            .map( | ( _, v ) | v as &dyn InstanceValue )
+          }
+          fn element_mut( &mut self, key : &Primitive ) -> Option< &mut dyn InstanceValue >
+          {
+            self.iter_mut()
+            .find( | ( k, _ ) | key_eq( k.primitive(), key ) )
+            .map( | ( _, v ) | v as &mut dyn InstanceValue )
+          }
+          fn values( &self ) -> Box< dyn Iterator< Item = ( Primitive, &dyn InstanceValue ) > + '_ >
+          {
+            Box::new( self.iter().filter_map( | ( k, v ) | Some( ( k.primitive()?, v as &dyn InstanceValue ) ) ) )
+          }
+          #[ inline( always ) ]
+          fn is_keyed( &self ) -> bool
+          {
+            true
+          }
+        }
+      )*
+    };
+  }
+
+  instance_value_map!( std::collections::HashMap< K, V >, std::collections::BTreeMap< K, V > );
+
+  impl< T > InstanceValue for Box< T >
+  where
+    T : InstanceValue,
+  {
+    #[ inline( always ) ]
+    fn as_any( &self ) -> &dyn core::any::Any
+    {
+      ( **self ).as_any()
+    }
+    #[ inline( always ) ]
+    fn as_any_mut( &mut self ) -> &mut dyn core::any::Any
+    {
+      ( **self ).as_any_mut()
+    }
+    #[ inline( always ) ]
+    fn primitive( &self ) -> Option< Primitive >
+    {
+      ( **self ).primitive()
+    }
+    #[ inline( always ) ]
+    fn set_primitive( &mut self, src : Primitive ) -> Result< (), ReflectError >
+    {
+      ( **self ).set_primitive( src )
+    }
+    #[ inline( always ) ]
+    fn element( &self, key : &Primitive ) -> Option< &dyn InstanceValue >
+    {
+      ( **self ).element( key )
+    }
+    #[ inline( always ) ]
+    fn element_mut( &mut self, key : &Primitive ) -> Option< &mut dyn InstanceValue >
+    {
+      ( **self ).element_mut( key )
+    }
+    #[ inline( always ) ]
+    fn is_keyed( &self ) -> bool
+    {
+      ( **self ).is_keyed()
+    }
+    #[ inline( always ) ]
+    fn variant( &self ) -> Option< &'static str >
+    {
+      ( **self ).variant()
+    }
+    #[ inline( always ) ]
+    fn set_variant( &mut self, name : &str ) -> Result< (), ReflectError >
+    {
+      ( **self ).set_variant( name )
+    }
+    #[ inline( always ) ]
+    fn values( &self ) -> Box< dyn Iterator< Item = ( Primitive, &dyn InstanceValue ) > + '_ >
+    {
+      ( **self ).values()
+    }
+  }
+
 }
 
+/// Format-neutral tree of reflected values, its writers and parser.
+pub mod tree;
+
 #[ doc( inline ) ]
 #[ allow( unused_imports ) ]
 pub use protected::*;
@@ -424,6 +1342,9 @@ pub mod protected
   #[ doc( inline ) ]
   #[ allow( unused_imports ) ]
   pub use super::orphan::*;
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::tree::orphan::*;
 }
 
 /// Orphan namespace of the module.
@@ -440,8 +1361,12 @@ pub mod orphan
     Instance,
     InstanceMarker,
     EntityDescriptor,
+    CollectionDescriptor,
     Entity,
     KeyVal,
+    ReflectError,
+    InstanceValue,
+    path_parse,
   };
 }
 
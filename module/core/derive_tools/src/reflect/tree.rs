@@ -0,0 +1,555 @@
+//!
+//! Format-neutral tree of reflected values, its writers and parser.
+//!
+
+/// Internal namespace.
+pub( crate ) mod private
+{
+  use crate::reflect::{ Primitive, InstanceValue, ReflectError };
+
+  ///
+  /// Format-neutral tree of values of a reflected instance.
+  ///
+  /// Tree is made by walking an instance through `InstanceValue`. It could be written as JSON or as indented text,
+  /// parsed back from JSON and applied to an instance to populate its values.
+  /// Structure with named fields and map are written as object even if empty, sequence is written as array.
+  /// Enum is a map with a single element keyed by name of its current variant, for example `{"Circle":{"r":1}}`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use derive_tools::reflect::{ Tree, Primitive };
+  /// let tree = Tree::Map( vec![ ( Primitive::str( "a" ), Tree::Scalar( Primitive::i32( 1 ) ) ) ] );
+  /// assert_eq!( tree.to_json(), r#"{"a":1}"# );
+  /// assert_eq!( Tree::Map( vec![] ).to_json(), "{}" );
+  /// assert_eq!( Tree::from_json( r#"{"a":1}"# ).unwrap().to_text(), "a : 1\n" );
+  /// ```
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub enum Tree
+  {
+    /// Scalar value.
+    Scalar( Primitive ),
+    /// Container and its elements keyed by name or by index.
+    Container( Vec< ( Primitive, Tree ) > ),
+    /// Container and its elements keyed by name, for example structure with named fields or map.
+    Map( Vec< ( Primitive, Tree ) > ),
+  }
+
+  impl Tree
+  {
+
+    /// Walk an instance and make tree of its values.
+    pub fn from_value( src : &dyn InstanceValue ) -> Self
+    {
+      if let Some( primitive ) = src.primitive()
+      {
+        return Tree::Scalar( primitive );
+      }
+      let elements : Vec< _ > = src.values().map( | ( key, val ) | ( key, Tree::from_value( val ) ) ).collect();
+      let elements = if src.is_keyed() { Tree::Map( elements ) } else { Tree::Container( elements ) };
+      match src.variant()
+      {
+        Some( variant ) => Tree::Map( Vec::from( [ ( Primitive::str( variant ), elements ) ] ) ),
+        None => elements,
+      }
+    }
+
+    /// Populate values of an instance from the tree.
+    ///
+    /// Only existing elements are populated, containers are neither grown nor shrunk, so element which does not exist is an error.
+    /// Enum is switched to the variant of the tree if the variant differs. Scalar `None` leaves value unchanged.
+    pub fn apply( &self, dst : &mut dyn InstanceValue ) -> Result< (), ReflectError >
+    {
+      match self
+      {
+        Tree::Scalar( Primitive::None ) => Ok( () ),
+        Tree::Scalar( primitive ) => dst.set_primitive( primitive.clone() ),
+        Tree::Container( elements ) | Tree::Map( elements ) if dst.variant().is_some() =>
+        {
+          let [ ( variant, tree ) ] = elements.as_slice() else
+          {
+            return Err( ReflectError::BadFormat( format!( "expected single variant of enum, but got {} elements", elements.len() ) ) );
+          };
+          let variant = variant.as_str().ok_or_else( || ReflectError::NoVariant( key_text( variant ) ) )?;
+          if dst.variant() != Some( variant )
+          {
+            dst.set_variant( variant )?;
+          }
+          match tree
+          {
+            Tree::Scalar( Primitive::None ) => Ok( () ),
+            Tree::Scalar( primitive ) => Err( ReflectError::BadFormat( format!( "expected fields of variant {}, but got {}", variant, scalar_text( primitive ) ) ) ),
+            Tree::Container( elements ) | Tree::Map( elements ) => elements_apply( elements, dst ),
+          }
+        },
+        Tree::Container( elements ) | Tree::Map( elements ) => elements_apply( elements, dst ),
+      }
+    }
+
+    /// Write the tree as compact JSON.
+    ///
+    /// Container keyed by indices `0..len` is written as array, any other container and map are written as object.
+    pub fn to_json( &self ) -> String
+    {
+      let mut result = String::new();
+      json_write( self, &mut result );
+      result
+    }
+
+    /// Write the tree as human-readable indented text, an element per line.
+    pub fn to_text( &self ) -> String
+    {
+      let mut result = String::new();
+      match self
+      {
+        Tree::Scalar( primitive ) =>
+        {
+          result.push_str( &scalar_text( primitive ) );
+          result.push( '\n' );
+        },
+        Tree::Container( _ ) | Tree::Map( _ ) => text_write( self, 0, &mut result ),
+      }
+      result
+    }
+
+    /// Parse tree from JSON.
+    ///
+    /// Integers are parsed as `i64` or `u64` if too big, other numbers are parsed as `f64`.
+    pub fn from_json( src : &str ) -> Result< Self, ReflectError >
+    {
+      let mut parser = JsonParser { src : src.as_bytes(), pos : 0 };
+      let result = parser.value()?;
+      parser.space();
+      if parser.pos != parser.src.len()
+      {
+        return Err( parser.error( "unexpected trailing characters" ) );
+      }
+      Ok( result )
+    }
+
+  }
+
+  fn elements_apply( elements : &[ ( Primitive, Tree ) ], dst : &mut dyn InstanceValue ) -> Result< (), ReflectError >
+  {
+    for ( key, tree ) in elements.iter()
+    {
+      let element = dst.element_mut( key ).ok_or_else( || ReflectError::NoElement( key.clone() ) )?;
+      tree.apply( element )?;
+    }
+    Ok( () )
+  }
+
+  /// Is container keyed by indices `0..len`.
+  fn is_sequence( elements : &[ ( Primitive, Tree ) ] ) -> bool
+  {
+    elements.iter().enumerate().all( | ( i, ( key, _ ) ) | *key == Primitive::usize( i ) )
+  }
+
+  fn json_string( src : &str, dst : &mut String )
+  {
+    dst.push( '"' );
+    for c in src.chars()
+    {
+      match c
+      {
+        '"' => dst.push_str( "\\\"" ),
+        '\\' => dst.push_str( "\\\\" ),
+        '\n' => dst.push_str( "\\n" ),
+        '\r' => dst.push_str( "\\r" ),
+        '\t' => dst.push_str( "\\t" ),
+        c if ( c as u32 ) < 0x20 => dst.push_str( &format!( "\\u{:04x}", c as u32 ) ),
+        c => dst.push( c ),
+      }
+    }
+    dst.push( '"' );
+  }
+
+  fn json_float( src : f64, dst : &mut String )
+  {
+    if src.is_finite()
+    {
+      dst.push_str( &src.to_string() );
+    }
+    else
+    {
+      dst.push_str( "null" );
+    }
+  }
+
+  fn json_write( tree : &Tree, dst : &mut String )
+  {
+    match tree
+    {
+      Tree::Scalar( primitive ) => match primitive
+      {
+        Primitive::None => dst.push_str( "null" ),
+        Primitive::String( src ) => json_string( src, dst ),
+        Primitive::str( src ) => json_string( src, dst ),
+        Primitive::f32( src ) => json_float( f64::from( *src ), dst ),
+        Primitive::f64( src ) => json_float( *src, dst ),
+        Primitive::binary( src ) =>
+        {
+          let bytes : Vec< String > = src.iter().map( | b | b.to_string() ).collect();
+          dst.push( '[' );
+          dst.push_str( &bytes.join( "," ) );
+          dst.push( ']' );
+        },
+        _ => dst.push_str( &scalar_text( primitive ) ),
+      },
+      Tree::Container( elements ) if is_sequence( elements ) =>
+      {
+        dst.push( '[' );
+        for ( i, ( _, tree ) ) in elements.iter().enumerate()
+        {
+          if i > 0
+          {
+            dst.push( ',' );
+          }
+          json_write( tree, dst );
+        }
+        dst.push( ']' );
+      },
+      Tree::Container( elements ) | Tree::Map( elements ) =>
+      {
+        dst.push( '{' );
+        for ( i, ( key, tree ) ) in elements.iter().enumerate()
+        {
+          if i > 0
+          {
+            dst.push( ',' );
+          }
+          json_string( &key_text( key ), dst );
+          dst.push( ':' );
+          json_write( tree, dst );
+        }
+        dst.push( '}' );
+      },
+    }
+  }
+
+  /// Text of a scalar, strings are quoted.
+  fn scalar_text( primitive : &Primitive ) -> String
+  {
+    match primitive
+    {
+      Primitive::None => "null".to_string(),
+      Primitive::bool( src ) => src.to_string(),
+      Primitive::i8( src ) => src.to_string(),
+      Primitive::i16( src ) => src.to_string(),
+      Primitive::i32( src ) => src.to_string(),
+      Primitive::i64( src ) => src.to_string(),
+      Primitive::isize( src ) => src.to_string(),
+      Primitive::u8( src ) => src.to_string(),
+      Primitive::u16( src ) => src.to_string(),
+      Primitive::u32( src ) => src.to_string(),
+      Primitive::u64( src ) => src.to_string(),
+      Primitive::usize( src ) => src.to_string(),
+      Primitive::f32( src ) => src.to_string(),
+      Primitive::f64( src ) => src.to_string(),
+      Primitive::String( src ) => format!( "{:?}", src ),
+      Primitive::str( src ) => format!( "{:?}", src ),
+      Primitive::binary( src ) => format!( "{:?}", src ),
+    }
+  }
+
+  /// Text of a key, strings are not quoted.
+  fn key_text( key : &Primitive ) -> String
+  {
+    match key.as_str()
+    {
+      Some( src ) => src.to_string(),
+      None => scalar_text( key ),
+    }
+  }
+
+  fn text_write( tree : &Tree, level : usize, dst : &mut String )
+  {
+    let ( elements, sequence ) = match tree
+    {
+      Tree::Scalar( _ ) => return,
+      Tree::Container( elements ) => ( elements, is_sequence( elements ) ),
+      Tree::Map( elements ) => ( elements, false ),
+    };
+    for ( key, tree ) in elements.iter()
+    {
+      dst.push_str( &"  ".repeat( level ) );
+      if sequence
+      {
+        dst.push_str( &format!( "[{}]", key_text( key ) ) );
+      }
+      else
+      {
+        dst.push_str( &key_text( key ) );
+      }
+      match tree
+      {
+        Tree::Scalar( primitive ) =>
+        {
+          dst.push_str( " : " );
+          dst.push_str( &scalar_text( primitive ) );
+          dst.push( '\n' );
+        },
+        Tree::Container( elements ) if elements.is_empty() =>
+        {
+          dst.push_str( " : []\n" );
+        },
+        Tree::Map( elements ) if elements.is_empty() =>
+        {
+          dst.push_str( " : {}\n" );
+        },
+        Tree::Container( _ ) | Tree::Map( _ ) =>
+        {
+          dst.push_str( " :\n" );
+          text_write( tree, level + 1, dst );
+        },
+      }
+    }
+  }
+
+  struct JsonParser< 'a >
+  {
+    src : &'a [ u8 ],
+    pos : usize,
+  }
+
+  impl JsonParser< '_ >
+  {
+
+    fn error( &self, msg : &str ) -> ReflectError
+    {
+      ReflectError::BadFormat( format!( "{} at {}", msg, self.pos ) )
+    }
+
+    fn space( &mut self )
+    {
+      while self.pos < self.src.len() && self.src[ self.pos ].is_ascii_whitespace()
+      {
+        self.pos += 1;
+      }
+    }
+
+    fn peek( &mut self ) -> Option< u8 >
+    {
+      self.space();
+      self.src.get( self.pos ).copied()
+    }
+
+    fn expect( &mut self, c : u8 ) -> Result< (), ReflectError >
+    {
+      if self.peek() == Some( c )
+      {
+        self.pos += 1;
+        Ok( () )
+      }
+      else
+      {
+        Err( self.error( &format!( "expected '{}'", c as char ) ) )
+      }
+    }
+
+    fn value( &mut self ) -> Result< Tree, ReflectError >
+    {
+      match self.peek()
+      {
+        Some( b'{' ) =>
+        {
+          self.pos += 1;
+          let mut elements = Vec::new();
+          if self.peek() == Some( b'}' )
+          {
+            self.pos += 1;
+            return Ok( Tree::Map( elements ) );
+          }
+          loop
+          {
+            self.space();
+            let key = self.string()?;
+            self.expect( b':' )?;
+            elements.push( ( Primitive::String( key ), self.value()? ) );
+            match self.peek()
+            {
+              Some( b',' ) => self.pos += 1,
+              Some( b'}' ) => { self.pos += 1; break },
+              _ => return Err( self.error( "expected ',' or '}'" ) ),
+            }
+          }
+          Ok( Tree::Map( elements ) )
+        },
+        Some( b'[' ) =>
+        {
+          self.pos += 1;
+          let mut elements = Vec::new();
+          if self.peek() == Some( b']' )
+          {
+            self.pos += 1;
+            return Ok( Tree::Container( elements ) );
+          }
+          loop
+          {
+            elements.push( ( Primitive::usize( elements.len() ), self.value()? ) );
+            match self.peek()
+            {
+              Some( b',' ) => self.pos += 1,
+              Some( b']' ) => { self.pos += 1; break },
+              _ => return Err( self.error( "expected ',' or ']'" ) ),
+            }
+          }
+          Ok( Tree::Container( elements ) )
+        },
+        Some( b'"' ) => Ok( Tree::Scalar( Primitive::String( self.string()? ) ) ),
+        Some( b'n' ) if self.src[ self.pos .. ].starts_with( b"null" ) =>
+        {
+          self.pos += 4;
+          Ok( Tree::Scalar( Primitive::None ) )
+        },
+        Some( b't' ) if self.src[ self.pos .. ].starts_with( b"true" ) =>
+        {
+          self.pos += 4;
+          Ok( Tree::Scalar( Primitive::bool( true ) ) )
+        },
+        Some( b'f' ) if self.src[ self.pos .. ].starts_with( b"false" ) =>
+        {
+          self.pos += 5;
+          Ok( Tree::Scalar( Primitive::bool( false ) ) )
+        },
+        Some( b'-' | b'0' ..= b'9' ) => self.number(),
+        _ => Err( self.error( "unexpected character" ) ),
+      }
+    }
+
+    fn string( &mut self ) -> Result< String, ReflectError >
+    {
+      if self.src.get( self.pos ) != Some( &b'"' )
+      {
+        return Err( self.error( "expected string" ) );
+      }
+      self.pos += 1;
+      let mut result = Vec::new();
+      loop
+      {
+        let c = *self.src.get( self.pos ).ok_or_else( || self.error( "unterminated string" ) )?;
+        self.pos += 1;
+        match c
+        {
+          b'"' => break,
+          b'\\' =>
+          {
+            let escaped = *self.src.get( self.pos ).ok_or_else( || self.error( "unterminated string" ) )?;
+            self.pos += 1;
+            match escaped
+            {
+              b'"' => result.push( b'"' ),
+              b'\\' => result.push( b'\\' ),
+              b'/' => result.push( b'/' ),
+              b'n' => result.push( b'\n' ),
+              b'r' => result.push( b'\r' ),
+              b't' => result.push( b'\t' ),
+              b'b' => result.push( 0x08 ),
+              b'f' => result.push( 0x0c ),
+              b'u' =>
+              {
+                let mut code = self.hex()?;
+                // character out of basic plane is escaped as UTF-16 surrogate pair, high surrogate goes first
+                if ( 0xd800 .. 0xdc00 ).contains( &code )
+                {
+                  if self.src.get( self.pos .. self.pos + 2 ) != Some( &b"\\u"[ .. ] )
+                  {
+                    return Err( self.error( "unpaired surrogate" ) );
+                  }
+                  self.pos += 2;
+                  let low = self.hex()?;
+                  if !( 0xdc00 .. 0xe000 ).contains( &low )
+                  {
+                    return Err( self.error( "unpaired surrogate" ) );
+                  }
+                  code = 0x10000 + ( ( code - 0xd800 ) << 10 ) + ( low - 0xdc00 );
+                }
+                let code = char::from_u32( code ).ok_or_else( || self.error( "unpaired surrogate" ) )?;
+                let mut buffer = [ 0 ; 4 ];
+                result.extend_from_slice( code.encode_utf8( &mut buffer ).as_bytes() );
+              },
+              _ => return Err( self.error( "bad escape" ) ),
+            }
+          },
+          c => result.push( c ),
+        }
+      }
+      String::from_utf8( result ).map_err( | _ | self.error( "invalid utf-8" ) )
+    }
+
+    /// Parse four hex digits of escape `\uXXXX`.
+    fn hex( &mut self ) -> Result< u32, ReflectError >
+    {
+      let code = self.src.get( self.pos .. self.pos + 4 )
+      .and_then( | hex | core::str::from_utf8( hex ).ok() )
+      .filter( | hex | hex.bytes().all( | c | c.is_ascii_hexdigit() ) )
+      .and_then( | hex | u32::from_str_radix( hex, 16 ).ok() )
+      .ok_or_else( || self.error( "bad escape" ) )?;
+      self.pos += 4;
+      Ok( code )
+    }
+
+    fn number( &mut self ) -> Result< Tree, ReflectError >
+    {
+      let begin = self.pos;
+      while self.pos < self.src.len() && matches!( self.src[ self.pos ], b'-' | b'+' | b'.' | b'e' | b'E' | b'0' ..= b'9' )
+      {
+        self.pos += 1;
+      }
+      let text = core::str::from_utf8( &self.src[ begin .. self.pos ] ).unwrap();
+      let result = if text.contains( [ '.', 'e', 'E' ] )
+      {
+        text.parse::< f64 >().ok().map( Primitive::f64 )
+      }
+      else
+      {
+        text.parse::< i64 >().ok().map( Primitive::i64 )
+        .or_else( || text.parse::< u64 >().ok().map( Primitive::u64 ) )
+      };
+      result.map( Tree::Scalar ).ok_or_else( || self.error( "bad number" ) )
+    }
+
+  }
+
+}
+
+#[ doc( inline ) ]
+#[ allow( unused_imports ) ]
+pub use protected::*;
+
+/// Protected namespace of the module.
+pub mod protected
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::orphan::*;
+}
+
+/// Orphan namespace of the module.
+pub mod orphan
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::exposed::*;
+  pub use super::private::
+  {
+    Tree,
+  };
+}
+
+/// Exposed namespace of the module.
+pub mod exposed
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::prelude::*;
+}
+
+#[ doc( inline ) ]
+#[ allow( unused_imports ) ]
+pub use exposed::*;
+
+/// Prelude to use essentials: `use my_module::prelude::*`.
+pub mod prelude
+{
+}
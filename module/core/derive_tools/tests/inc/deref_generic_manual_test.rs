@@ -0,0 +1,117 @@
+use super::*;
+
+#[ derive( Debug ) ]
+pub struct Wrap< 'a, T >( &'a T );
+
+impl< 'a, T > core::ops::Deref for Wrap< 'a, T >
+{
+  type Target = &'a T;
+  #[ inline( always ) ]
+  fn deref( &self ) -> &Self::Target
+  {
+    &self.0
+  }
+}
+
+impl< 'a, T > AsRef< &'a T > for Wrap< 'a, T >
+{
+  fn as_ref( &self ) -> &&'a T
+  {
+    &self.0
+  }
+}
+
+#[ derive( Debug ) ]
+pub struct Named< T >
+where
+  T : Clone,
+{
+  pub inner : T,
+}
+
+impl< T > core::ops::Deref for Named< T >
+where
+  T : Clone,
+{
+  type Target = T;
+  #[ inline( always ) ]
+  fn deref( &self ) -> &Self::Target
+  {
+    &self.inner
+  }
+}
+
+impl< T > core::ops::DerefMut for Named< T >
+where
+  T : Clone,
+{
+  #[ inline( always ) ]
+  fn deref_mut( &mut self ) -> &mut Self::Target
+  {
+    &mut self.inner
+  }
+}
+
+impl< T > AsRef< T > for Named< T >
+where
+  T : Clone,
+{
+  fn as_ref( &self ) -> &T
+  {
+    &self.inner
+  }
+}
+
+impl< T > AsMut< T > for Named< T >
+where
+  T : Clone,
+{
+  fn as_mut( &mut self ) -> &mut T
+  {
+    &mut self.inner
+  }
+}
+
+#[ derive( Debug ) ]
+pub struct Marked< T >
+{
+  pub a : u8,
+  pub b : T,
+}
+
+impl< T > core::ops::Deref for Marked< T >
+{
+  type Target = T;
+  #[ inline( always ) ]
+  fn deref( &self ) -> &Self::Target
+  {
+    &self.b
+  }
+}
+
+impl< T > core::ops::DerefMut for Marked< T >
+{
+  #[ inline( always ) ]
+  fn deref_mut( &mut self ) -> &mut Self::Target
+  {
+    &mut self.b
+  }
+}
+
+impl< T > AsRef< T > for Marked< T >
+{
+  fn as_ref( &self ) -> &T
+  {
+    &self.b
+  }
+}
+
+impl< T > AsMut< T > for Marked< T >
+{
+  fn as_mut( &mut self ) -> &mut T
+  {
+    &mut self.b
+  }
+}
+
+include!( "./only_test/deref_generic.rs" );
@@ -0,0 +1,22 @@
+use super::*;
+
+#[ derive( Debug, TheModule::Deref, TheModule::AsRef ) ]
+pub struct Wrap< 'a, T >( &'a T );
+
+#[ derive( Debug, TheModule::Deref, TheModule::DerefMut, TheModule::AsRef, TheModule::AsMut ) ]
+pub struct Named< T >
+where
+  T : Clone,
+{
+  pub inner : T,
+}
+
+#[ derive( Debug, TheModule::Deref, TheModule::DerefMut, TheModule::AsRef, TheModule::AsMut ) ]
+pub struct Marked< T >
+{
+  pub a : u8,
+  #[ deref ]
+  pub b : T,
+}
+
+include!( "./only_test/deref_generic.rs" );
@@ -0,0 +1,56 @@
+use super::*;
+
+#[ derive( Debug, PartialEq, TheModule::Display ) ]
+#[ display( "{a}-{b}" ) ]
+pub struct Struct1
+{
+  pub a : i32,
+  pub b : String,
+}
+
+#[ derive( Debug, PartialEq, TheModule::Display ) ]
+pub struct Newtype( pub f32 );
+
+#[ derive( Debug, PartialEq, TheModule::Display ) ]
+pub struct Unit;
+
+#[ derive( Debug, PartialEq, TheModule::Display ) ]
+#[ display( "{{{1}, {0}}}" ) ]
+pub struct Tuple< T >( pub T, pub u8 );
+
+#[ derive( Debug, PartialEq, TheModule::Display ) ]
+pub enum Enum1
+{
+  Unit,
+  #[ display( "one:{0}" ) ]
+  One( u8 ),
+  #[ display( "point {x} {y}" ) ]
+  Point { x : i32, y : i32 },
+  Wrap( Newtype ),
+}
+
+// --
+
+#[ test ]
+fn display()
+{
+  a_id!( Struct1 { a : 1, b : "x".into() }.to_string(), "1-x" );
+  a_id!( Newtype( 1.5 ).to_string(), "1.5" );
+  a_id!( Unit.to_string(), "Unit" );
+  a_id!( Tuple( "a", 2 ).to_string(), "{2, a}" );
+  a_id!( Enum1::Unit.to_string(), "Unit" );
+  a_id!( Enum1::One( 3 ).to_string(), "one:3" );
+  a_id!( Enum1::Point { x : -1, y : 2 }.to_string(), "point -1 2" );
+  a_id!( Enum1::Wrap( Newtype( 2.0 ) ).to_string(), "2" );
+}
+
+//
+
+#[ test ]
+fn display_flags()
+{
+  // flags of the formatter are not applied to each field, the value is written as a whole like by `write!`
+  a_id!( format!( "{:>5}", Struct1 { a : 1, b : "x".into() } ), "1-x" );
+  a_id!( format!( "{:*<8}", Enum1::Point { x : -1, y : 2 } ), "point -1 2" );
+  a_id!( format!( "{:.1}", Newtype( 1.25 ) ), "1.25" );
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+#[ derive( Debug, PartialEq ) ]
+pub struct Pair< T, U >( T, U )
+where
+  T : Copy;
+
+impl< T, U > From< ( T, U ) > for Pair< T, U >
+where
+  T : Copy,
+{
+  #[ inline( always ) ]
+  fn from( src : ( T, U ) ) -> Self
+  {
+    Self( src.0, src.1 )
+  }
+}
+
+#[ derive( Debug, PartialEq ) ]
+pub struct Lifetimed< 'a >
+{
+  a : &'a str,
+}
+
+impl< 'a > From< &'a str > for Lifetimed< 'a >
+{
+  #[ inline( always ) ]
+  fn from( src : &'a str ) -> Self
+  {
+    Self { a : src }
+  }
+}
+
+impl< 'a > From< Lifetimed< 'a > > for &'a str
+{
+  #[ inline( always ) ]
+  fn from( src : Lifetimed< 'a > ) -> Self
+  {
+    src.a
+  }
+}
+
+#[ derive( Debug, PartialEq ) ]
+pub struct Marked< T >
+where
+  T : Default,
+{
+  a : i32,
+  b : T,
+}
+
+impl< T > From< i32 > for Marked< T >
+where
+  T : Default,
+{
+  #[ inline( always ) ]
+  fn from( src : i32 ) -> Self
+  {
+    Self { a : src, b : Default::default() }
+  }
+}
+
+impl< T > From< Marked< T > > for i32
+where
+  T : Default,
+{
+  #[ inline( always ) ]
+  fn from( src : Marked< T > ) -> Self
+  {
+    src.a
+  }
+}
+
+include!( "./only_test/from_generic.rs" );
@@ -0,0 +1,24 @@
+use super::*;
+
+#[ derive( Debug, PartialEq, TheModule::From ) ]
+pub struct Pair< T, U >( T, U )
+where
+  T : Copy;
+
+#[ derive( Debug, PartialEq, TheModule::From, TheModule::InnerFrom ) ]
+pub struct Lifetimed< 'a >
+{
+  a : &'a str,
+}
+
+#[ derive( Debug, PartialEq, TheModule::From, TheModule::InnerFrom ) ]
+pub struct Marked< T >
+where
+  T : Default,
+{
+  #[ from ]
+  a : i32,
+  b : T,
+}
+
+include!( "./only_test/from_generic.rs" );
@@ -0,0 +1,53 @@
+use super::*;
+
+#[ allow( dead_code ) ]
+#[ derive( Debug, PartialEq ) ]
+pub enum Enum1
+{
+  Int( i32 ),
+  Other( i32 ),
+  Text { text : String },
+  Pair( i32, i32 ),
+  Unit,
+}
+
+impl From< i32 > for Enum1
+{
+  #[ inline( always ) ]
+  fn from( src : i32 ) -> Self
+  {
+    Self::Int( src )
+  }
+}
+
+impl From< String > for Enum1
+{
+  #[ inline( always ) ]
+  fn from( src : String ) -> Self
+  {
+    Self::Text { text : src }
+  }
+}
+
+#[ allow( dead_code ) ]
+#[ derive( Debug, PartialEq ) ]
+pub enum Enum2< T >
+where
+  T : Copy,
+{
+  Value( T ),
+  None,
+}
+
+impl< T > From< T > for Enum2< T >
+where
+  T : Copy,
+{
+  #[ inline( always ) ]
+  fn from( src : T ) -> Self
+  {
+    Self::Value( src )
+  }
+}
+
+include!( "./only_test/from_inner_enum.rs" );
@@ -0,0 +1,25 @@
+use super::*;
+
+#[ allow( dead_code ) ]
+#[ derive( Debug, PartialEq, TheModule::From ) ]
+pub enum Enum1
+{
+  Int( i32 ),
+  #[ from( skip ) ]
+  Other( i32 ),
+  Text { text : String },
+  Pair( i32, i32 ),
+  Unit,
+}
+
+#[ allow( dead_code ) ]
+#[ derive( Debug, PartialEq, TheModule::From ) ]
+pub enum Enum2< T >
+where
+  T : Copy,
+{
+  Value( T ),
+  None,
+}
+
+include!( "./only_test/from_inner_enum.rs" );
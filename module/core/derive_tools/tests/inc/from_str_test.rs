@@ -0,0 +1,155 @@
+use super::*;
+use TheModule::from_str::ParseError;
+
+#[ derive( Debug, PartialEq, TheModule::Display, TheModule::FromStr ) ]
+#[ display( "{a}-{b}" ) ]
+pub struct Struct1
+{
+  pub a : i32,
+  pub b : String,
+}
+
+#[ derive( Debug, PartialEq, TheModule::Display, TheModule::FromStr ) ]
+pub struct Newtype( pub u32 );
+
+#[ derive( Debug, PartialEq, TheModule::Display, TheModule::FromStr ) ]
+pub struct Unit;
+
+#[ derive( Debug, PartialEq, TheModule::Display, TheModule::FromStr ) ]
+#[ display( "[{0}]" ) ]
+pub struct Partial( pub u8, pub Vec< u8 > );
+
+#[ derive( Debug, PartialEq, TheModule::Display, TheModule::FromStr ) ]
+#[ display( "<{0}>" ) ]
+pub struct Generic< T >( pub T );
+
+#[ derive( Debug, PartialEq, TheModule::Display, TheModule::FromStr ) ]
+pub enum Enum1
+{
+  Unit,
+  #[ display( "one:{0}" ) ]
+  One( u8 ),
+  #[ display( "point {x} {y}" ) ]
+  Point { x : i32, y : i32 },
+}
+
+// --
+
+#[ test ]
+fn parse()
+{
+  a_id!( "1-x".parse(), Ok( Struct1 { a : 1, b : "x".into() } ) );
+  a_id!( "-1--x".parse(), Ok( Struct1 { a : -1, b : "-x".into() } ) );
+  a_id!( "13".parse(), Ok( Newtype( 13 ) ) );
+  a_id!( "Unit".parse(), Ok( Unit ) );
+  a_id!( "[3]".parse(), Ok( Partial( 3, vec![] ) ) );
+  a_id!( "<a b>".parse(), Ok( Generic( "a b".to_string() ) ) );
+  a_id!( "Unit".parse(), Ok( Enum1::Unit ) );
+  a_id!( "one:3".parse(), Ok( Enum1::One( 3 ) ) );
+  a_id!( "point -1 2".parse(), Ok( Enum1::Point { x : -1, y : 2 } ) );
+}
+
+//
+
+#[ test ]
+fn round_trip()
+{
+  let src = Struct1 { a : 1, b : "x-y".into() };
+  a_id!( src.to_string().parse(), Ok( src ) );
+  let src = Generic( 2.5 );
+  a_id!( src.to_string().parse(), Ok( src ) );
+  for src in [ Enum1::Unit, Enum1::One( 7 ), Enum1::Point { x : 3, y : -4 } ]
+  {
+    a_id!( src.to_string().parse(), Ok( src ) );
+  }
+}
+
+//
+
+#[ test ]
+fn errors()
+{
+  let got = "1+x".parse::< Struct1 >();
+  a_id!( got, Err( ParseError::new( "Struct1", "expects \"-\" after field `a`" ) ) );
+  let got = "x-1".parse::< Struct1 >();
+  a_id!( got, Err( ParseError::new( "Struct1", "field `a` : invalid digit found in string" ) ) );
+  let got = "Units".parse::< Unit >();
+  a_id!( got, Err( ParseError::new( "Unit", "unexpected \"s\"" ) ) );
+  let got = "3]".parse::< Partial >();
+  a_id!( got, Err( ParseError::new( "Partial", "expects \"[\"" ) ) );
+  let got = "two:3".parse::< Enum1 >();
+  a_id!( got, Err( ParseError::new( "Enum1", "no variant matches" ) ) );
+  a_id!( got.unwrap_err().to_string(), "Failed to parse Enum1 : no variant matches" );
+}
+
+//
+
+#[ derive( Debug, PartialEq, TheModule::Display, TheModule::FromStr ) ]
+#[ display( "{type}:{r#loop}" ) ]
+pub struct Raw
+{
+  pub r#type : u8,
+  pub r#loop : bool,
+}
+
+mod facade
+{
+  pub use ::derive_tools as tools;
+}
+
+#[ derive( Debug, PartialEq, TheModule::FromStr ) ]
+#[ from_str( crate = facade::tools ) ]
+pub struct CratePath( pub u8 );
+
+#[ test ]
+fn raw_and_crate_path()
+{
+  let src = Raw { r#type : 1, r#loop : true };
+  a_id!( src.to_string(), "1:true" );
+  a_id!( "1:true".parse(), Ok( src ) );
+  a_id!( "2".parse(), Ok( CratePath( 2 ) ) );
+}
+
+//
+
+/// Generated code doesn't depend on names of prelude and on `core` which could be shadowed.
+mod shadowed
+{
+  #![ allow( dead_code, non_camel_case_types, unused_macros ) ]
+  use super::TheModule;
+
+  mod core {}
+  struct Ok;
+  struct Err;
+  struct Some;
+  struct None;
+  struct String;
+  macro_rules! format { ( $( $t : tt )* ) => { compile_error!( "shadowed" ) } }
+
+  #[ derive( Debug, PartialEq, TheModule::Display, TheModule::FromStr ) ]
+  #[ display( "{a}-{b}" ) ]
+  pub struct Struct1
+  {
+    pub a : i32,
+    pub b : u8,
+  }
+
+  #[ derive( Debug, PartialEq, TheModule::Display, TheModule::FromStr ) ]
+  pub enum Enum1
+  {
+    Unit,
+    #[ display( "one:{0}" ) ]
+    One( u8 ),
+  }
+
+  #[ test ]
+  fn parse_and_display()
+  {
+    let src = Struct1 { a : 1, b : 2 };
+    assert_eq!( src.to_string(), "1-2" );
+    assert_eq!( "1-2".parse::< Struct1 >().unwrap(), src );
+    assert!( "1-".parse::< Struct1 >().is_err() );
+    assert_eq!( "one:3".parse::< Enum1 >().unwrap(), Enum1::One( 3 ) );
+    assert_eq!( Enum1::Unit.to_string(), "Unit" );
+  }
+}
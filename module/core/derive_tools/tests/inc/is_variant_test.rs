@@ -0,0 +1,26 @@
+use super::*;
+
+#[ derive( Debug, TheModule::IsVariant ) ]
+pub enum Shape< T >
+{
+  Circle( T ),
+  Square { side : T },
+  HttpShape( T, T ),
+  Empty,
+}
+
+// --
+
+#[ test ]
+fn is_variant()
+{
+  let src = Shape::Circle( 1 );
+  a_true!( src.is_circle() );
+  a_true!( !src.is_square() );
+  a_true!( !src.is_http_shape() );
+  a_true!( !src.is_empty() );
+
+  a_true!( Shape::Square { side : 1 }.is_square() );
+  a_true!( Shape::HttpShape( 1, 2 ).is_http_shape() );
+  a_true!( Shape::< i32 >::Empty.is_empty() );
+}
@@ -36,6 +36,10 @@ mod deref_mut_manual_test;
 #[ cfg( feature = "derive_deref_mut" ) ]
 mod deref_mut_test;
 
+mod deref_generic_manual_test;
+#[ cfg( all( feature = "derive_deref", feature = "derive_deref_mut", feature = "derive_as_ref", feature = "derive_as_mut" ) ) ]
+mod deref_generic_test;
+
 mod from_inner_manual_test;
 mod from_inner_named_manual_test;
 mod from_inner_multiple_named_manual_test;
@@ -68,6 +72,21 @@ mod inner_from_unit_test;
 #[ cfg( feature = "derive_inner_from" ) ]
 mod inner_from_multiple_test;
 
+mod from_generic_manual_test;
+#[ cfg( all( feature = "derive_from", feature = "derive_inner_from" ) ) ]
+mod from_generic_test;
+
+mod from_inner_enum_manual_test;
+#[ cfg( feature = "derive_from" ) ]
+mod from_inner_enum_test;
+
+#[ cfg( feature = "derive_is_variant" ) ]
+mod is_variant_test;
+#[ cfg( feature = "derive_unwrap" ) ]
+mod unwrap_test;
+#[ cfg( feature = "derive_try_into" ) ]
+mod try_into_test;
+
 #[ cfg( feature = "derive_reflect" ) ]
 mod reflect_common_test;
 #[ cfg( feature = "derive_reflect" ) ]
@@ -78,6 +97,20 @@ mod reflect_struct_manual_test;
 mod reflect_struct_in_struct_manual_test;
 #[ cfg( feature = "derive_reflect" ) ]
 mod reflect_struct_with_lifetime_manual_test;
+#[ cfg( feature = "derive_reflect" ) ]
+mod reflect_struct_test;
+#[ cfg( feature = "derive_reflect" ) ]
+mod reflect_enum_test;
+#[ cfg( feature = "derive_reflect" ) ]
+mod reflect_value_test;
+#[ cfg( feature = "derive_reflect" ) ]
+mod reflect_container_test;
+#[ cfg( feature = "derive_reflect" ) ]
+mod reflect_tree_test;
+#[ cfg( feature = "derive_display" ) ]
+mod display_test;
+#[ cfg( all( feature = "derive_display", feature = "derive_from_str" ) ) ]
+mod from_str_test;
 
 // #[ cfg( all( feature = "type_variadic_from" ) ) ]
 // mod variadic_from_manual_test;
@@ -0,0 +1,34 @@
+#[ test ]
+fn deref_generic()
+{
+
+  // Deref, AsRef of a structure with lifetime
+
+  let src = 13;
+  let got = Wrap( &src );
+  a_id!( **got, 13 );
+  let got : &&i32 = got.as_ref();
+  a_id!( **got, 13 );
+
+  // Deref, DerefMut, AsRef, AsMut of a structure with named field and where clause
+
+  let mut got = Named { inner : vec![ 1, 2 ] };
+  a_id!( got.len(), 2 );
+  got.push( 3 );
+  let exp : &Vec< i32 > = got.as_ref();
+  a_id!( exp, &vec![ 1, 2, 3 ] );
+  got.as_mut().clear();
+  a_id!( got.inner, Vec::< i32 >::new() );
+
+  // Deref, DerefMut, AsRef, AsMut of a structure with marked field
+
+  let mut got = Marked { a : 1, b : "x".to_string() };
+  a_id!( got.len(), 1 );
+  got.push( 'y' );
+  let exp : &String = got.as_ref();
+  a_id!( exp, "xy" );
+  got.as_mut().clear();
+  a_id!( got.b, "" );
+  a_id!( got.a, 1 );
+
+}
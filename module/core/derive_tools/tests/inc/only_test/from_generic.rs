@@ -0,0 +1,27 @@
+#[ test ]
+fn from_generic()
+{
+
+  // From of a generic structure with where clause
+
+  let got : Pair< i32, &str > = ( 1, "a" ).into();
+  let exp = Pair( 1, "a" );
+  a_id!( got, exp );
+
+  // From, InnerFrom of a structure with lifetime
+
+  let got : Lifetimed< '_ > = "a".into();
+  let exp = Lifetimed { a : "a" };
+  a_id!( got, exp );
+  let got : &str = Lifetimed { a : "b" }.into();
+  a_id!( got, "b" );
+
+  // From, InnerFrom of a structure with marked field
+
+  let got : Marked< String > = 13.into();
+  let exp = Marked { a : 13, b : String::new() };
+  a_id!( got, exp );
+  let got : i32 = Marked { a : 14, b : "x".to_string() }.into();
+  a_id!( got, 14 );
+
+}
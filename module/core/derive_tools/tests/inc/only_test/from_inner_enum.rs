@@ -0,0 +1,17 @@
+#[ test ]
+fn from_inner_enum()
+{
+
+  let got : Enum1 = 13.into();
+  let exp = Enum1::Int( 13 );
+  a_id!( got, exp );
+
+  let got : Enum1 = "a".to_string().into();
+  let exp = Enum1::Text { text : "a".to_string() };
+  a_id!( got, exp );
+
+  let got : Enum2< u8 > = 1u8.into();
+  let exp = Enum2::Value( 1 );
+  a_id!( got, exp );
+
+}
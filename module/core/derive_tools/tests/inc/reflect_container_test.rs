@@ -0,0 +1,206 @@
+use super::*;
+pub use TheModule::reflect;
+use std::collections::{ HashMap, HashSet, BTreeMap };
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct Struct1
+{
+  pub vec : Vec< i32 >,
+  pub map : BTreeMap< String, Struct2 >,
+  pub optional : Option< String >,
+  pub boxed : Box< Struct2 >,
+}
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct Struct2
+{
+  pub s1 : i32,
+}
+
+#[ derive( Debug, Clone, PartialEq, Eq, Hash, TheModule::Reflect ) ]
+pub struct Key
+{
+  pub k : i32,
+}
+
+// --
+
+#[ test ]
+fn vec()
+{
+  use reflect::{ Instance, Entity, InstanceValue, Primitive };
+
+  let ins = vec![ 1, 2, 3 ];
+  a_id!( ins.reflect().is_container(), true );
+  a_id!( ins.reflect().len(), 3 );
+  a_id!( ins.reflect().type_name(), "alloc::vec::Vec<i32>" );
+  let keys = ins.reflect().elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( keys, vec![ Primitive::usize( 0 ), Primitive::usize( 1 ), Primitive::usize( 2 ) ] );
+  let types = ins.reflect().elements().map( | e | e.val.type_name() ).collect::< Vec< _ > >();
+  a_id!( types, vec![ "i32", "i32", "i32" ] );
+  a_id!( < Vec< i32 > as Instance >::Reflect().len(), 0 );
+
+  let mut ins = ins;
+  a_id!( ins.get( "[1]" ).unwrap().primitive(), Some( Primitive::i32( 2 ) ) );
+  ins.set( "[2]", Primitive::i32( 13 ) ).unwrap();
+  a_id!( ins, vec![ 1, 2, 13 ] );
+}
+
+//
+
+#[ test ]
+fn option()
+{
+  use reflect::{ Instance, Entity, InstanceValue, Primitive };
+
+  let ins : Option< i32 > = None;
+  a_id!( ins.reflect().is_container(), true );
+  a_id!( ins.reflect().len(), 0 );
+  a_id!( ins.reflect().elements().count(), 0 );
+  a_id!( ins.values().count(), 0 );
+
+  let mut ins = Some( 1 );
+  a_id!( ins.reflect().len(), 1 );
+  let keys = ins.reflect().elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( keys, vec![ Primitive::usize( 0 ) ] );
+  ins.set( "[0]", Primitive::i32( 2 ) ).unwrap();
+  a_id!( ins, Some( 2 ) );
+}
+
+//
+
+#[ test ]
+fn maps()
+{
+  use reflect::{ Instance, Entity, InstanceValue, Primitive };
+
+  let mut ins = HashMap::new();
+  ins.insert( "a", 1 );
+  a_id!( ins.reflect().is_container(), true );
+  a_id!( ins.reflect().len(), 1 );
+  let keys = ins.reflect().elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( keys, vec![ Primitive::str( "a" ) ] );
+  ins.set( "a", Primitive::i32( 2 ) ).unwrap();
+  a_id!( ins[ "a" ], 2 );
+
+  let mut ins = BTreeMap::new();
+  ins.insert( 3u8, "x".to_string() );
+  ins.insert( 1u8, "y".to_string() );
+  let keys = ins.reflect().elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( keys, vec![ Primitive::u8( 1 ), Primitive::u8( 3 ) ] );
+  // inherent methods of maps shadow methods of `InstanceValue`
+  a_id!( InstanceValue::get( &ins, "[3]" ).unwrap().primitive(), Some( Primitive::String( "x".into() ) ) );
+  let got = InstanceValue::values( &ins ).map( | ( k, v ) | ( k, v.primitive() ) ).collect::< Vec< _ > >();
+  let exp = vec!
+  [
+    ( Primitive::u8( 1 ), Some( Primitive::String( "y".into() ) ) ),
+    ( Primitive::u8( 3 ), Some( Primitive::String( "x".into() ) ) ),
+  ];
+  a_id!( got, exp );
+}
+
+//
+
+#[ test ]
+fn set()
+{
+  use reflect::{ Instance, Entity, InstanceValue, Primitive };
+
+  let ins : HashSet< i32 > = [ 13, -1, 2, 100 ].into_iter().collect();
+  a_id!( ins.reflect().is_container(), true );
+  a_id!( ins.reflect().len(), 4 );
+  let got = ins.values().map( | ( k, v ) | ( k, v.primitive().unwrap() ) ).collect::< Vec< _ > >();
+  let exp = vec!
+  [
+    ( Primitive::usize( 0 ), Primitive::i32( -1 ) ),
+    ( Primitive::usize( 1 ), Primitive::i32( 2 ) ),
+    ( Primitive::usize( 2 ), Primitive::i32( 13 ) ),
+    ( Primitive::usize( 3 ), Primitive::i32( 100 ) ),
+  ];
+  a_id!( got, exp );
+  a_id!( InstanceValue::get( &ins, "[2]" ).unwrap().primitive(), Some( Primitive::i32( 13 ) ) );
+
+  let ins : HashSet< &'static str > = [ "b", "c", "a" ].into_iter().collect();
+  let got = ins.values().map( | ( _, v ) | v.primitive().unwrap() ).collect::< Vec< _ > >();
+  a_id!( got, vec![ Primitive::str( "a" ), Primitive::str( "b" ), Primitive::str( "c" ) ] );
+
+  // element by index is the element listed at the index
+  let ins : HashSet< i32 > = ( -20 .. 20 ).collect();
+  for ( i, ( k, v ) ) in ins.values().enumerate()
+  {
+    a_id!( k, Primitive::usize( i ) );
+    a_id!( ins.element( &k ).unwrap().primitive(), v.primitive() );
+  }
+
+  // set of not primitives is listed in order of iteration
+  let ins : HashSet< Key > = [ Key { k : 1 }, Key { k : 2 } ].into_iter().collect();
+  a_id!( ins.values().count(), 2 );
+  for ( k, v ) in ins.values()
+  {
+    a_id!( ins.element( &k ).map( | e | e.as_any().downcast_ref::< Key >() ), Some( v.as_any().downcast_ref::< Key >() ) );
+  }
+}
+
+//
+
+#[ test ]
+fn map_not_primitive_keys()
+{
+  use reflect::{ Instance, Entity, InstanceValue, Primitive };
+
+  let mut ins = HashMap::new();
+  ins.insert( Key { k : 1 }, 1 );
+  a_id!( ins.reflect().len(), 0 );
+  a_id!( ins.reflect().elements().count(), 0 );
+  a_id!( InstanceValue::values( &ins ).count(), 0 );
+  a_id!( InstanceValue::get( &ins, "[0]" ).err(), Some( reflect::ReflectError::NoElement( Primitive::usize( 0 ) ) ) );
+}
+
+//
+
+#[ test ]
+fn boxed()
+{
+  use reflect::{ Instance, Entity, InstanceValue, Primitive };
+
+  let mut ins = Box::new( Struct2 { s1 : 1 } );
+  a_id!( ins.reflect().type_name(), "derive_tests::inc::reflect_container_test::Struct2" );
+  a_id!( ins.reflect().len(), 1 );
+  ins.set( "s1", Primitive::i32( 2 ) ).unwrap();
+  a_id!( ins.s1, 2 );
+}
+
+//
+
+#[ test ]
+fn in_struct()
+{
+  use reflect::{ Instance, Entity, InstanceValue, Primitive };
+
+  let mut map = BTreeMap::new();
+  map.insert( "k".to_string(), Struct2 { s1 : 2 } );
+  let mut ins = Struct1
+  {
+    vec : vec![ 1 ],
+    map,
+    optional : None,
+    boxed : Box::new( Struct2 { s1 : 3 } ),
+  };
+
+  let types = ins.reflect().elements().map( | e | e.val.type_name() ).collect::< Vec< _ > >();
+  let exp = vec!
+  [
+    "alloc::vec::Vec<i32>",
+    "alloc::collections::btree::map::BTreeMap<alloc::string::String, derive_tests::inc::reflect_container_test::Struct2>",
+    "core::option::Option<alloc::string::String>",
+    "derive_tests::inc::reflect_container_test::Struct2",
+  ];
+  a_id!( types, exp );
+
+  a_id!( ins.get( "vec[0]" ).unwrap().primitive(), Some( Primitive::i32( 1 ) ) );
+  a_id!( ins.get( "map.k.s1" ).unwrap().primitive(), Some( Primitive::i32( 2 ) ) );
+  a_id!( ins.get( "boxed.s1" ).unwrap().primitive(), Some( Primitive::i32( 3 ) ) );
+  a_id!( ins.get( "optional[0]" ).err(), Some( reflect::ReflectError::NoElement( Primitive::usize( 0 ) ) ) );
+  ins.set( "map.k.s1", Primitive::i32( 20 ) ).unwrap();
+  a_id!( ins.map[ "k" ].s1, 20 );
+}
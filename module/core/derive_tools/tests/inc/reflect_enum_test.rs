@@ -0,0 +1,40 @@
+use super::*;
+pub use TheModule::reflect;
+
+#[ allow( dead_code ) ]
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub enum Enum1
+{
+  Unit,
+  Tuple( i32, String ),
+  Named { f1 : i32, f2 : &'static str },
+}
+
+// --
+
+#[ test ]
+fn reflect_enum()
+{
+  use reflect::{ Instance, Entity };
+
+  let ins = Enum1::Tuple( 1, "2".into() );
+  a_id!( ins.reflect().is_container(), true );
+  a_id!( ins.reflect().len(), 3 );
+  a_id!( ins.reflect().type_name(), "derive_tests::inc::reflect_enum_test::Enum1" );
+  a_id!( ins.reflect().type_id(), core::any::TypeId::of::< Enum1 >() );
+  let names = ins.reflect().elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( names, vec![ reflect::Primitive::str( "Unit" ), reflect::Primitive::str( "Tuple" ), reflect::Primitive::str( "Named" ) ] );
+  let lens = ins.reflect().elements().map( | e | e.val.len() ).collect::< Vec< _ > >();
+  a_id!( lens, vec![ 0, 2, 2 ] );
+
+  let tuple = ins.reflect().elements().nth( 1 ).unwrap();
+  a_id!( tuple.val.is_container(), true );
+  let names = tuple.val.elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( names, vec![ reflect::Primitive::usize( 0 ), reflect::Primitive::usize( 1 ) ] );
+
+  let named = ins.reflect().elements().nth( 2 ).unwrap();
+  let names = named.val.elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( names, vec![ reflect::Primitive::str( "f1" ), reflect::Primitive::str( "f2" ) ] );
+  let types = named.val.elements().map( | e | e.val.type_name() ).collect::< Vec< _ > >();
+  a_id!( types, vec![ "i32", "&str" ] );
+}
@@ -0,0 +1,139 @@
+use super::*;
+pub use TheModule::reflect;
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct Struct1
+{
+  pub f1 : i32,
+  pub f2 : String,
+  pub f3 : Struct2,
+}
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct Struct2
+{
+  pub s1 : i32,
+  pub s2 : String,
+  pub s3 : &'static str,
+}
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct StructTuple( pub i32, pub String );
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct StructWithLifetime< 'a, 'b >
+{
+  pub f1 : &'a i32,
+  pub f2 : i32,
+  pub f3 : &'b str,
+}
+
+// --
+
+#[ test ]
+fn reflect_struct()
+{
+  use reflect::{ Instance, Entity };
+
+  let ins = Struct1
+  {
+    f1 : 1,
+    f2 : "2".into(),
+    f3 : Struct2 { s1 : 10, s2 : "20".into(), s3 : "30" },
+  };
+
+  a_id!( ins.reflect().is_container(), true );
+  a_id!( ins.reflect().len(), 3 );
+  a_id!( ins.reflect().type_name(), "derive_tests::inc::reflect_struct_test::Struct1" );
+  a_id!( ins.reflect().type_id(), core::any::TypeId::of::< Struct1 >() );
+  let names = ins.reflect().elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( names, vec![ reflect::Primitive::str( "f1" ), reflect::Primitive::str( "f2" ), reflect::Primitive::str( "f3" ) ] );
+  let types = ins.reflect().elements().map( | e | e.val.type_name() ).collect::< Vec< _ > >();
+  a_id!( types, vec![ "i32", "alloc::string::String", "derive_tests::inc::reflect_struct_test::Struct2" ] );
+
+  let f3 = ins.reflect().elements().nth( 2 ).unwrap();
+  a_id!( f3.key, reflect::Primitive::str( "f3" ) );
+  a_id!( f3.val.is_container(), true );
+  a_id!( f3.val.len(), 3 );
+  let names = f3.val.elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( names, vec![ reflect::Primitive::str( "s1" ), reflect::Primitive::str( "s2" ), reflect::Primitive::str( "s3" ) ] );
+  let types = f3.val.elements().map( | e | e.val.type_name() ).collect::< Vec< _ > >();
+  a_id!( types, vec![ "i32", "alloc::string::String", "&str" ] );
+}
+
+//
+
+#[ test ]
+fn reflect_struct_tuple()
+{
+  use reflect::{ Instance, Entity };
+
+  let ins = StructTuple( 1, "2".into() );
+  a_id!( ins.reflect().is_container(), true );
+  a_id!( ins.reflect().len(), 2 );
+  let names = ins.reflect().elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( names, vec![ reflect::Primitive::usize( 0 ), reflect::Primitive::usize( 1 ) ] );
+  let types = ins.reflect().elements().map( | e | e.val.type_name() ).collect::< Vec< _ > >();
+  a_id!( types, vec![ "i32", "alloc::string::String" ] );
+}
+
+//
+
+#[ test ]
+fn reflect_struct_with_lifetime()
+{
+  use reflect::{ Instance, Entity };
+
+  let x = 1;
+  let z = "3";
+  let ins = StructWithLifetime { f1 : &x, f2 : 2, f3 : z };
+  a_id!( ins.reflect().len(), 3 );
+  a_id!( ins.reflect().type_id(), core::any::TypeId::of::< StructWithLifetime< 'static, 'static > >() );
+  let types = ins.reflect().elements().map( | e | e.val.type_name() ).collect::< Vec< _ > >();
+  a_id!( types, vec![ "&i32", "i32", "&str" ] );
+}
+
+//
+
+mod facade
+{
+  pub use ::derive_tools as tools;
+}
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+#[ reflect( crate = facade::tools ) ]
+pub struct StructCratePath
+{
+  pub f1 : i32,
+}
+
+#[ test ]
+fn reflect_struct_crate_path()
+{
+  use reflect::{ Instance, Entity };
+
+  let ins = StructCratePath { f1 : 1 };
+  a_id!( ins.reflect().len(), 1 );
+  let names = ins.reflect().elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( names, vec![ reflect::Primitive::str( "f1" ) ] );
+}
+
+//
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct StructRaw
+{
+  pub r#type : i32,
+  pub r#loop : bool,
+}
+
+#[ test ]
+fn reflect_struct_raw_names()
+{
+  use reflect::{ Instance, Entity, InstanceValue, Primitive };
+
+  let ins = StructRaw { r#type : 1, r#loop : true };
+  let names = ins.reflect().elements().map( | e | e.key ).collect::< Vec< _ > >();
+  a_id!( names, vec![ reflect::Primitive::str( "type" ), reflect::Primitive::str( "loop" ) ] );
+  a_id!( ins.get( "type" ).unwrap().primitive(), Some( Primitive::i32( 1 ) ) );
+}
@@ -0,0 +1,243 @@
+use super::*;
+pub use TheModule::reflect;
+
+#[ derive( Debug, Clone, PartialEq, Default, TheModule::Reflect ) ]
+pub struct Struct1
+{
+  pub f1 : i32,
+  pub f2 : String,
+  pub f3 : Struct2,
+}
+
+#[ derive( Debug, Clone, PartialEq, Default, TheModule::Reflect ) ]
+pub struct Struct2
+{
+  pub s1 : [ u8 ; 3 ],
+  pub s2 : f64,
+}
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub enum Shape
+{
+  Point,
+  Circle { r : f64, filled : bool },
+  Segment( i32, i32 ),
+}
+
+#[ derive( Debug, Clone, PartialEq, Default, TheModule::Reflect ) ]
+pub struct Empty
+{
+}
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct Node
+{
+  pub id : u8,
+}
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub enum Item
+{
+  Empty,
+  Node( Node ),
+}
+
+fn ins() -> Struct1
+{
+  Struct1
+  {
+    f1 : 1,
+    f2 : "a \"b\"\n".into(),
+    f3 : Struct2 { s1 : [ 10, 11, 12 ], s2 : 2.5 },
+  }
+}
+
+// --
+
+#[ test ]
+fn from_value()
+{
+  use reflect::{ Tree, Primitive };
+
+  let got = Tree::from_value( &ins().f3 );
+  let exp = Tree::Map( vec!
+  [
+    ( Primitive::str( "s1" ), Tree::Container( vec!
+    [
+      ( Primitive::usize( 0 ), Tree::Scalar( Primitive::u8( 10 ) ) ),
+      ( Primitive::usize( 1 ), Tree::Scalar( Primitive::u8( 11 ) ) ),
+      ( Primitive::usize( 2 ), Tree::Scalar( Primitive::u8( 12 ) ) ),
+    ])),
+    ( Primitive::str( "s2" ), Tree::Scalar( Primitive::f64( 2.5 ) ) ),
+  ]);
+  a_id!( got, exp );
+  a_id!( Tree::from_value( &13 ), Tree::Scalar( Primitive::i32( 13 ) ) );
+}
+
+//
+
+#[ test ]
+fn to_json()
+{
+  use reflect::Tree;
+
+  let got = Tree::from_value( &ins() ).to_json();
+  let exp = r#"{"f1":1,"f2":"a \"b\"\n","f3":{"s1":[10,11,12],"s2":2.5}}"#;
+  a_id!( got, exp );
+
+  let got = Tree::from_value( &vec![ Some( 1u8 ), None ] ).to_json();
+  a_id!( got, "[[1],[]]" );
+  a_id!( Tree::from_value( &f64::NAN ).to_json(), "null" );
+
+  // empty structure and map are objects, empty sequence is array
+  a_id!( Tree::from_value( &Empty {} ).to_json(), "{}" );
+  a_id!( Tree::from_value( &std::collections::HashMap::< u8, u8 >::new() ).to_json(), "{}" );
+  a_id!( Tree::from_value( &Vec::< u8 >::new() ).to_json(), "[]" );
+  a_id!( Tree::from_json( "{}" ).unwrap().to_json(), "{}" );
+}
+
+//
+
+#[ test ]
+fn to_text()
+{
+  use reflect::Tree;
+
+  let got = Tree::from_value( &ins() ).to_text();
+  let exp = r#"f1 : 1
+f2 : "a \"b\"\n"
+f3 :
+  s1 :
+    [0] : 10
+    [1] : 11
+    [2] : 12
+  s2 : 2.5
+"#;
+  a_id!( got, exp );
+  a_id!( Tree::from_value( &"x".to_string() ).to_text(), "\"x\"\n" );
+
+  let got = Tree::from_json( r#"{ "a" : {}, "b" : [] }"# ).unwrap().to_text();
+  a_id!( got, "a : {}\nb : []\n" );
+}
+
+//
+
+#[ test ]
+fn from_json()
+{
+  use reflect::{ Tree, Primitive, ReflectError };
+
+  let got = Tree::from_json( r#" { "a" : [ 1, -2, 3.5, 18446744073709551615 ], "b" : "A\t", "c" : null } "# ).unwrap();
+  let exp = Tree::Map( vec!
+  [
+    ( Primitive::String( "a".into() ), Tree::Container( vec!
+    [
+      ( Primitive::usize( 0 ), Tree::Scalar( Primitive::i64( 1 ) ) ),
+      ( Primitive::usize( 1 ), Tree::Scalar( Primitive::i64( -2 ) ) ),
+      ( Primitive::usize( 2 ), Tree::Scalar( Primitive::f64( 3.5 ) ) ),
+      ( Primitive::usize( 3 ), Tree::Scalar( Primitive::u64( u64::MAX ) ) ),
+    ])),
+    ( Primitive::String( "b".into() ), Tree::Scalar( Primitive::String( "A\t".into() ) ) ),
+    ( Primitive::String( "c".into() ), Tree::Scalar( Primitive::None ) ),
+  ]);
+  a_id!( got, exp );
+
+  a_id!( Tree::from_json( "[ true, false ]" ).unwrap().to_json(), "[true,false]" );
+  a_true!( matches!( Tree::from_json( "tru" ), Err( ReflectError::BadFormat( _ ) ) ) );
+  a_true!( matches!( Tree::from_json( "[1,]" ), Err( ReflectError::BadFormat( _ ) ) ) );
+  a_true!( matches!( Tree::from_json( "{} x" ), Err( ReflectError::BadFormat( _ ) ) ) );
+
+  // character out of basic plane is escaped as surrogate pair
+  let got = Tree::from_json( r#""\u00e9\ud83d\ude00""# ).unwrap();
+  a_id!( got, Tree::Scalar( Primitive::String( "\u{e9}\u{1f600}".into() ) ) );
+  a_true!( matches!( Tree::from_json( r#""\ud83d""# ), Err( ReflectError::BadFormat( _ ) ) ) );
+  a_true!( matches!( Tree::from_json( r#""\ud83d\u0041""# ), Err( ReflectError::BadFormat( _ ) ) ) );
+  a_true!( matches!( Tree::from_json( r#""\ude00""# ), Err( ReflectError::BadFormat( _ ) ) ) );
+}
+
+//
+
+#[ test ]
+fn apply()
+{
+  use reflect::{ Tree, Primitive, ReflectError };
+
+  // round trip through json
+  let json = Tree::from_value( &ins() ).to_json();
+  let mut got = Struct1::default();
+  Tree::from_json( &json ).unwrap().apply( &mut got ).unwrap();
+  a_id!( got, ins() );
+
+  // partial tree populates only its elements
+  let mut got = ins();
+  Tree::from_json( r#"{ "f3" : { "s1" : [ 1 ] } }"# ).unwrap().apply( &mut got ).unwrap();
+  a_id!( got.f3.s1, [ 1, 11, 12 ] );
+  a_id!( got.f1, 1 );
+
+  // maps are populated by keys of objects
+  let mut got = std::collections::HashMap::from([ ( 1u8, 10i32 ), ( 2, 20 ) ]);
+  Tree::from_json( r#"{ "2" : 21 }"# ).unwrap().apply( &mut got ).unwrap();
+  a_id!( got[ &2 ], 21 );
+
+  // errors
+  let mut got = ins();
+  let err = Tree::from_json( r#"{ "f4" : 1 }"# ).unwrap().apply( &mut got );
+  a_id!( err, Err( ReflectError::NoElement( Primitive::String( "f4".into() ) ) ) );
+  let err = Tree::from_json( r#"{ "f1" : "x" }"# ).unwrap().apply( &mut got );
+  a_true!( matches!( err, Err( ReflectError::TypeMismatch { expected : "i32", .. } ) ) );
+
+  // containers are not grown
+  let err = Tree::from_json( r#"{ "f3" : { "s1" : [ 1, 2, 3, 4 ] } }"# ).unwrap().apply( &mut got );
+  a_id!( err, Err( ReflectError::NoElement( Primitive::usize( 3 ) ) ) );
+  let mut got = vec![ 1 ];
+  let err = Tree::from_json( "[ 1, 2 ]" ).unwrap().apply( &mut got );
+  a_id!( err, Err( ReflectError::NoElement( Primitive::usize( 1 ) ) ) );
+}
+
+//
+
+#[ test ]
+fn enums()
+{
+  use reflect::{ Tree, Primitive, ReflectError };
+
+  let ins = Shape::Circle { r : 1.5, filled : true };
+  let got = Tree::from_value( &ins );
+  let exp = Tree::Map( vec!
+  [
+    ( Primitive::str( "Circle" ), Tree::Map( vec!
+    [
+      ( Primitive::str( "r" ), Tree::Scalar( Primitive::f64( 1.5 ) ) ),
+      ( Primitive::str( "filled" ), Tree::Scalar( Primitive::bool( true ) ) ),
+    ])),
+  ]);
+  a_id!( got, exp );
+  a_id!( got.to_json(), r#"{"Circle":{"r":1.5,"filled":true}}"# );
+  a_id!( Tree::from_value( &Shape::Point ).to_json(), r#"{"Point":{}}"# );
+
+  // variant is switched
+  for src in [ Shape::Point, Shape::Circle { r : 2.0, filled : false }, Shape::Segment( 1, -1 ) ]
+  {
+    let json = Tree::from_value( &src ).to_json();
+    let mut got = Shape::Circle { r : 1.5, filled : true };
+    Tree::from_json( &json ).unwrap().apply( &mut got ).unwrap();
+    a_id!( got, src );
+  }
+  let mut got = Shape::Point;
+  Tree::from_json( r#"{ "Circle" : { "r" : 3 } }"# ).unwrap().apply( &mut got ).unwrap();
+  a_id!( got, Shape::Circle { r : 3.0, filled : false } );
+
+  // errors
+  let mut got = Shape::Point;
+  let err = Tree::from_json( r#"{ "Square" : {} }"# ).unwrap().apply( &mut got );
+  a_id!( err, Err( ReflectError::NoVariant( "Square".into() ) ) );
+  let err = Tree::from_json( r#"{ "Point" : {}, "Segment" : [] }"# ).unwrap().apply( &mut got );
+  a_true!( matches!( err, Err( ReflectError::BadFormat( _ ) ) ) );
+  let mut got = Item::Empty;
+  let err = Tree::from_json( r#"{ "Node" : [ { "id" : 1 } ] }"# ).unwrap().apply( &mut got );
+  a_id!( err, Err( ReflectError::NoVariant( "Node".into() ) ) );
+  a_id!( got, Item::Empty );
+  let mut got = Item::Node( Node { id : 0 } );
+  Tree::from_json( r#"{ "Node" : [ { "id" : 1 } ] }"# ).unwrap().apply( &mut got ).unwrap();
+  a_id!( got, Item::Node( Node { id : 1 } ) );
+}
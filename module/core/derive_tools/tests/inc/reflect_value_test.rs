@@ -0,0 +1,128 @@
+use super::*;
+pub use TheModule::reflect;
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct Struct1
+{
+  pub f1 : i32,
+  pub f2 : String,
+  pub f3 : Struct2,
+}
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub struct Struct2
+{
+  pub s1 : [ u8 ; 3 ],
+  pub s2 : Enum1,
+}
+
+#[ derive( Debug, Clone, PartialEq, TheModule::Reflect ) ]
+pub enum Enum1
+{
+  Unit,
+  Tuple( i32, &'static str ),
+  Named { f1 : f64 },
+}
+
+fn ins() -> Struct1
+{
+  Struct1
+  {
+    f1 : 1,
+    f2 : "2".into(),
+    f3 : Struct2 { s1 : [ 10, 11, 12 ], s2 : Enum1::Tuple( 13, "14" ) },
+  }
+}
+
+// --
+
+#[ test ]
+fn path_parse()
+{
+  use reflect::Primitive;
+
+  a_id!( reflect::path_parse( "" ), Ok( vec![] ) );
+  a_id!( reflect::path_parse( "a" ), Ok( vec![ Primitive::String( "a".into() ) ] ) );
+  let exp = vec![ Primitive::String( "a".into() ), Primitive::String( "b".into() ), Primitive::usize( 3 ), Primitive::String( "c".into() ) ];
+  a_id!( reflect::path_parse( "a.b[3].c" ), Ok( exp ) );
+  a_id!( reflect::path_parse( "[1][2]" ), Ok( vec![ Primitive::usize( 1 ), Primitive::usize( 2 ) ] ) );
+  a_id!( reflect::path_parse( "a..b" ), Err( reflect::ReflectError::BadPath( "a..b".into() ) ) );
+  a_id!( reflect::path_parse( "a[x]" ), Err( reflect::ReflectError::BadPath( "a[x]".into() ) ) );
+  a_id!( reflect::path_parse( "a[1" ), Err( reflect::ReflectError::BadPath( "a[1".into() ) ) );
+}
+
+//
+
+#[ test ]
+fn get()
+{
+  use reflect::{ InstanceValue, Primitive };
+
+  let ins = ins();
+  a_id!( ins.get( "f1" ).unwrap().primitive(), Some( Primitive::i32( 1 ) ) );
+  a_id!( ins.get( "f2" ).unwrap().primitive(), Some( Primitive::String( "2".into() ) ) );
+  a_id!( ins.get( "f3.s1[1]" ).unwrap().primitive(), Some( Primitive::u8( 11 ) ) );
+  a_id!( ins.get( "f3.s2[1]" ).unwrap().primitive(), Some( Primitive::str( "14" ) ) );
+  a_id!( ins.get( "f3" ).unwrap().primitive(), None );
+  a_id!( ins.get( "f3" ).unwrap().downcast_ref::< Struct2 >(), Some( &ins.f3 ) );
+  a_id!( ins.get( "f3.s1" ).unwrap().as_any().downcast_ref::< [ u8 ; 3 ] >(), Some( &[ 10, 11, 12 ] ) );
+  a_id!( ins.get( "f3.s1[3]" ).err(), Some( reflect::ReflectError::NoElement( Primitive::usize( 3 ) ) ) );
+  a_id!( ins.get( "f4" ).err(), Some( reflect::ReflectError::NoElement( Primitive::String( "f4".into() ) ) ) );
+  a_id!( ins.get( "f3.s2.f1" ).err(), Some( reflect::ReflectError::NoElement( Primitive::String( "f1".into() ) ) ) );
+}
+
+//
+
+#[ test ]
+fn set()
+{
+  use reflect::{ InstanceValue, Primitive };
+
+  let mut ins = ins();
+  ins.set( "f1", Primitive::u8( 5 ) ).unwrap();
+  ins.set( "f2", Primitive::str( "x" ) ).unwrap();
+  ins.set( "f3.s1[0]", Primitive::i64( 255 ) ).unwrap();
+  ins.set( "f3.s2[0]", Primitive::i32( 7 ) ).unwrap();
+  a_id!( ins.f1, 5 );
+  a_id!( ins.f2, "x".to_string() );
+  a_id!( ins.f3.s1, [ 255, 11, 12 ] );
+  a_id!( ins.f3.s2, Enum1::Tuple( 7, "14" ) );
+
+  let got = ins.set( "f3.s1[0]", Primitive::i32( 256 ) );
+  a_id!( got, Err( reflect::ReflectError::TypeMismatch { expected : "u8", got : Primitive::i32( 256 ) } ) );
+  let got = ins.set( "f3", Primitive::i32( 1 ) );
+  a_true!( got.is_err() );
+
+  *ins.get_mut( "f3.s2" ).unwrap().downcast_mut::< Enum1 >().unwrap() = Enum1::Named { f1 : 1.0 };
+  ins.set( "f3.s2.f1", Primitive::f32( 2.5 ) ).unwrap();
+  a_id!( ins.f3.s2, Enum1::Named { f1 : 2.5 } );
+}
+
+//
+
+#[ test ]
+fn values()
+{
+  use reflect::{ InstanceValue, Primitive };
+
+  let ins = ins();
+  let got = ins.values().map( | ( k, v ) | ( k, v.primitive() ) ).collect::< Vec< _ > >();
+  let exp = vec!
+  [
+    ( Primitive::str( "f1" ), Some( Primitive::i32( 1 ) ) ),
+    ( Primitive::str( "f2" ), Some( Primitive::String( "2".into() ) ) ),
+    ( Primitive::str( "f3" ), None ),
+  ];
+  a_id!( got, exp );
+
+  let got = ins.get( "f3.s1" ).unwrap().values().map( | ( k, v ) | ( k, v.primitive() ) ).collect::< Vec< _ > >();
+  let exp = vec!
+  [
+    ( Primitive::usize( 0 ), Some( Primitive::u8( 10 ) ) ),
+    ( Primitive::usize( 1 ), Some( Primitive::u8( 11 ) ) ),
+    ( Primitive::usize( 2 ), Some( Primitive::u8( 12 ) ) ),
+  ];
+  a_id!( got, exp );
+
+  a_id!( Enum1::Unit.values().count(), 0 );
+}
@@ -0,0 +1,64 @@
+use super::*;
+
+#[ derive( Debug, PartialEq, TheModule::TryInto ) ]
+pub enum Value
+{
+  Int( i32 ),
+  #[ try_into( skip ) ]
+  Other( i32 ),
+  Text { text : String },
+  Pair( u8, bool ),
+  Empty,
+}
+
+#[ derive( Debug, PartialEq, TheModule::TryInto ) ]
+pub enum Generic< T >
+{
+  Item( T ),
+  Pair( T, u8 ),
+  List( Vec< T > ),
+  Count( usize ),
+}
+
+// --
+
+#[ test ]
+fn try_into()
+{
+  let got : Result< i32, _ > = Value::Int( 13 ).try_into();
+  a_id!( got, Ok( 13 ) );
+  let got : Result< i32, _ > = Value::Other( 13 ).try_into();
+  a_id!( got, Err( Value::Other( 13 ) ) );
+  let got : Result< String, _ > = Value::Text { text : "a".into() }.try_into();
+  a_id!( got, Ok( "a".to_string() ) );
+  let got : Result< ( u8, bool ), _ > = Value::Pair( 1, true ).try_into();
+  a_id!( got, Ok( ( 1, true ) ) );
+  let got = String::try_from( Value::Empty );
+  a_id!( got, Err( Value::Empty ) );
+}
+
+//
+
+#[ test ]
+fn try_into_generic()
+{
+  a_id!( Value::Int( 13 ).try_into_int(), Ok( 13 ) );
+  a_id!( Value::Pair( 1, true ).try_into_int(), Err( Value::Pair( 1, true ) ) );
+
+  let got = Generic::Item( "a" ).try_into_item();
+  a_id!( got, Ok( "a" ) );
+  let got = Generic::Pair( "a", 1 ).try_into_pair();
+  a_id!( got, Ok( ( "a", 1 ) ) );
+  let got = Generic::< &str >::Count( 3 ).try_into_item();
+  a_id!( got, Err( Generic::Count( 3 ) ) );
+  let got : Result< usize, _ > = Generic::< &str >::Count( 3 ).try_into();
+  a_id!( got, Ok( 3 ) );
+
+  // only a bare type parameter can't be converted into, types which cover it can
+  let got : Result< Vec< &str >, _ > = Generic::List( vec![ "a" ] ).try_into();
+  a_id!( got, Ok( vec![ "a" ] ) );
+  let got : Result< ( &str, u8 ), _ > = Generic::Pair( "a", 1 ).try_into();
+  a_id!( got, Ok( ( "a", 1 ) ) );
+  let got = Vec::try_from( Generic::Item( "a" ) );
+  a_id!( got, Err( Generic::Item( "a" ) ) );
+}
@@ -0,0 +1,75 @@
+use super::*;
+
+#[ derive( Debug, PartialEq, TheModule::Unwrap ) ]
+pub enum Shape
+{
+  Circle( f32 ),
+  Rect { width : f32, height : f32 },
+  Empty,
+}
+
+// --
+
+#[ test ]
+fn as_variant()
+{
+  let mut src = Shape::Circle( 1.0 );
+  a_id!( src.as_circle(), Some( &1.0 ) );
+  a_id!( src.as_rect(), None );
+  *src.as_circle_mut().unwrap() = 2.0;
+  a_id!( src, Shape::Circle( 2.0 ) );
+
+  let mut src = Shape::Rect { width : 1.0, height : 2.0 };
+  a_id!( src.as_rect(), Some( ( &1.0, &2.0 ) ) );
+  *src.as_rect_mut().unwrap().1 = 3.0;
+  a_id!( src, Shape::Rect { width : 1.0, height : 3.0 } );
+}
+
+//
+
+#[ test ]
+fn into_variant()
+{
+  a_id!( Shape::Circle( 1.0 ).into_circle(), Some( 1.0 ) );
+  a_id!( Shape::Empty.into_circle(), None );
+  a_id!( Shape::Rect { width : 1.0, height : 2.0 }.into_rect(), Some( ( 1.0, 2.0 ) ) );
+  a_id!( Shape::Circle( 1.0 ).unwrap_circle(), 1.0 );
+}
+
+//
+
+#[ test ]
+#[ should_panic( expected = "called `Shape::unwrap_circle()` on another variant" ) ]
+fn unwrap_other_variant()
+{
+  Shape::Empty.unwrap_circle();
+}
+
+//
+
+mod shadowed
+{
+  #![ allow( dead_code, non_camel_case_types, unused_macros ) ]
+  use super::TheModule;
+
+  mod core {}
+  struct Option;
+  struct Some;
+  struct None;
+  macro_rules! panic { ( $( $t : tt )* ) => { compile_error!( "shadowed" ) } }
+
+  #[ derive( TheModule::Unwrap ) ]
+  pub enum Shape
+  {
+    Circle( f32 ),
+    Empty,
+  }
+
+  #[ test ]
+  fn unwrap()
+  {
+    assert!( Shape::Circle( 1.0 ).as_circle().is_some() );
+    assert!( Shape::Empty.into_circle().is_none() );
+    assert_eq!( Shape::Circle( 1.0 ).unwrap_circle(), 1.0 );
+  }
+}
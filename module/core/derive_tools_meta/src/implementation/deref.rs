@@ -1,21 +1,26 @@
 
 use super::*;
 
+//
+
 pub fn deref( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
 {
   let parsed = syn::parse::< InputParsed >( input )?;
-  let field_type = parsed.first_field_type()?;
+  let ( field_member, field_type ) = parsed.target_field( "deref" )?;
   let item_name = parsed.item_name;
+  let ( impl_generics, ty_generics, where_clause ) = parsed.generics.split_for_impl();
 
   let result = qt!
   {
-    impl core::ops::Deref for #item_name
+    #[ automatically_derived ]
+    impl #impl_generics core::ops::Deref for #item_name #ty_generics
+    #where_clause
     {
       type Target = #field_type;
       #[ inline( always ) ]
       fn deref( &self ) -> &Self::Target
       {
-        &self.0
+        &self.#field_member
       }
     }
   };
@@ -6,17 +6,20 @@ use super::*;
 pub fn deref_mut( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
 {
   let parsed = syn::parse::< InputParsed >( input )?;
-  // let field_type = parsed.first_field_type()?;
+  let ( field_member, _field_type ) = parsed.target_field( "deref" )?;
   let item_name = parsed.item_name;
+  let ( impl_generics, ty_generics, where_clause ) = parsed.generics.split_for_impl();
 
   let result = qt!
   {
-    impl core::ops::DerefMut for #item_name
+    #[ automatically_derived ]
+    impl #impl_generics core::ops::DerefMut for #item_name #ty_generics
+    #where_clause
     {
       #[ inline( always ) ]
       fn deref_mut( &mut self ) -> &mut Self::Target
       {
-        &mut self.0
+        &mut self.#field_member
       }
     }
   };
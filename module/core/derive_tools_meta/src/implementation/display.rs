@@ -0,0 +1,69 @@
+use super::*;
+use format::{ Piece, Variant };
+
+//
+
+pub fn display( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+{
+  let ast = syn::parse::< syn::DeriveInput >( input )?;
+  let item_name = &ast.ident;
+  let variants = format::variants_of( &ast )?;
+
+  let mut bounds : Vec< syn::WherePredicate > = Vec::new();
+  let arms : Vec< proc_macro2::TokenStream > = variants.iter().map( | variant | arm_generate( variant, &mut bounds ) ).collect();
+
+  let mut generics = ast.generics.clone();
+  generics.make_where_clause().predicates.extend( bounds );
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+
+  let result = qt!
+  {
+    #[ automatically_derived ]
+    impl #impl_generics ::core::fmt::Display for #item_name #ty_generics
+    #where_clause
+    {
+      #[ allow( unused_variables ) ]
+      fn fmt( &self, f : &mut ::core::fmt::Formatter< '_ > ) -> ::core::fmt::Result
+      {
+        match self
+        {
+          #( #arms )*
+        }
+      }
+    }
+  };
+
+  Ok( result )
+}
+
+/// Arm of match writing a structure or a variant by a single call of `write_fmt`, so flags of the formatter, like width,
+/// are not applied to each field, like `write!` does.
+fn arm_generate( variant : &Variant, bounds : &mut Vec< syn::WherePredicate > ) -> proc_macro2::TokenStream
+{
+  let pattern = variant.pattern();
+  let mut format = String::new();
+  let mut args = vec![];
+  for piece in &variant.pieces
+  {
+    match piece
+    {
+      Piece::Literal( literal ) => format.push_str( &literal.replace( '{', "{{" ).replace( '}', "}}" ) ),
+      Piece::Field( index ) =>
+      {
+        let field = &variant.fields[ *index ];
+        let binding = &field.binding;
+        let ty = &field.ty;
+        bounds.push( parse_qt!{ #ty : ::core::fmt::Display } );
+        format.push_str( "{}" );
+        args.push( binding );
+      },
+    }
+  }
+  qt!
+  {
+    #pattern =>
+    {
+      ::core::fmt::Formatter::write_fmt( f, ::core::format_args!( #format #( , #args )* ) )
+    },
+  }
+}
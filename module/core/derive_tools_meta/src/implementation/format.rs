@@ -0,0 +1,188 @@
+//!
+//! Format of text representation shared by derives `Display` and `FromStr`.
+//!
+
+use super::*;
+
+/// Piece of a format.
+#[ derive( Debug, Clone, PartialEq ) ]
+pub enum Piece
+{
+  /// Literal text.
+  Literal( String ),
+  /// Placeholder of a field, index of the field among fields of the variant.
+  Field( usize ),
+}
+
+/// Field of a structure or a variant, bound to a local variable.
+pub struct Field
+{
+  /// Name of the field or its index for tuple fields.
+  pub key : String,
+  /// Member to construct or destructure by.
+  pub member : syn::Member,
+  /// Local variable the field is bound to.
+  pub binding : syn::Ident,
+  /// Type of the field.
+  pub ty : syn::Type,
+}
+
+/// Structure or variant of an enum which has text representation.
+pub struct Variant
+{
+  /// Path to construct or destructure it, `Self` or `Self::Variant`.
+  pub path : proc_macro2::TokenStream,
+  /// Its fields.
+  pub fields : Vec< Field >,
+  /// Pieces of its format.
+  pub pieces : Vec< Piece >,
+  /// Span of the format to report errors.
+  pub span : proc_macro2::Span,
+}
+
+impl Variant
+{
+
+  /// Pattern to destructure the variant binding all its fields.
+  pub fn pattern( &self ) -> proc_macro2::TokenStream
+  {
+    let path = &self.path;
+    let members = self.fields.iter().map( | field | &field.member );
+    let bindings = self.fields.iter().map( | field | &field.binding );
+    qt!{ #path { #( #members : #bindings ),* } }
+  }
+
+}
+
+/// Parse format like `"{a} - {b}"`. Placeholders refer to fields by name or by index, `{{` and `}}` are escaped braces.
+pub fn pieces_parse( format : &syn::LitStr, fields : &[ Field ] ) -> Result< Vec< Piece > >
+{
+  let src = format.value();
+  let mut result = Vec::new();
+  let mut literal = String::new();
+  let mut chars = src.chars().peekable();
+  while let Some( c ) = chars.next()
+  {
+    match c
+    {
+      '{' if chars.peek() == Some( &'{' ) =>
+      {
+        chars.next();
+        literal.push( '{' );
+      },
+      '}' if chars.peek() == Some( &'}' ) =>
+      {
+        chars.next();
+        literal.push( '}' );
+      },
+      '{' =>
+      {
+        let mut key = String::new();
+        loop
+        {
+          match chars.next()
+          {
+            Some( '}' ) => break,
+            Some( c ) => key.push( c ),
+            None => return Err( syn_err!( format, "Unclosed placeholder in format {:?}", src ) ),
+          }
+        }
+        // field named by raw identifier could be referred with or without prefix `r#`
+        let key = key.trim();
+        let key = key.strip_prefix( "r#" ).unwrap_or( key );
+        let index = fields.iter().position( | field | field.key == key )
+        .ok_or_else( || syn_err!( format, "Unknown field `{}` in format {:?}", key, src ) )?;
+        if !literal.is_empty()
+        {
+          result.push( Piece::Literal( core::mem::take( &mut literal ) ) );
+        }
+        result.push( Piece::Field( index ) );
+      },
+      '}' => return Err( syn_err!( format, "Unmatched `}}` in format {:?}", src ) ),
+      c => literal.push( c ),
+    }
+  }
+  if !literal.is_empty()
+  {
+    result.push( Piece::Literal( literal ) );
+  }
+  Ok( result )
+}
+
+/// Fields of a structure or a variant.
+pub fn fields_of( fields : &syn::Fields ) -> Vec< Field >
+{
+  fields.iter().enumerate().map( | ( index, field ) |
+  {
+    let ( key, member ) = match &field.ident
+    {
+      Some( ident ) => ( syn::ext::IdentExt::unraw( ident ).to_string(), syn::Member::Named( ident.clone() ) ),
+      None => ( index.to_string(), syn::Member::Unnamed( syn::Index::from( index ) ) ),
+    };
+    let binding = macro_tools::format_ident!( "field_{}", key );
+    Field { key, member, binding, ty : field.ty.clone() }
+  }).collect()
+}
+
+/// Format given by attribute `#[ display( "..." ) ]` if any.
+pub fn format_attr( attrs : &[ syn::Attribute ] ) -> Result< Option< syn::LitStr > >
+{
+  let mut result = None;
+  for attr in attrs.iter().filter( | attr | attr.path.is_ident( "display" ) )
+  {
+    if result.is_some()
+    {
+      return Err( syn_err!( attr, "Duplicated attribute `display`" ) );
+    }
+    result = Some( attr.parse_args::< syn::LitStr >()? );
+  }
+  Ok( result )
+}
+
+/// Format of a structure or a variant without attribute: name of a unit, the only field of a newtype.
+fn format_default( name : &syn::Ident, fields : &[ Field ] ) -> Result< Vec< Piece > >
+{
+  match fields.len()
+  {
+    0 => Ok( vec![ Piece::Literal( name.to_string() ) ] ),
+    1 => Ok( vec![ Piece::Field( 0 ) ] ),
+    _ => Err( syn_err!( name, "Expects attribute #[ display( \"...\" ) ] for `{}` which has several fields", name ) ),
+  }
+}
+
+/// Variants of an item having text representation, structure has the only variant.
+pub fn variants_of( ast : &syn::DeriveInput ) -> Result< Vec< Variant > >
+{
+  match &ast.data
+  {
+    syn::Data::Struct( data_struct ) =>
+    {
+      let fields = fields_of( &data_struct.fields );
+      let ( pieces, span ) = match format_attr( &ast.attrs )?
+      {
+        Some( format ) => ( pieces_parse( &format, &fields )?, format.span() ),
+        None => ( format_default( &ast.ident, &fields )?, ast.ident.span() ),
+      };
+      Ok( vec![ Variant { path : qt!{ Self }, fields, pieces, span } ] )
+    },
+    syn::Data::Enum( data_enum ) =>
+    {
+      if let Some( attr ) = ast.attrs.iter().find( | attr | attr.path.is_ident( "display" ) )
+      {
+        return Err( syn_err!( attr, "Attribute `display` of an enum should be applied to its variants" ) );
+      }
+      data_enum.variants.iter().map( | variant |
+      {
+        let ident = &variant.ident;
+        let fields = fields_of( &variant.fields );
+        let ( pieces, span ) = match format_attr( &variant.attrs )?
+        {
+          Some( format ) => ( pieces_parse( &format, &fields )?, format.span() ),
+          None => ( format_default( ident, &fields )?, ident.span() ),
+        };
+        Ok( Variant { path : qt!{ Self::#ident }, fields, pieces, span } )
+      }).collect()
+    },
+    syn::Data::Union( _ ) => Err( syn_err!( ast, "Text representation could not be derived for union" ) ),
+  }
+}
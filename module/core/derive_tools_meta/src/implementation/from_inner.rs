@@ -1,4 +1,3 @@
-
 use macro_tools::proc_macro2::TokenStream;
 
 use super::*;
@@ -7,34 +6,44 @@ use super::*;
 
 pub fn from_inner( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
 {
+  let ast = syn::parse::< syn::DeriveInput >( input.clone() )?;
+  if let syn::Data::Enum( _ ) = ast.data
+  {
+    return generate_from_variants( &ast );
+  }
   let parsed = syn::parse::< InputParsed >( input )?;
-  let field_types = parsed.field_types;
-  let field_names = parsed.field_names;
-  let item_name = parsed.item_name;
-  let result = 
-  match ( field_types.len(), field_names )
+  let marked = parsed.marked_field( "from" )?;
+  let field_types = &parsed.field_types;
+  let field_names = &parsed.field_names;
+  let item_name = &parsed.item_name;
+  let generics = &parsed.generics;
+  let result =
+  match ( marked, field_types.len(), field_names )
   {
-    ( 0, _ ) => { generate_unit(item_name) },
-    ( 1, Some( field_names ) ) => generate_from_single_field_named( &field_types[ 0 ], &field_names[ 0 ], item_name ),
-    ( 1, None ) => generate_from_single_field( &field_types[ 0 ], item_name ),
-    ( _, Some( field_names ) ) => generate_from_multiple_fields_named( &field_types, &field_names, item_name ),
-    ( _, None ) => generate_from_multiple_fields( &field_types, item_name ),
+    ( Some( index ), _, _ ) => generate_from_marked_field( &parsed, index ),
+    ( None, 0, _ ) => { generate_unit( item_name, generics ) },
+    ( None, 1, Some( field_names ) ) => generate_from_single_field_named( &field_types[ 0 ], &field_names[ 0 ], item_name, generics ),
+    ( None, 1, None ) => generate_from_single_field( &field_types[ 0 ], item_name, generics ),
+    ( None, _, Some( field_names ) ) => generate_from_multiple_fields_named( field_types, field_names, item_name, generics ),
+    ( None, _, None ) => generate_from_multiple_fields( field_types, item_name, generics ),
   };
 
   Ok( result )
 }
 
-fn generate_from_single_field_named( field_type: &syn::Type, field_name: &syn::Ident, item_name: syn::Ident ) -> TokenStream 
+fn generate_from_single_field_named( field_type: &syn::Type, field_name: &syn::Ident, item_name: &syn::Ident, generics: &syn::Generics ) -> TokenStream
 {
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
   qt!
   {
     #[ automatically_derived ]
     // impl From < i32 > for MyStruct
-    impl From< #field_type > for #item_name 
+    impl #impl_generics From< #field_type > for #item_name #ty_generics
+    #where_clause
     {
       #[ inline( always ) ]
       // fn from( src: i32 ) -> Self
-      fn from( src: #field_type ) -> Self 
+      fn from( src: #field_type ) -> Self
       {
         // Self { a: src }
         Self { #field_name: src }
@@ -43,17 +52,19 @@ fn generate_from_single_field_named( field_type: &syn::Type, field_name: &syn::I
   }
 }
 
-fn generate_from_single_field( field_type: &syn::Type, item_name: syn::Ident ) -> TokenStream 
+fn generate_from_single_field( field_type: &syn::Type, item_name: &syn::Ident, generics: &syn::Generics ) -> TokenStream
 {
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
   qt!
   {
     #[automatically_derived]
     // impl From< bool > for IsTransparent
-    impl From< #field_type > for #item_name 
+    impl #impl_generics From< #field_type > for #item_name #ty_generics
+    #where_clause
     {
       #[ inline( always ) ]
       // fn from( src: bool ) -> Self
-      fn from( src: #field_type ) -> Self 
+      fn from( src: #field_type ) -> Self
       {
         // Self(src)
         Self(src)
@@ -62,36 +73,39 @@ fn generate_from_single_field( field_type: &syn::Type, item_name: syn::Ident ) -
   }
 }
 
-fn generate_from_multiple_fields_named( field_types: &Vec< syn::Type >, field_names: &Vec< syn::Ident >, item_name: syn::Ident) -> TokenStream 
+fn generate_from_multiple_fields_named( field_types: &Vec< syn::Type >, field_names: &Vec< syn::Ident >, item_name: &syn::Ident, generics: &syn::Generics ) -> TokenStream
 {
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
   let params: Vec< TokenStream > = field_names
     .iter()
     .enumerate()
-    .map(| ( index, field_name ) | 
+    .map(| ( index, field_name ) |
       {
         let index = index.to_string().parse::< TokenStream >().unwrap();
         qt! { #field_name : src.#index }
       })
       .collect();
 
-  qt! 
+  qt!
   {
     // impl From< (i32, bool) > for StructNamedFields
-    impl From< (#(#field_types), *) > for #item_name 
+    impl #impl_generics From< (#(#field_types), *) > for #item_name #ty_generics
+    #where_clause
     {
       #[ inline( always ) ]
       // fn from( src: (i32, bool) ) -> Self
-      fn from( src: (#(#field_types), *) ) -> Self 
+      fn from( src: (#(#field_types), *) ) -> Self
       {
         // StructNamedFields{ a: src.0, b: src.1 }
-        #item_name { #(#params), * }
+        Self { #(#params), * }
       }
     }
   }
 }
 
-fn generate_from_multiple_fields( field_types: &Vec< syn::Type >, item_name: syn::Ident ) -> TokenStream 
+fn generate_from_multiple_fields( field_types: &Vec< syn::Type >, item_name: &syn::Ident, generics: &syn::Generics ) -> TokenStream
 {
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
   let params: Vec<TokenStream> = ( 0..field_types.len() )
   .map( | index |
     {
@@ -100,28 +114,69 @@ fn generate_from_multiple_fields( field_types: &Vec< syn::Type >, item_name: syn
     } )
   .collect();
 
-  qt! 
+  qt!
   {
     // impl From< (i32, bool) > for StructWithManyFields
-    impl From< (#(#field_types), *) > for #item_name 
+    impl #impl_generics From< (#(#field_types), *) > for #item_name #ty_generics
+    #where_clause
     {
       #[ inline( always ) ]
       // fn from( src: (i32, bool) ) -> Self
-      fn from( src: (#(#field_types), *) ) -> Self 
+      fn from( src: (#(#field_types), *) ) -> Self
       {
         // StructWithManyFields( src.0, src.1 )
-        #item_name( #(#params), *)
+        Self( #(#params), *)
+      }
+    }
+  }
+}
+
+fn generate_from_marked_field( parsed: &InputParsed, index: usize ) -> TokenStream
+{
+  let item_name = &parsed.item_name;
+  let field_type = &parsed.field_types[ index ];
+  let ( impl_generics, ty_generics, where_clause ) = parsed.generics.split_for_impl();
+  let params: Vec< TokenStream > = ( 0..parsed.field_types.len() )
+  .map( | i |
+    {
+      let member = parsed.field_member( i );
+      if i == index
+      {
+        qt! { #member : src }
+      }
+      else
+      {
+        qt! { #member : core::default::Default::default() }
+      }
+    } )
+  .collect();
+
+  qt!
+  {
+    #[ automatically_derived ]
+    // impl From< i32 > for StructWithMarkedField
+    impl #impl_generics From< #field_type > for #item_name #ty_generics
+    #where_clause
+    {
+      #[ inline( always ) ]
+      // fn from( src: i32 ) -> Self
+      fn from( src: #field_type ) -> Self
+      {
+        // Self { a: src, b: Default::default() }
+        Self { #(#params), * }
       }
     }
   }
 }
 
-fn generate_unit( item_name: syn::Ident ) -> TokenStream
+fn generate_unit( item_name: &syn::Ident, generics: &syn::Generics ) -> TokenStream
 {
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
   qt!
   {
     // impl From< () > for UnitStruct
-    impl From< () > for #item_name
+    impl #impl_generics From< () > for #item_name #ty_generics
+    #where_clause
     {
       #[ inline( always ) ]
       fn from( src: () ) -> Self
@@ -130,4 +185,55 @@ fn generate_unit( item_name: syn::Ident ) -> TokenStream
       }
     }
   }
-}
\ No newline at end of file
+}
+
+fn generate_from_variants( ast: &syn::DeriveInput ) -> Result< TokenStream >
+{
+  let item_name = &ast.ident;
+  let ( impl_generics, ty_generics, where_clause ) = ast.generics.split_for_impl();
+  let mut result = TokenStream::new();
+  let mut converted : Vec< ( String, syn::Ident ) > = Vec::new();
+  for variant in macro_tools::enum_variants( ast, "From" )?
+  {
+    // only variants with single field could be converted from inner type
+    if variant.fields.len() != 1 || variant.is_skipped( "from" )?
+    {
+      continue;
+    }
+    let field_type = variant.field_types()[ 0 ];
+    // two implementations of `From` for the same type conflict
+    let type_text = qt!{ #field_type }.to_string();
+    if let Some( ( _, other ) ) = converted.iter().find( | ( text, _ ) | *text == type_text )
+    {
+      return Err( syn_err!
+      (
+        variant.ident,
+        "Variants `{}` and `{}` wrap the same type `{}`, so `From` could be derived for only one of them. Mark another one with #[ from( skip ) ]",
+        other,
+        variant.ident,
+        type_text,
+      ));
+    }
+    converted.push( ( type_text, variant.ident.clone() ) );
+    let bindings = variant.bindings();
+    let construct = variant.pattern( &qt!{ Self }, &bindings );
+    let binding = &bindings[ 0 ];
+    result.extend( qt!
+    {
+      #[ automatically_derived ]
+      // impl From< i32 > for MyEnum
+      impl #impl_generics From< #field_type > for #item_name #ty_generics
+      #where_clause
+      {
+        #[ inline( always ) ]
+        // fn from( src: i32 ) -> Self
+        fn from( #binding: #field_type ) -> Self
+        {
+          // Self::A( src )
+          #construct
+        }
+      }
+    });
+  }
+  Ok( result )
+}
@@ -0,0 +1,189 @@
+use super::*;
+use format::{ Piece, Variant };
+
+//
+
+pub fn from_str( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+{
+  let ast = syn::parse::< syn::DeriveInput >( input )?;
+  let item_name = &ast.ident;
+  let type_name = item_name.to_string();
+  let variants = format::variants_of( &ast )?;
+  let krate = macro_tools::crate_path( &ast.attrs, "from_str", "derive_tools" )?;
+  let error = qt!{ #krate::from_str::ParseError };
+
+  let mut bounds : Vec< syn::WherePredicate > = Vec::new();
+  let mut parsers = Vec::new();
+  for variant in variants.iter()
+  {
+    parsers.push( parser_generate( variant, &type_name, &error, &mut bounds )? );
+  }
+
+  let mut generics = ast.generics.clone();
+  generics.make_where_clause().predicates.extend( bounds );
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+
+  // Structure has the only parser, its error is reported as is. Variants are tried in order of declaration.
+  let body = if let ( syn::Data::Struct( _ ), Some( parser ) ) = ( &ast.data, parsers.first() )
+  {
+    qt!{ ( #parser )( src ) }
+  }
+  else
+  {
+    qt!
+    {
+      #(
+        if let ::core::result::Result::Ok( result ) = ( #parsers )( src )
+        {
+          return ::core::result::Result::Ok( result );
+        }
+      )*
+      ::core::result::Result::Err( #error::new( #type_name, "no variant matches" ) )
+    }
+  };
+
+  let result = qt!
+  {
+    #[ automatically_derived ]
+    impl #impl_generics ::core::str::FromStr for #item_name #ty_generics
+    #where_clause
+    {
+      type Err = #error;
+      fn from_str( src : &str ) -> ::core::result::Result< Self, Self::Err >
+      {
+        #body
+      }
+    }
+  };
+
+  Ok( result )
+}
+
+/// Closure parsing a structure or a variant piece by piece.
+///
+/// Literal text is expected as is. Text of a field lasts up to the first occurrence of the literal text following it
+/// which gives parsable text, or up to the end.
+/// Fields absent in the format get default values.
+fn parser_generate
+(
+  variant : &Variant,
+  type_name : &str,
+  error : &proc_macro2::TokenStream,
+  bounds : &mut Vec< syn::WherePredicate >,
+)
+-> Result< proc_macro2::TokenStream >
+{
+  let mut steps = Vec::new();
+  for ( i, piece ) in variant.pieces.iter().enumerate()
+  {
+    match piece
+    {
+      Piece::Literal( literal ) =>
+      {
+        let reason = format!( "expects {:?}", literal );
+        steps.push( qt!
+        {
+          let rest = rest.strip_prefix( #literal )
+          .ok_or_else( || #error::new( #type_name, #reason ) )?;
+        });
+      },
+      Piece::Field( index ) =>
+      {
+        let field = &variant.fields[ *index ];
+        let binding = &field.binding;
+        let ty = &field.ty;
+        let key = &field.key;
+        let field_error = qt!
+        {
+          | err : < #ty as ::core::str::FromStr >::Err |
+          #error::new( #type_name, ::std::format!( "field `{}` : {}", #key, err ) )
+        };
+        let step = match variant.pieces.get( i + 1 )
+        {
+          None => qt!
+          {
+            let #binding = < #ty as ::core::str::FromStr >::from_str( rest ).map_err( #field_error )?;
+            let rest = "";
+          },
+          // Text of the field is tried up to each occurrence of the following literal text, the first parsed wins.
+          Some( Piece::Literal( literal ) ) =>
+          {
+            let reason = format!( "expects {:?} after field `{}`", literal, key );
+            qt!
+            {
+              let mut error = ::core::option::Option::None;
+              let mut found = ::core::option::Option::None;
+              for ( end, _ ) in rest.match_indices( #literal )
+              {
+                match < #ty as ::core::str::FromStr >::from_str( &rest[ .. end ] )
+                {
+                  ::core::result::Result::Ok( value ) =>
+                  {
+                    found = ::core::option::Option::Some( ( value, &rest[ end .. ] ) );
+                    break
+                  },
+                  ::core::result::Result::Err( err ) =>
+                  {
+                    error = error.or( ::core::option::Option::Some( err ) )
+                  },
+                }
+              }
+              let ( #binding, rest ) = match ( found, error )
+              {
+                ( ::core::option::Option::Some( found ), _ ) => found,
+                ( ::core::option::Option::None, ::core::option::Option::Some( err ) ) =>
+                return ::core::result::Result::Err( ( #field_error )( err ) ),
+                ( ::core::option::Option::None, ::core::option::Option::None ) =>
+                return ::core::result::Result::Err( #error::new( #type_name, #reason ) ),
+              };
+            }
+          },
+          Some( Piece::Field( _ ) ) => return Err( syn::Error::new
+          (
+            variant.span,
+            format!( "Field `{}` should be followed by literal text to be parsed", key ),
+          )),
+        };
+        bounds.push( parse_qt!{ #ty : ::core::str::FromStr } );
+        bounds.push( parse_qt!{ < #ty as ::core::str::FromStr >::Err : ::core::fmt::Display } );
+        steps.push( step );
+      },
+    }
+  }
+
+  let parsed : Vec< usize > = variant.pieces.iter().filter_map( | piece | match piece
+  {
+    Piece::Field( index ) => Some( *index ),
+    Piece::Literal( _ ) => None,
+  }).collect();
+  let inits = variant.fields.iter().enumerate().map( | ( index, field ) |
+  {
+    let member = &field.member;
+    let binding = &field.binding;
+    if parsed.contains( &index )
+    {
+      qt!{ #member : #binding }
+    }
+    else
+    {
+      let ty = &field.ty;
+      bounds.push( parse_qt!{ #ty : ::core::default::Default } );
+      qt!{ #member : ::core::default::Default::default() }
+    }
+  }).collect::< Vec< _ > >();
+  let path = &variant.path;
+
+  Ok( qt!
+  {
+    | src : &str | -> ::core::result::Result< Self, #error >
+    {
+      let rest = src;
+      #( #steps )*
+      if !rest.is_empty()
+      {
+        return ::core::result::Result::Err( #error::new( #type_name, ::std::format!( "unexpected {:?}", rest ) ) );
+      }
+      ::core::result::Result::Ok( #path { #( #inits ),* } )
+    }
+  })
+}
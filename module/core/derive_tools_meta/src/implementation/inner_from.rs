@@ -1,4 +1,3 @@
-
 use macro_tools::proc_macro2::TokenStream;
 
 use super::*;
@@ -8,32 +7,39 @@ use super::*;
 pub fn inner_from( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
 {
   let parsed = syn::parse::< InputParsed >( input )?;
-  let field_types = parsed.field_types;
-  let field_names = parsed.field_names;
-  let item_name = parsed.item_name;
-  let result = 
-  match ( field_types.len(), field_names ) 
+  let marked = parsed.marked_field( "from" )?;
+  let field_types = &parsed.field_types;
+  let field_names = &parsed.field_names;
+  let item_name = &parsed.item_name;
+  let generics = &parsed.generics;
+  let result =
+  match ( marked, field_types.len(), field_names )
   {
-    ( 0, _ ) => generate_unit( item_name ),
-    ( 1, Some( field_names ) ) => 
+    ( Some( index ), _, _ ) =>
+    {
+      let field_member = parsed.field_member( index );
+      generate_from_impl_member( item_name, generics, &field_types[ index ], &field_member )
+    }
+    ( None, 0, _ ) => generate_unit( item_name, generics ),
+    ( None, 1, Some( field_names ) ) =>
     {
       let field_name = field_names.get( 0 ).unwrap();
       let field_type = field_types.get( 0 ).unwrap();
-      generate_from_impl_named( item_name, field_type, field_name )
+      generate_from_impl_named( item_name, generics, field_type, field_name )
     }
-    ( 1, None ) => 
+    ( None, 1, None ) =>
     {
       let field_type = field_types.get( 0 ).unwrap();
-      generate_from_impl( item_name, field_type )
+      generate_from_impl( item_name, generics, field_type )
     }
-    ( _, Some( field_names ) ) => 
+    ( None, _, Some( field_names ) ) =>
     {
       let params: Vec< TokenStream > = field_names.iter()
       .map( | field_name | qt! { src.#field_name } )
       .collect();
-      generate_from_impl_multiple_fields( item_name, &field_types, &params )
+      generate_from_impl_multiple_fields( item_name, generics, field_types, &params )
     }
-    ( _, None ) => 
+    ( None, _, None ) =>
     {
       let params: Vec< TokenStream > = ( 0..field_types.len() )
       .map( | index |
@@ -42,23 +48,25 @@ pub fn inner_from( input : proc_macro::TokenStream ) -> Result< proc_macro2::Tok
         qt! { src.#index }
       })
       .collect();
-      generate_from_impl_multiple_fields( item_name, &field_types, &params )
+      generate_from_impl_multiple_fields( item_name, generics, field_types, &params )
     }
   };
   Ok( result )
 }
 
-fn generate_from_impl_named( item_name: syn::Ident, field_type: &syn::Type, field_name: &syn::Ident ) -> TokenStream 
+fn generate_from_impl_named( item_name: &syn::Ident, generics: &syn::Generics, field_type: &syn::Type, field_name: &syn::Ident ) -> TokenStream
 {
-  qt! 
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+  qt!
   {
     #[ automatically_derived ]
     // impl From< MyStruct > for i32
-    impl From< #item_name > for #field_type 
+    impl #impl_generics From< #item_name #ty_generics > for #field_type
+    #where_clause
     {
       #[ inline( always ) ]
       // fm from( src: MyStruct ) -> Self
-      fn from( src: #item_name ) -> Self 
+      fn from( src: #item_name #ty_generics ) -> Self
       {
         // src.a
         src.#field_name
@@ -67,17 +75,19 @@ fn generate_from_impl_named( item_name: syn::Ident, field_type: &syn::Type, fiel
   }
 }
 
-fn generate_from_impl( item_name: syn::Ident, field_type: &syn::Type ) -> TokenStream 
+fn generate_from_impl( item_name: &syn::Ident, generics: &syn::Generics, field_type: &syn::Type ) -> TokenStream
 {
-  qt! 
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+  qt!
   {
     #[ automatically_derived ]
     // impl From< IsTransparent> for bool
-    impl From< #item_name > for #field_type 
+    impl #impl_generics From< #item_name #ty_generics > for #field_type
+    #where_clause
     {
       #[ inline( always ) ]
       // fn from( src: IsTransparent ) -> Self
-      fn from( src: #item_name ) -> Self 
+      fn from( src: #item_name #ty_generics ) -> Self
       {
         src.0
       }
@@ -85,17 +95,40 @@ fn generate_from_impl( item_name: syn::Ident, field_type: &syn::Type ) -> TokenS
   }
 }
 
-fn generate_from_impl_multiple_fields ( item_name: syn::Ident, field_types: &Vec< syn::Type >, params: &Vec< TokenStream > ) -> TokenStream 
+fn generate_from_impl_member( item_name: &syn::Ident, generics: &syn::Generics, field_type: &syn::Type, field_member: &syn::Member ) -> TokenStream
 {
-  qt! 
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+  qt!
+  {
+    #[ automatically_derived ]
+    // impl From< StructWithMarkedField > for i32
+    impl #impl_generics From< #item_name #ty_generics > for #field_type
+    #where_clause
+    {
+      #[ inline( always ) ]
+      // fn from( src: StructWithMarkedField ) -> Self
+      fn from( src: #item_name #ty_generics ) -> Self
+      {
+        // src.a
+        src.#field_member
+      }
+    }
+  }
+}
+
+fn generate_from_impl_multiple_fields ( item_name: &syn::Ident, generics: &syn::Generics, field_types: &Vec< syn::Type >, params: &Vec< TokenStream > ) -> TokenStream
+{
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+  qt!
   {
     #[ automatically_derived ]
     // impl From< StructWithManyFields > for ( i32, bool )
-    impl From< #item_name > for ( #(#field_types), *) 
+    impl #impl_generics From< #item_name #ty_generics > for ( #(#field_types), *)
+    #where_clause
     {
       #[ inline( always ) ]
       // fn from( src: StructWithManyFields ) -> Self
-      fn from( src: #item_name ) -> Self
+      fn from( src: #item_name #ty_generics ) -> Self
       {
         //( src.0, src.1 )
         (#(#params), *)
@@ -104,17 +137,19 @@ fn generate_from_impl_multiple_fields ( item_name: syn::Ident, field_types: &Vec
   }
 }
 
-fn generate_unit( item_name: syn::Ident ) -> TokenStream
+fn generate_unit( item_name: &syn::Ident, generics: &syn::Generics ) -> TokenStream
 {
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
   qt!
   {
     #[ automatically_derived ]
     // impl From< UnitStruct > for ()
-    impl From< #item_name > for () 
+    impl #impl_generics From< #item_name #ty_generics > for ()
+    #where_clause
     {
       #[ inline( always ) ]
       // fn from( src: UnitStruct ) -> ()
-      fn from( src: #item_name ) -> ()
+      fn from( src: #item_name #ty_generics ) -> ()
       {
         ()
       }
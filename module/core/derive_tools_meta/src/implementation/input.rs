@@ -7,9 +7,11 @@ pub struct InputParsed
 {
   pub item : syn::ItemStruct,
   pub item_name : syn::Ident,
+  pub generics : syn::Generics,
   pub fields : syn::Fields,
   pub fields_many : Many< syn::Field >,
   pub field_types: Vec< syn::Type >,
+  #[ cfg( any( feature = "derive_from", feature = "derive_inner_from" ) ) ]
   pub field_names: Option< Vec< syn::Ident > >,
   // pub field_type : syn::Type,
 }
@@ -54,6 +56,57 @@ impl InputParsed
 
     return Err( syn_err!( self.item.span(), "Expects type for fields" ) );
   }
+
+  /// Index of the field marked by attribute `#[ marker ]` if any.
+  #[ cfg( any( feature = "derive_deref", feature = "derive_deref_mut", feature = "derive_as_ref", feature = "derive_as_mut", feature = "derive_from", feature = "derive_inner_from" ) ) ]
+  pub fn marked_field( &self, marker : &str ) -> Result< Option< usize > >
+  {
+    let mut result = None;
+    for ( index, field ) in self.fields_many.iter().enumerate()
+    {
+      if let Some( attr ) = field.attrs.iter().find( | attr | attr.path.is_ident( marker ) )
+      {
+        if result.is_some()
+        {
+          return Err( syn_err!( attr, "Only one field could be marked with #[ {} ]", marker ) );
+        }
+        result = Some( index );
+      }
+    }
+    Ok( result )
+  }
+
+  /// Field a newtype-style derive targets : the field marked by attribute `#[ marker ]` or the only field.
+  /// Returns member to access the field and its type.
+  #[ cfg( any( feature = "derive_deref", feature = "derive_deref_mut", feature = "derive_as_ref", feature = "derive_as_mut" ) ) ]
+  pub fn target_field( &self, marker : &str ) -> Result< ( syn::Member, syn::Type ) >
+  {
+    let index = match ( self.marked_field( marker )?, self.fields_many.0.len() )
+    {
+      ( Some( index ), _ ) => index,
+      ( None, 1 ) => 0,
+      ( None, 0 ) => return Err( syn_err!( self.item.span(), "Expects fields" ) ),
+      ( None, _ ) => return Err( syn_err!
+      (
+        self.item.span(),
+        "Expects exactly one field or a field marked with #[ {} ]",
+        marker,
+      )),
+    };
+    Ok( ( self.field_member( index ), self.field_types[ index ].clone() ) )
+  }
+
+  /// Member to access field by index, its name or index for tuple fields.
+  #[ cfg( any( feature = "derive_deref", feature = "derive_deref_mut", feature = "derive_as_ref", feature = "derive_as_mut", feature = "derive_from", feature = "derive_inner_from", feature = "derive_variadic_from" ) ) ]
+  pub fn field_member( &self, index : usize ) -> syn::Member
+  {
+    match &self.fields_many.0[ index ].ident
+    {
+      Some( ident ) => syn::Member::Named( ident.clone() ),
+      None => syn::Member::Unnamed( syn::Index::from( index ) ),
+    }
+  }
+
 }
 
 //
@@ -70,12 +123,23 @@ impl syn::parse::Parse for InputParsed
     //
 
     let item_name = item.ident.clone();
+    let generics = item.generics.clone();
     let fields = item.fields.clone();
     let fields_many : Vec< syn::Field > = match item.fields
     {
       syn::Fields::Unnamed( ref fields ) => { fields.unnamed.iter().cloned().collect() },
       syn::Fields::Named( ref fields ) => { fields.named.iter().cloned().collect() },
-      _ => return Ok( Self { item, item_name, fields, fields_many: Many(vec![]), field_types: vec![], field_names: None } ),
+      _ => return Ok( Self
+      {
+        item,
+        item_name,
+        generics,
+        fields,
+        fields_many : Many( vec![] ),
+        field_types : vec![],
+        #[ cfg( any( feature = "derive_from", feature = "derive_inner_from" ) ) ]
+        field_names : None,
+      }),
     };
 
     // if fields.len() != 1
@@ -86,8 +150,19 @@ impl syn::parse::Parse for InputParsed
     // let field_type = field.ty.clone();
     let fields_many = fields_many.into();
     let field_types = field_types( &fields_many )?;
+    #[ cfg( any( feature = "derive_from", feature = "derive_inner_from" ) ) ]
     let field_names = field_names( &fields_many )?;
-    Ok( Self { item, item_name, fields, fields_many, field_types, field_names } )
+    Ok( Self
+    {
+      item,
+      item_name,
+      generics,
+      fields,
+      fields_many,
+      field_types,
+      #[ cfg( any( feature = "derive_from", feature = "derive_inner_from" ) ) ]
+      field_names,
+    })
   }
 }
 
@@ -112,6 +187,7 @@ fn field_types ( fields: &Many< syn::Field > ) -> Result< Vec< syn::Type> >
   Ok( field_types )
 }
 
+#[ cfg( any( feature = "derive_from", feature = "derive_inner_from" ) ) ]
 fn field_names( fields: &Many< syn::Field > ) -> Result< Option< Vec< syn::Ident > > > 
 {
   let mut field_names: Vec< syn::Ident > = vec![];
@@ -127,4 +203,4 @@ fn field_names( fields: &Many< syn::Field > ) -> Result< Option< Vec< syn::Ident
     }
   }
   Ok( Some( field_names ) )
-}
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use super::*;
+
+//
+
+pub fn is_variant( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+{
+  let ast = syn::parse::< syn::DeriveInput >( input )?;
+  let item_name = &ast.ident;
+  let variants = macro_tools::enum_variants( &ast, "IsVariant" )?;
+  let ( impl_generics, ty_generics, where_clause ) = ast.generics.split_for_impl();
+
+  let methods = variants.iter().map( | variant |
+  {
+    let ident = &variant.ident;
+    let method = macro_tools::format_ident!( "is_{}", variant.snake_name() );
+    let doc = format!( "Is it variant `{}`.", ident );
+    qt!
+    {
+      #[ doc = #doc ]
+      #[ inline( always ) ]
+      pub fn #method( &self ) -> bool
+      {
+        matches!( self, Self::#ident { .. } )
+      }
+    }
+  });
+
+  let result = qt!
+  {
+    #[ automatically_derived ]
+    impl #impl_generics #item_name #ty_generics
+    #where_clause
+    {
+      #( #methods )*
+    }
+  };
+
+  Ok( result )
+}
@@ -1,29 +1,45 @@
-
-//!
-//! Implement couple of derives of general-purpose.
-//!
-
-#[ allow( unused_imports ) ]
-use macro_tools::prelude::*;
-pub use macro_tools::{ Result, Many };
-pub use iter_tools as iter;
-
-pub mod input;
-#[ allow( unused_imports ) ]
-use input::*;
-#[ cfg( feature = "derive_as_mut" ) ]
-pub mod as_mut;
-#[ cfg( feature = "derive_as_ref" ) ]
-pub mod as_ref;
-#[ cfg( feature = "derive_deref" ) ]
-pub mod deref;
-#[ cfg( feature = "derive_deref_mut" ) ]
-pub mod deref_mut;
-#[ cfg( feature = "derive_from" ) ]
-pub mod from_inner;
-#[ cfg( feature = "derive_inner_from" ) ]
-pub mod inner_from;
-#[ cfg( feature = "derive_variadic_from" ) ]
-pub mod variadic_from;
-#[ cfg( feature = "derive_reflect" ) ]
-pub mod reflect;
+
+//!
+//! Implement couple of derives of general-purpose.
+//!
+
+#[ allow( unused_imports ) ]
+use macro_tools::prelude::*;
+#[ allow( unused_imports ) ]
+pub use macro_tools::Result;
+#[ cfg( any( feature = "derive_deref", feature = "derive_deref_mut", feature = "derive_as_ref", feature = "derive_as_mut", feature = "derive_from", feature = "derive_inner_from", feature = "derive_variadic_from" ) ) ]
+pub use macro_tools::Many;
+
+#[ cfg( any( feature = "derive_deref", feature = "derive_deref_mut", feature = "derive_as_ref", feature = "derive_as_mut", feature = "derive_from", feature = "derive_inner_from", feature = "derive_variadic_from" ) ) ]
+pub mod input;
+#[ cfg( any( feature = "derive_deref", feature = "derive_deref_mut", feature = "derive_as_ref", feature = "derive_as_mut", feature = "derive_from", feature = "derive_inner_from", feature = "derive_variadic_from" ) ) ]
+#[ allow( unused_imports ) ]
+use input::*;
+#[ cfg( feature = "derive_as_mut" ) ]
+pub mod as_mut;
+#[ cfg( feature = "derive_as_ref" ) ]
+pub mod as_ref;
+#[ cfg( feature = "derive_deref" ) ]
+pub mod deref;
+#[ cfg( feature = "derive_deref_mut" ) ]
+pub mod deref_mut;
+#[ cfg( feature = "derive_from" ) ]
+pub mod from_inner;
+#[ cfg( feature = "derive_inner_from" ) ]
+pub mod inner_from;
+#[ cfg( feature = "derive_variadic_from" ) ]
+pub mod variadic_from;
+#[ cfg( feature = "derive_reflect" ) ]
+pub mod reflect;
+#[ cfg( any( feature = "derive_display", feature = "derive_from_str" ) ) ]
+pub mod format;
+#[ cfg( feature = "derive_display" ) ]
+pub mod display;
+#[ cfg( feature = "derive_from_str" ) ]
+pub mod from_str;
+#[ cfg( feature = "derive_is_variant" ) ]
+pub mod is_variant;
+#[ cfg( feature = "derive_unwrap" ) ]
+pub mod unwrap;
+#[ cfg( feature = "derive_try_into" ) ]
+pub mod try_into;
@@ -1,19 +1,529 @@
-
-// use macro_tools::proc_macro2::TokenStream;
-use super::*;
-
-//
-
-pub fn reflect( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
-{
-  let parsed = syn::parse::< InputParsed >( input )?;
-  // let field_types = parsed.field_types;
-  // let field_names = parsed.field_names;
-  // let item_name = parsed.item_name;
-
-  let result = qt!
-  {
-  };
-
-  Ok( result )
-}
+
+// use macro_tools::proc_macro2::TokenStream;
+use super::*;
+
+//
+
+pub fn reflect( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+{
+  let ast = syn::parse::< syn::DeriveInput >( input )?;
+  let item_name = &ast.ident;
+  let generics = &ast.generics;
+  let vis = &ast.vis;
+  let krate = macro_tools::crate_path( &ast.attrs, "reflect", "derive_tools" )?;
+  let reflect = qt!{ #krate::reflect };
+
+  let lifetimes : Vec< syn::Ident > = generics.lifetimes().map( | param | param.lifetime.ident.clone() ).collect();
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+
+  // Type of the entity with all lifetimes replaced by `'static`, to get its type id and descriptors of its fields.
+  let item_static = lifetimes_to_static( qt!{ #item_name #ty_generics }, &lifetimes );
+
+  // Arguments of a descriptor with all lifetimes replaced by `'static`.
+  let args_static : Vec< proc_macro2::TokenStream > = generics.params.iter().map( | param | match param
+  {
+    syn::GenericParam::Lifetime( _ ) => qt!{ 'static },
+    syn::GenericParam::Type( param ) => { let ident = &param.ident; qt!{ #ident } },
+    syn::GenericParam::Const( param ) => { let ident = &param.ident; qt!{ #ident } },
+  }).collect();
+
+  let descriptor = macro_tools::format_ident!( "{}EntityDescriptor", item_name );
+  let entities = match &ast.data
+  {
+    syn::Data::Struct( data_struct ) =>
+    {
+      let ( elements, bounds ) = fields_elements( &reflect, &data_struct.fields, &lifetimes );
+      entity_generate( &reflect, &descriptor, &item_static, generics, vis, &elements, &bounds )
+    },
+    syn::Data::Enum( data_enum ) =>
+    {
+      let mut elements = Vec::new();
+      let mut bounds = Vec::new();
+      let mut variants = Vec::new();
+      for variant in data_enum.variants.iter()
+      {
+        let variant_name = syn::ext::IdentExt::unraw( &variant.ident ).to_string();
+        let variant_descriptor = macro_tools::format_ident!( "{}{}EntityDescriptor", item_name, variant.ident );
+        let ( variant_elements, variant_bounds ) = fields_elements( &reflect, &variant.fields, &lifetimes );
+        variants.push( entity_generate( &reflect, &variant_descriptor, &item_static, generics, vis, &variant_elements, &variant_bounds ) );
+        elements.push( qt!
+        {
+          #reflect::KeyVal
+          {
+            key : #reflect::Primitive::str( #variant_name ),
+            val : ::std::boxed::Box::new( #variant_descriptor::< #( #args_static ),* >::new() ),
+          }
+        });
+        bounds.push( parse_qt!{ #variant_descriptor< #( #args_static ),* > : #reflect::Entity } );
+      }
+      let entity = entity_generate( &reflect, &descriptor, &item_static, generics, vis, &elements, &bounds );
+      qt!
+      {
+        #entity
+        #( #variants )*
+      }
+    },
+    syn::Data::Union( _ ) => return Err( syn_err!( ast, "Reflect could not be derived for union" ) ),
+  };
+
+  // Access to data requires `Any`, so it's not implemented for entities with lifetimes.
+  let instance_value = if lifetimes.is_empty()
+  {
+    let variants : Vec< ( proc_macro2::TokenStream, Option< String >, &syn::Fields ) > = match &ast.data
+    {
+      syn::Data::Struct( data_struct ) => vec![ ( qt!{ Self }, None, &data_struct.fields ) ],
+      syn::Data::Enum( data_enum ) => data_enum.variants.iter().map( | variant |
+      {
+        let ident = &variant.ident;
+        ( qt!{ Self::#ident }, Some( syn::ext::IdentExt::unraw( ident ).to_string() ), &variant.fields )
+      }).collect(),
+      syn::Data::Union( _ ) => unreachable!(),
+    };
+    instance_value_generate( &reflect, item_name, generics, &variants )
+  }
+  else
+  {
+    qt!{}
+  };
+
+  let result = qt!
+  {
+    const _ : () =
+    {
+
+      #entities
+
+      #instance_value
+
+      #[ automatically_derived ]
+      impl #impl_generics #reflect::Instance for #item_name #ty_generics
+      #where_clause
+      {
+        type Entity = #descriptor #ty_generics;
+        #[ inline( always ) ]
+        fn Reflect() -> Self::Entity
+        {
+          #descriptor::new()
+        }
+      }
+
+    };
+  };
+
+  Ok( result )
+}
+
+///
+/// Generate descriptor of a structure or of a variant of an enum and implement `Entity` for it.
+///
+
+fn entity_generate
+(
+  reflect : &proc_macro2::TokenStream,
+  descriptor : &syn::Ident,
+  item_static : &proc_macro2::TokenStream,
+  generics : &syn::Generics,
+  vis : &syn::Visibility,
+  elements : &[ proc_macro2::TokenStream ],
+  bounds : &[ syn::WherePredicate ],
+)
+-> proc_macro2::TokenStream
+{
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+  let len = elements.len();
+
+  let phantom : Vec< proc_macro2::TokenStream > = generics.params.iter().filter_map( | param | match param
+  {
+    syn::GenericParam::Lifetime( param ) => { let lifetime = &param.lifetime; Some( qt!{ & #lifetime () } ) },
+    syn::GenericParam::Type( param ) => { let ident = &param.ident; Some( qt!{ #ident } ) },
+    syn::GenericParam::Const( _ ) => None,
+  }).collect();
+
+  let mut entity_generics = generics.clone();
+  let predicates = &mut entity_generics.make_where_clause().predicates;
+  predicates.push( parse_qt!{ #item_static : 'static } );
+  predicates.extend( bounds.iter().cloned() );
+  let ( _, _, entity_where_clause ) = entity_generics.split_for_impl();
+
+  qt!
+  {
+
+    #[ doc( hidden ) ]
+    #vis struct #descriptor #generics #where_clause
+    {
+      _phantom : ::core::marker::PhantomData< fn() -> ( #( #phantom, )* ) >,
+    }
+
+    impl #impl_generics #descriptor #ty_generics #where_clause
+    {
+      /// Constructor of the descriptor.
+      #[ inline( always ) ]
+      pub fn new() -> Self
+      {
+        let _phantom = ::core::marker::PhantomData;
+        Self { _phantom }
+      }
+    }
+
+    impl #impl_generics ::core::fmt::Debug for #descriptor #ty_generics #entity_where_clause
+    {
+      fn fmt( &self, f : &mut ::core::fmt::Formatter< '_ > ) -> ::core::fmt::Result
+      {
+        use #reflect::Entity;
+        f.write_str( &format!( "{}#{:?}", self.type_name(), self.type_id() ) )
+      }
+    }
+
+    #[ automatically_derived ]
+    impl #impl_generics #reflect::Entity for #descriptor #ty_generics #entity_where_clause
+    {
+
+      #[ inline( always ) ]
+      fn is_container( &self ) -> bool
+      {
+        true
+      }
+
+      #[ inline( always ) ]
+      fn len( &self ) -> usize
+      {
+        #len
+      }
+
+      #[ inline( always ) ]
+      fn type_name( &self ) -> &'static str
+      {
+        ::core::any::type_name::< #item_static >()
+      }
+
+      #[ inline( always ) ]
+      fn type_id( &self ) -> ::core::any::TypeId
+      {
+        ::core::any::TypeId::of::< #item_static >()
+      }
+
+      #[ inline( always ) ]
+      fn elements( &self ) -> ::std::boxed::Box< dyn ::core::iter::Iterator< Item = #reflect::KeyVal > >
+      {
+        let result : ::std::vec::Vec< #reflect::KeyVal > = ::std::vec!
+        [
+          #( #elements, )*
+        ];
+        ::std::boxed::Box::new( result.into_iter() )
+      }
+
+    }
+
+  }
+}
+
+///
+/// Implement `InstanceValue` giving access to fields of an instance.
+///
+/// Each variant is given as path to construct it, its name and its fields. Structure is a single variant with path `Self` and no name.
+/// Enum also gets access to its current variant and could be switched to another variant with default values of fields.
+///
+
+fn instance_value_generate
+(
+  reflect : &proc_macro2::TokenStream,
+  item_name : &syn::Ident,
+  generics : &syn::Generics,
+  variants : &[ ( proc_macro2::TokenStream, Option< String >, &syn::Fields ) ],
+)
+-> proc_macro2::TokenStream
+{
+  let ( impl_generics, ty_generics, _ ) = generics.split_for_impl();
+
+  let mut value_generics = generics.clone();
+  let predicates = &mut value_generics.make_where_clause().predicates;
+  predicates.push( parse_qt!{ #item_name #ty_generics : 'static } );
+
+  let mut arms_element = Vec::new();
+  let mut arms_element_mut = Vec::new();
+  let mut arms_values = Vec::new();
+  let mut arms_keyed = Vec::new();
+  let mut arms_variant = Vec::new();
+  let mut arms_set_variant = Vec::new();
+  for ( path, name, fields ) in variants.iter()
+  {
+    let bindings : Vec< syn::Ident > = fields.iter().enumerate().map( | ( index, field ) | match &field.ident
+    {
+      Some( ident ) => ident.clone(),
+      None => macro_tools::format_ident!( "_{}", index ),
+    }).collect();
+    let keys : Vec< proc_macro2::TokenStream > = fields.iter().enumerate().map( | ( index, field ) | match &field.ident
+    {
+      Some( ident ) => { let name = syn::ext::IdentExt::unraw( ident ).to_string(); qt!{ #name } },
+      None => qt!{ #index },
+    }).collect();
+    let primitives : Vec< proc_macro2::TokenStream > = fields.iter().zip( keys.iter() ).map( | ( field, key ) | match &field.ident
+    {
+      Some( _ ) => qt!{ #reflect::Primitive::str( #key ) },
+      None => qt!{ #reflect::Primitive::usize( #key ) },
+    }).collect();
+    for field in fields.iter()
+    {
+      let ty = &field.ty;
+      predicates.push( parse_qt!{ #ty : #reflect::InstanceValue } );
+    }
+
+    let pattern = match fields
+    {
+      syn::Fields::Named( _ ) => qt!{ #path { #( #bindings ),* } },
+      syn::Fields::Unnamed( _ ) => qt!{ #path( #( #bindings ),* ) },
+      syn::Fields::Unit => qt!{ #path { .. } },
+    };
+    let key_get = match fields
+    {
+      syn::Fields::Named( _ ) => qt!{ key.as_str() },
+      _ => qt!{ key.as_usize() },
+    };
+
+    arms_element.push( qt!
+    {
+      #pattern => match #key_get
+      {
+        #( ::core::option::Option::Some( #keys ) => ::core::option::Option::Some( #bindings as &dyn #reflect::InstanceValue ), )*
+        _ => ::core::option::Option::None,
+      },
+    });
+    arms_element_mut.push( qt!
+    {
+      #pattern => match #key_get
+      {
+        #( ::core::option::Option::Some( #keys ) => ::core::option::Option::Some( #bindings as &mut dyn #reflect::InstanceValue ), )*
+        _ => ::core::option::Option::None,
+      },
+    });
+    arms_values.push( qt!
+    {
+      #pattern => ::std::vec!
+      [
+        #( ( #primitives, #bindings as &dyn #reflect::InstanceValue ), )*
+      ],
+    });
+    let keyed = !matches!( fields, syn::Fields::Unnamed( _ ) );
+    arms_keyed.push( qt!{ #path { .. } => #keyed, } );
+
+    if let Some( name ) = name
+    {
+      let defaults = fields.iter().map( | field |
+      {
+        let ty = &field.ty;
+        qt!{ ( &::core::marker::PhantomData::< #ty > ).maybe_default().ok_or_else( no_variant )? }
+      });
+      let construct = match fields
+      {
+        syn::Fields::Named( _ ) => qt!{ #path { #( #bindings : #defaults ),* } },
+        syn::Fields::Unnamed( _ ) => qt!{ #path( #( #defaults ),* ) },
+        syn::Fields::Unit => qt!{ #path },
+      };
+      arms_variant.push( qt!{ #path { .. } => ::core::option::Option::Some( #name ), } );
+      arms_set_variant.push( qt!
+      {
+        #name =>
+        {
+          *self = #construct;
+          ::core::result::Result::Ok( () )
+        },
+      });
+    }
+  }
+
+  let methods_variant = if arms_variant.is_empty()
+  {
+    qt!{}
+  }
+  else
+  {
+    qt!
+    {
+
+      fn variant( &self ) -> ::core::option::Option< &'static str >
+      {
+        match self
+        {
+          #( #arms_variant )*
+        }
+      }
+
+      fn set_variant( &mut self, name : &str ) -> ::core::result::Result< (), #reflect::ReflectError >
+      {
+        // Autoref specialization, fields without default value make the variant impossible to switch to.
+        trait NotDefault< T >
+        {
+          fn maybe_default( self : &Self ) -> ::core::option::Option< T > { ::core::option::Option::None }
+        }
+
+        trait WithDefault< T >
+        {
+          fn maybe_default( self : &Self ) -> ::core::option::Option< T >;
+        }
+
+        impl< T > NotDefault< T >
+        for & ::core::marker::PhantomData< T >
+        {}
+
+        impl< T > WithDefault< T >
+        for ::core::marker::PhantomData< T >
+        where T : ::core::default::Default,
+        {
+          fn maybe_default( self : &Self ) -> ::core::option::Option< T >
+          {
+            ::core::option::Option::Some( T::default() )
+          }
+        }
+
+        let no_variant = || #reflect::ReflectError::NoVariant( name.to_string() );
+        match name
+        {
+          #( #arms_set_variant )*
+          _ => ::core::result::Result::Err( no_variant() ),
+        }
+      }
+
+    }
+  };
+
+  let ( _, _, where_clause ) = value_generics.split_for_impl();
+
+  qt!
+  {
+    #[ automatically_derived ]
+    impl #impl_generics #reflect::InstanceValue for #item_name #ty_generics
+    #where_clause
+    {
+
+      #[ inline( always ) ]
+      fn as_any( &self ) -> &dyn ::core::any::Any
+      {
+        self
+      }
+
+      #[ inline( always ) ]
+      fn as_any_mut( &mut self ) -> &mut dyn ::core::any::Any
+      {
+        self
+      }
+
+      #[ allow( unused_variables ) ]
+      fn element( &self, key : &#reflect::Primitive ) -> ::core::option::Option< &dyn #reflect::InstanceValue >
+      {
+        match self
+        {
+          #( #arms_element )*
+        }
+      }
+
+      #[ allow( unused_variables ) ]
+      fn element_mut( &mut self, key : &#reflect::Primitive ) -> ::core::option::Option< &mut dyn #reflect::InstanceValue >
+      {
+        match self
+        {
+          #( #arms_element_mut )*
+        }
+      }
+
+      fn values( &self ) -> ::std::boxed::Box< dyn ::core::iter::Iterator< Item = ( #reflect::Primitive, &dyn #reflect::InstanceValue ) > + '_ >
+      {
+        let result : ::std::vec::Vec< ( #reflect::Primitive, &dyn #reflect::InstanceValue ) > = match self
+        {
+          #( #arms_values )*
+        };
+        ::std::boxed::Box::new( result.into_iter() )
+      }
+
+      fn is_keyed( &self ) -> bool
+      {
+        match self
+        {
+          #( #arms_keyed )*
+        }
+      }
+      #methods_variant
+    }
+  }
+}
+
+///
+/// Generate elements of the descriptor for fields of a structure or of a variant.
+///
+/// Key of a named field is its name, key of an unnamed field is its index.
+/// Returns elements and bounds requiring type of each field to be reflectable.
+///
+
+fn fields_elements
+(
+  reflect : &proc_macro2::TokenStream,
+  fields : &syn::Fields,
+  lifetimes : &[ syn::Ident ],
+)
+-> ( Vec< proc_macro2::TokenStream >, Vec< syn::WherePredicate > )
+{
+  let mut elements = Vec::new();
+  let mut bounds = Vec::new();
+  for ( index, field ) in fields.iter().enumerate()
+  {
+    let ty = &field.ty;
+    let ty = lifetimes_to_static( qt!{ #ty }, lifetimes );
+    let key = match &field.ident
+    {
+      Some( ident ) =>
+      {
+        let name = syn::ext::IdentExt::unraw( ident ).to_string();
+        qt!{ #reflect::Primitive::str( #name ) }
+      },
+      None => qt!{ #reflect::Primitive::usize( #index ) },
+    };
+    bounds.push( parse_qt!{ #ty : #reflect::Instance } );
+    bounds.push( parse_qt!{ < #ty as #reflect::Instance >::Entity : 'static } );
+    elements.push( qt!
+    {
+      #reflect::KeyVal
+      {
+        key : #key,
+        val : ::std::boxed::Box::new( < #ty as #reflect::Instance >::Reflect() ),
+      }
+    });
+  }
+  ( elements, bounds )
+}
+
+///
+/// Replace every lifetime from the list by `'static`.
+///
+
+fn lifetimes_to_static( tokens : proc_macro2::TokenStream, lifetimes : &[ syn::Ident ] ) -> proc_macro2::TokenStream
+{
+  use proc_macro2::TokenTree;
+  let mut result = Vec::new();
+  let mut iter = tokens.into_iter().peekable();
+  while let Some( tree ) = iter.next()
+  {
+    match tree
+    {
+      TokenTree::Punct( ref punct ) if punct.as_char() == '\'' =>
+      {
+        let replace = matches!( iter.peek(), Some( TokenTree::Ident( ident ) ) if lifetimes.contains( ident ) );
+        if replace
+        {
+          iter.next();
+          result.extend( qt!{ 'static } );
+        }
+        else
+        {
+          result.push( tree );
+        }
+      },
+      TokenTree::Group( group ) =>
+      {
+        let mut replaced = proc_macro2::Group::new( group.delimiter(), lifetimes_to_static( group.stream(), lifetimes ) );
+        replaced.set_span( group.span() );
+        result.push( TokenTree::Group( replaced ) );
+      },
+      _ => result.push( tree ),
+    }
+  }
+  result.into_iter().collect()
+}
@@ -0,0 +1,132 @@
+use super::*;
+
+//
+
+pub fn try_into( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+{
+  let ast = syn::parse::< syn::DeriveInput >( input )?;
+  let item_name = &ast.ident;
+  let variants = macro_tools::enum_variants( &ast, "TryInto" )?;
+  let ( impl_generics, ty_generics, where_clause ) = ast.generics.split_for_impl();
+  let params : Vec< &syn::Ident > = ast.generics.type_params().map( | param | &param.ident ).collect();
+
+  let mut methods = Vec::new();
+  let mut impls = Vec::new();
+  let mut converted : Vec< ( String, syn::Ident ) > = Vec::new();
+  for variant in variants.iter()
+  {
+    // Unit variants have nothing to convert into.
+    if variant.fields.is_empty() || variant.is_skipped( "try_into" )?
+    {
+      continue;
+    }
+    let ident = &variant.ident;
+    let method = macro_tools::format_ident!( "try_into_{}", variant.snake_name() );
+    let bindings = variant.bindings();
+    let pattern = variant.pattern( &qt!{ Self }, &bindings );
+    let types = variant.field_types();
+    let ( ty, value ) = if types.len() == 1
+    {
+      let ty = types[ 0 ];
+      let binding = &bindings[ 0 ];
+      ( qt!{ #ty }, qt!{ #binding } )
+    }
+    else
+    {
+      ( qt!{ ( #( #types ),* ) }, qt!{ ( #( #bindings ),* ) } )
+    };
+
+    let doc = format!( "Fields of variant `{}` if it is the variant, otherwise the enum is given back.", ident );
+    methods.push( qt!
+    {
+      #[ doc = #doc ]
+      #[ inline ]
+      pub fn #method( self ) -> ::core::result::Result< #ty, Self >
+      {
+        match self
+        {
+          #pattern => ::core::result::Result::Ok( #value ),
+          _ => ::core::result::Result::Err( self ),
+        }
+      }
+    });
+
+    // Orphan rules forbid `impl< T > TryFrom< Enum< T > > for T`, so a field of bare type parameter gets the method only.
+    if types.len() == 1 && uncovered( types[ 0 ], &params )
+    {
+      continue;
+    }
+    // two implementations of `TryFrom` for the same type conflict
+    let type_text = ty.to_string();
+    if let Some( ( _, other ) ) = converted.iter().find( | ( text, _ ) | *text == type_text )
+    {
+      return Err( syn_err!
+      (
+        variant.ident,
+        "Variants `{}` and `{}` have fields of the same type `{}`, so `TryFrom` could be derived for only one of them. Mark another one with #[ try_into( skip ) ]",
+        other,
+        variant.ident,
+        type_text,
+      ));
+    }
+    converted.push( ( type_text, variant.ident.clone() ) );
+    impls.push( qt!
+    {
+      #[ automatically_derived ]
+      impl #impl_generics ::core::convert::TryFrom< #item_name #ty_generics > for #ty
+      #where_clause
+      {
+        type Error = #item_name #ty_generics;
+        #[ inline ]
+        fn try_from( src : #item_name #ty_generics ) -> ::core::result::Result< Self, Self::Error >
+        {
+          src.#method()
+        }
+      }
+    });
+  }
+
+  Ok( qt!
+  {
+    #[ automatically_derived ]
+    #[ allow( unreachable_patterns ) ]
+    impl #impl_generics #item_name #ty_generics
+    #where_clause
+    {
+      #( #methods )*
+    }
+    #( #impls )*
+  })
+}
+
+/// Is the type a bare type parameter, possibly behind a reference or `Box` which don't cover it for orphan rules.
+fn uncovered( ty : &syn::Type, params : &[ &syn::Ident ] ) -> bool
+{
+  match ty
+  {
+    syn::Type::Reference( reference ) => uncovered( &reference.elem, params ),
+    syn::Type::Paren( paren ) => uncovered( &paren.elem, params ),
+    syn::Type::Group( group ) => uncovered( &group.elem, params ),
+    syn::Type::Path( path ) if path.qself.is_none() =>
+    {
+      if let Some( ident ) = path.path.get_ident()
+      {
+        return params.iter().any( | param | *param == ident );
+      }
+      match path.path.segments.last()
+      {
+        Some( segment ) if segment.ident == "Box" => match &segment.arguments
+        {
+          syn::PathArguments::AngleBracketed( args ) => args.args.iter().any( | arg | match arg
+          {
+            syn::GenericArgument::Type( ty ) => uncovered( ty, params ),
+            _ => false,
+          }),
+          _ => false,
+        },
+        _ => false,
+      }
+    },
+    _ => false,
+  }
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+//
+
+pub fn unwrap( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+{
+  let ast = syn::parse::< syn::DeriveInput >( input )?;
+  let item_name = &ast.ident;
+  let variants = macro_tools::enum_variants( &ast, "Unwrap" )?;
+  let ( impl_generics, ty_generics, where_clause ) = ast.generics.split_for_impl();
+
+  // Unit variants have nothing to unwrap.
+  let methods = variants.iter().filter( | variant | !variant.fields.is_empty() ).map( | variant |
+  {
+    let ident = &variant.ident;
+    let snake = variant.snake_name();
+    let as_ref = macro_tools::format_ident!( "as_{}", snake );
+    let as_mut = macro_tools::format_ident!( "as_{}_mut", snake );
+    let into = macro_tools::format_ident!( "into_{}", snake );
+    let unwrap = macro_tools::format_ident!( "unwrap_{}", snake );
+    let bindings = variant.bindings();
+    let pattern = variant.pattern( &qt!{ Self }, &bindings );
+    let types = variant.field_types();
+
+    // The only field is returned as is, several fields are returned as a tuple.
+    let ( ty, ty_ref, ty_mut, value ) = if types.len() == 1
+    {
+      let ty = types[ 0 ];
+      let binding = &bindings[ 0 ];
+      ( qt!{ #ty }, qt!{ &#ty }, qt!{ &mut #ty }, qt!{ #binding } )
+    }
+    else
+    {
+      ( qt!{ ( #( #types ),* ) }, qt!{ ( #( &#types ),* ) }, qt!{ ( #( &mut #types ),* ) }, qt!{ ( #( #bindings ),* ) } )
+    };
+
+    let doc_as_ref = format!( "Reference on fields of variant `{}` if it is the variant.", ident );
+    let doc_as_mut = format!( "Mutable reference on fields of variant `{}` if it is the variant.", ident );
+    let doc_into = format!( "Fields of variant `{}` if it is the variant.", ident );
+    let doc_unwrap = format!( "Fields of variant `{}`.\n\n# Panics\n\nPanics if it is not the variant.", ident );
+    let panic = format!( "called `{}::{}()` on another variant", item_name, unwrap );
+    qt!
+    {
+      #[ doc = #doc_as_ref ]
+      #[ inline ]
+      pub fn #as_ref( &self ) -> ::core::option::Option< #ty_ref >
+      {
+        match self
+        {
+          #pattern => ::core::option::Option::Some( #value ),
+          _ => ::core::option::Option::None,
+        }
+      }
+      #[ doc = #doc_as_mut ]
+      #[ inline ]
+      pub fn #as_mut( &mut self ) -> ::core::option::Option< #ty_mut >
+      {
+        match self
+        {
+          #pattern => ::core::option::Option::Some( #value ),
+          _ => ::core::option::Option::None,
+        }
+      }
+      #[ doc = #doc_into ]
+      #[ inline ]
+      pub fn #into( self ) -> ::core::option::Option< #ty >
+      {
+        match self
+        {
+          #pattern => ::core::option::Option::Some( #value ),
+          _ => ::core::option::Option::None,
+        }
+      }
+      #[ doc = #doc_unwrap ]
+      #[ inline ]
+      #[ track_caller ]
+      pub fn #unwrap( self ) -> #ty
+      {
+        match self
+        {
+          #pattern => #value,
+          _ => ::core::panic!( #panic ),
+        }
+      }
+    }
+  });
+
+  let result = qt!
+  {
+    #[ automatically_derived ]
+    #[ allow( unreachable_patterns ) ]
+    impl #impl_generics #item_name #ty_generics
+    #where_clause
+    {
+      #( #methods )*
+    }
+  };
+
+  Ok( result )
+}
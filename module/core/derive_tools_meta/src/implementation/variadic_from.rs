@@ -1,6 +1,8 @@
-
 use super::*;
-use iter::{ IterExt, Itertools };
+use quote::ToTokens;
+
+/// The biggest number of fields for which constructor `From_N` exists.
+const ARITY_MAX : usize = 12;
 
 //
 
@@ -8,146 +10,86 @@ pub fn variadic_from( input : proc_macro::TokenStream ) -> Result< proc_macro2::
 {
 
   let parsed = syn::parse::< InputParsed >( input )?;
-  let item_name = parsed.item_name;
+  let item_name = &parsed.item_name;
+  let ( impl_generics, ty_generics, where_clause ) = parsed.generics.split_for_impl();
 
-  let result = match &parsed.fields
+  let len = parsed.field_types.len();
+  if len == 0
+  {
+    return Err( syn_err!( parsed.item.span(), "Expects fields" ) );
+  }
+  if len > ARITY_MAX
+  {
+    return Err( syn_err!
+    (
+      parsed.fields.span(),
+      "VariadicFrom supports structures with up to {} fields, but {} has {}",
+      ARITY_MAX,
+      item_name,
+      len,
+    ));
+  }
+
+  let types = &parsed.field_types;
+  let vars : Vec< syn::Ident > = ( 0 .. len ).map( | index | macro_tools::format_ident!( "field_{}", index ) ).collect();
+  let members : Vec< syn::Member > = ( 0 .. len ).map( | index | parsed.field_member( index ) ).collect();
+
+  // Self { a : field_0, b : field_1 } works for tuple structures as well : Self { 0 : field_0, 1 : field_1 }
+  let construct = qt!{ Self { #( #members : #vars ),* } };
+
+  let from_trait = macro_tools::format_ident!( "From_{}", len );
+  let from_method = macro_tools::format_ident!( "from_{}", len );
+
+  let mut result = qt!
   {
-    syn::Fields::Named( _ ) =>
-    {
 
-      let
-      (
-        types,
-        fn_params,
-        src_into_vars,
-        vars
-      ) : ( Vec< _ >, Vec< _ >, Vec< _ >, Vec< _ > ) = parsed.fields.iter().map_result( | field |
+    #[ automatically_derived ]
+    // impl wtools::From_2< i32, i32 > for StructNamedFields
+    impl #impl_generics wtools::#from_trait< #( #types ),* > for #item_name #ty_generics
+    #where_clause
+    {
+      // fn from_2( field_0 : i32, field_1 : i32 ) -> Self
+      #[ inline( always ) ]
+      fn #from_method( #( #vars : #types ),* ) -> Self
       {
-        let ident = field.ident.clone().ok_or_else( || syn_err!( parsed.item.span(), "Fields should be named" ) )?;
-        let ty = field.ty.clone();
-        Result::Ok
-        ((
-          qt!{ #ty, },
-          qt!{ #ident : #ty, },
-          qt!{ let #ident = core::convert::Into::into( #ident ); },
-          qt!{ #ident, },
-        ))
-      })?
-      .into_iter().multiunzip();
-
-      let l = format!( "{}", parsed.fields.len() );
-      let from_trait = macro_tools::format_ident!( "From_{l}" );
-      let from_method = macro_tools::format_ident!( "from_{l}" );
+        #construct
+      }
+    }
 
-      qt!
+    #[ automatically_derived ]
+    // impl From< ( i32, i32 ) > for StructNamedFields
+    impl #impl_generics From< ( #( #types, )* ) > for #item_name #ty_generics
+    #where_clause
+    {
+      #[ inline( always ) ]
+      fn from( ( #( #vars, )* ) : ( #( #types, )* ) ) -> Self
       {
-
-        #[ automatically_derived ]
-        // impl wtools::From_2< i32 > for StructNamedFields
-        impl wtools::#from_trait< #( #types )* > for #item_name
-        {
-          // fn from_1( a : i32, b : i32 ) -> Self
-          fn #from_method
-          (
-            #( #fn_params )*
-          ) -> Self
-          {
-            #( #src_into_vars )*
-            // let a = core::convert::Into::into( a );
-            // let b = core::convert::Into::into( b );
-            Self
-            {
-              #( #vars )*
-              // a,
-              // b,
-            }
-          }
-        }
-
-        impl From< ( i32, i32 ) > for StructNamedFields
-        {
-          /// Returns the argument unchanged.
-          #[ inline( always ) ]
-          fn from( src : ( i32, i32 ) ) -> Self
-          {
-            Self::from_1( src )
-          }
-        }
-
+        #construct
       }
-
     }
-    syn::Fields::Unnamed( _ ) =>
-    {
 
-      let mut counter = 0;
-      let
-      (
-        vars_assing_default,
-        src_into_vars,
-        vars
-      ) : ( Vec< _ >, Vec< _ >, Vec< _ > ) = parsed.fields.iter().map_result( | _field |
-      {
-        let ident = macro_tools::format_ident!( "_{}", format!( "{counter}" ) );
-        counter += 1;
-        Result::Ok
-        ((
-          qt!{ let #ident = core::default::Default::default(); },
-          qt!{ let #ident = src.into(); },
-          qt!{ #ident, },
-        ))
-      })?
-      .into_iter().multiunzip();
+  };
 
-      qt!
+  // array could be converted only if all fields have the same type
+  let first = types[ 0 ].to_token_stream().to_string();
+  if types.iter().all( | ty | ty.to_token_stream().to_string() == first )
+  {
+    let ty = &types[ 0 ];
+    result.extend( qt!
+    {
+      #[ automatically_derived ]
+      // impl From< [ i32 ; 2 ] > for StructNamedFields
+      impl #impl_generics From< [ #ty ; #len ] > for #item_name #ty_generics
+      #where_clause
       {
-        #[ automatically_derived ]
-        impl wtools::From_0 for #item_name
+        #[ inline( always ) ]
+        fn from( [ #( #vars ),* ] : [ #ty ; #len ] ) -> Self
         {
-          fn from_0() -> Self
-          {
-            #( #vars_assing_default )*
-            // let a = Default::default();
-            // let b = Default::default();
-            // let c = Default::default();
-            // let d = Default::default();
-            Self
-            (
-              #( #vars )*
-              // a,
-              // b,
-              // c,
-              // d,
-            )
-          }
+          #construct
         }
-
-        #[ automatically_derived ]
-        impl wtools::From_1< i32 > for #item_name
-        {
-          fn from_1( src : i32 ) -> Self
-          {
-            #( #src_into_vars )*
-            // let a = src.into();
-            // let b = src.into();
-            // let c = src.into();
-            // let d = src.into();
-            Self
-            (
-              #( #vars )*
-              // a,
-              // b,
-              // c,
-              // d,
-            )
-          }
-        }
-
       }
-
-    }
-    _ => return Err( syn_err!( parsed.fields.span(), "Expects fields" ) ),
-  };
+    });
+  }
 
   Ok( result )
 }
@@ -21,42 +21,19 @@
 // #[ cfg( feature = "enabled" ) ]
 // use macro_tools::prelude::*;
 
-#[ cfg
-(
-	any
-	(
-		feature = "derive_as_mut",
-		feature = "derive_as_ref",
-		feature = "derive_deref",
-		feature = "derive_deref_mut",
-		feature = "derive_from",
-		feature = "derive_inner_from",
-		feature = "derive_variadic_from",
-		feature = "derive_reflect",
-	)
-)]
 #[ cfg( feature = "enabled" ) ]
 mod implementation;
-#[ cfg
-(
-	any
-	(
-		feature = "derive_as_mut",
-		feature = "derive_as_ref",
-		feature = "derive_deref",
-		feature = "derive_deref_mut",
-		feature = "derive_from",
-		feature = "derive_inner_from",
-		feature = "derive_variadic_from",
-		feature = "derive_reflect",
-	)
-)]
 #[ cfg( feature = "enabled" ) ]
+#[ allow( unused_imports ) ]
 use implementation::*;
 
 ///
 /// Derive macro to implement From converting inner type into outer when-ever it's possible to do automatically.
 ///
+/// Generics, lifetimes and where-clause of the structure are carried through. Multiple fields are converted from a tuple,
+/// unless a field is marked with attribute `#[ from ]`, then it is converted from the marked field and other fields get default values.
+/// Enum is converted from inner type of each variant with single field, unless the variant is marked with `#[ from( skip ) ]`.
+///
 /// ### Sample :: struct instead of macro.
 ///
 /// Write this
@@ -83,7 +60,7 @@ use implementation::*;
 
 #[ cfg( feature = "enabled" ) ]
 #[ cfg( feature = "derive_from" ) ]
-#[ proc_macro_derive( From ) ]
+#[ proc_macro_derive( From, attributes( from ) ) ]
 pub fn from( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
   let result = from_inner::from_inner( input );
@@ -97,6 +74,10 @@ pub fn from( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 ///
 /// Derive macro to implement From converting inner type into outer when-ever it's possible to do automatically.
 ///
+/// Generics, lifetimes and where-clause of the structure are carried through. Multiple fields are converted from a tuple,
+/// unless a field is marked with attribute `#[ from ]`, then it is converted from the marked field and other fields get default values.
+/// Enum is converted from inner type of each variant with single field, unless the variant is marked with `#[ from( skip ) ]`.
+///
 /// ### Sample :: struct instead of macro.
 ///
 /// Write this
@@ -123,7 +104,7 @@ pub fn from( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 
 #[ cfg( feature = "enabled" ) ]
 #[ cfg( feature = "derive_from" ) ]
-#[ proc_macro_derive( FromInner ) ]
+#[ proc_macro_derive( FromInner, attributes( from ) ) ]
 pub fn from_inner( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
   let result = from_inner::from_inner( input );
@@ -137,6 +118,9 @@ pub fn from_inner( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 ///
 /// Derive macro to implement From converting outer type into inner when-ever it's possible to do automatically.
 ///
+/// Generics, lifetimes and where-clause of the structure are carried through. Multiple fields are converted into a tuple,
+/// unless a field is marked with attribute `#[ from ]`, then the structure is converted into the marked field.
+///
 /// ### Sample :: struct instead of macro.
 ///
 /// Write this
@@ -163,7 +147,7 @@ pub fn from_inner( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 
 #[ cfg( feature = "enabled" ) ]
 #[ cfg( feature = "derive_inner_from" ) ]
-#[ proc_macro_derive( InnerFrom ) ]
+#[ proc_macro_derive( InnerFrom, attributes( from ) ) ]
 pub fn inner_from( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
   let result = inner_from::inner_from( input );
@@ -177,6 +161,9 @@ pub fn inner_from( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 ///
 /// Derive macro to implement Deref when-ever it's possible to do automatically.
 ///
+/// Target is the only field of the structure or the field marked with attribute `#[ deref ]`, which is shared by
+/// derives `Deref`, `DerefMut`, `AsRef` and `AsMut`. Generics, lifetimes and where-clause of the structure are carried through.
+///
 /// ### Sample :: struct instead of macro.
 ///
 /// Write this
@@ -204,7 +191,7 @@ pub fn inner_from( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 
 #[ cfg( feature = "enabled" ) ]
 #[ cfg( feature = "derive_deref" ) ]
-#[ proc_macro_derive( Deref ) ]
+#[ proc_macro_derive( Deref, attributes( deref ) ) ]
 pub fn deref( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
   let result = deref::deref( input );
@@ -218,6 +205,9 @@ pub fn deref( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 ///
 /// Derive macro to implement Deref when-ever it's possible to do automatically.
 ///
+/// Target is the only field of the structure or the field marked with attribute `#[ deref ]`, which is shared by
+/// derives `Deref`, `DerefMut`, `AsRef` and `AsMut`. Generics, lifetimes and where-clause of the structure are carried through.
+///
 /// ### Sample :: struct instead of macro.
 ///
 /// Write this
@@ -254,7 +244,7 @@ pub fn deref( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 
 #[ cfg( feature = "enabled" ) ]
 #[ cfg( feature = "derive_deref_mut" ) ]
-#[ proc_macro_derive( DerefMut ) ]
+#[ proc_macro_derive( DerefMut, attributes( deref ) ) ]
 pub fn deref_mut( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
   let result = deref_mut::deref_mut( input );
@@ -268,6 +258,8 @@ pub fn deref_mut( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 ///
 /// Derive macro to implement AsRef when-ever it's possible to do automatically.
 ///
+/// Target is the only field of the structure or the field marked with attribute `#[ deref ]`.
+///
 /// ### Sample :: struct instead of macro.
 ///
 /// Write this
@@ -293,7 +285,7 @@ pub fn deref_mut( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 
 #[ cfg( feature = "enabled" ) ]
 #[ cfg( feature = "derive_as_ref" ) ]
-#[ proc_macro_derive( AsRef ) ]
+#[ proc_macro_derive( AsRef, attributes( deref ) ) ]
 pub fn as_ref( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
   let result = as_ref::as_ref( input );
@@ -307,6 +299,8 @@ pub fn as_ref( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 ///
 /// Derive macro to implement AsMut when-ever it's possible to do automatically.
 ///
+/// Target is the only field of the structure or the field marked with attribute `#[ deref ]`.
+///
 /// ### Sample :: struct instead of macro.
 ///
 /// Write this
@@ -333,7 +327,7 @@ pub fn as_ref( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 
 #[ cfg( feature = "enabled" ) ]
 #[ cfg( feature = "derive_as_mut" ) ]
-#[ proc_macro_derive( AsMut ) ]
+#[ proc_macro_derive( AsMut, attributes( deref ) ) ]
 pub fn as_mut( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
   let result = as_mut::as_mut( input );
@@ -345,37 +339,45 @@ pub fn as_mut( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 }
 
 ///
-/// Derive macro to implement default constructors `From_0`, `From_1`, `From_2`, `From_3`.
+/// Derive macro to implement variadic constructor `From_N` with a parameter per field, along with `From` of tuple of field types.
+/// If all fields have the same type `From` of array of them is implemented as well.
+/// Structures with up to 12 fields are supported, generic parameters are carried through.
 ///
 /// ### Sample :: struct instead of macro.
 ///
 /// Write this
 ///
 /// ```rust, ignore, no_run
-/// # use derive_tools::*;
-/// #[ derive( Make ) ]
-/// pub struct IsTransparent( bool );
+/// # use variadic_from::*;
+/// #[ derive( VariadicFrom ) ]
+/// pub struct Point( i32, i32 );
 /// ```
 ///
 /// Instead of this
 ///
 /// ```rust, ignore, no_run
-/// pub struct IsTransparent( bool );
-/// impl From_0 for IsTransparent
+/// pub struct Point( i32, i32 );
+/// impl wtools::From_2< i32, i32 > for Point
 /// {
-///   fn make0() -> Self
+///   fn from_2( field_0 : i32, field_1 : i32 ) -> Self
 ///   {
-///     Self::default();
+///     Self( field_0, field_1 )
 ///   }
 /// }
-/// impl From_1 for IsTransparent
+/// impl From< ( i32, i32 ) > for Point
 /// {
-///   fn make1( src : bool ) -> Self
+///   fn from( ( field_0, field_1 ) : ( i32, i32 ) ) -> Self
 ///   {
-///     Self( src )
+///     Self( field_0, field_1 )
+///   }
+/// }
+/// impl From< [ i32 ; 2 ] > for Point
+/// {
+///   fn from( [ field_0, field_1 ] : [ i32 ; 2 ] ) -> Self
+///   {
+///     Self( field_0, field_1 )
 ///   }
 /// }
-///
 /// ```
 
 // qqq : xxx : why no run?
@@ -396,14 +398,33 @@ pub fn derive_variadic_from( input : proc_macro::TokenStream ) -> proc_macro::To
 ///
 /// Reflect structure of any kind.
 ///
+/// Implements `reflect::Instance` for the structure or the enum and `reflect::Entity` for its descriptor.
+/// Elements of a structure are its fields keyed by name or by index, elements of an enum are its variants keyed by name.
+/// Unless the entity has lifetimes, `reflect::InstanceValue` is implemented too, giving access to values of fields by path.
+/// Path to crate `derive_tools` is `::derive_tools` unless given by attribute `#[ reflect( crate = path ) ]`.
+///
 /// ### Sample :: trivial.
 ///
-/// qqq : write, please
+/// ```ignore
+/// use derive_tools::{ Reflect, reflect::{ Instance, Entity, Primitive } };
+///
+/// #[ derive( Reflect ) ]
+/// pub struct Struct1
+/// {
+///   pub f1 : i32,
+///   pub f2 : String,
+/// }
+///
+/// let ins = Struct1 { f1 : 1, f2 : "2".into() };
+/// assert_eq!( ins.reflect().len(), 2 );
+/// let names : Vec< _ > = ins.reflect().elements().map( | e | e.key ).collect();
+/// assert_eq!( names, vec![ Primitive::str( "f1" ), Primitive::str( "f2" ) ] );
+/// ```
 ///
 
 #[ cfg( feature = "enabled" ) ]
 #[ cfg( feature = "derive_reflect" ) ]
-#[ proc_macro_derive( Reflect ) ]
+#[ proc_macro_derive( Reflect, attributes( reflect ) ) ]
 pub fn derive_reflect( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
   let result = reflect::reflect( input );
@@ -413,3 +434,186 @@ pub fn derive_reflect( input : proc_macro::TokenStream ) -> proc_macro::TokenStr
     Err( err ) => err.to_compile_error().into(),
   }
 }
+
+///
+/// Derive `Display` writing fields by format given by attribute `#[ display( "..." ) ]`.
+///
+/// Placeholders of the format refer to fields by name or by index, `{{` and `}}` are escaped braces.
+/// Without the attribute unit is written as its name and newtype as its only field.
+/// Variants of an enum have their own attributes. The same format drives derive `FromStr`.
+///
+/// ### Sample :: trivial.
+///
+/// ```ignore
+/// use derive_tools::*;
+///
+/// #[ derive( Display, FromStr, PartialEq, Debug ) ]
+/// #[ display( "{a}-{b}" ) ]
+/// struct Struct1
+/// {
+///   a : i32,
+///   b : i32,
+/// }
+///
+/// assert_eq!( format!( "{}", Struct1 { a : 1, b : 3 } ), "1-3" );
+/// assert_eq!( "1-3".parse(), Ok( Struct1 { a : 1, b : 3 } ) );
+/// ```
+///
+
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "derive_display" ) ]
+#[ proc_macro_derive( Display, attributes( display ) ) ]
+pub fn derive_display( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
+{
+  let result = display::display( input );
+  match result
+  {
+    Ok( stream ) => stream.into(),
+    Err( err ) => err.to_compile_error().into(),
+  }
+}
+
+///
+/// Derive `FromStr` parsing fields by format given by attribute `#[ display( "..." ) ]`, inverse of derive `Display`.
+///
+/// Literal text of the format is expected as is, text of a field lasts up to the first occurrence of the literal text following it
+/// which the field could be parsed from. So placeholders should be separated by literal text. Fields absent in the format get default values.
+/// Variants of an enum are tried in order of declaration. Error is `derive_tools::from_str::ParseError`,
+/// path to crate `derive_tools` is `::derive_tools` unless given by attribute `#[ from_str( crate = path ) ]`.
+///
+/// ### Sample :: newtype.
+///
+/// ```ignore
+/// use derive_tools::*;
+///
+/// #[ derive( Display, FromStr, PartialEq, Debug ) ]
+/// struct Id( u32 );
+///
+/// let id : Id = "13".parse().unwrap();
+/// assert_eq!( id, Id( 13 ) );
+/// assert_eq!( id.to_string(), "13" );
+/// ```
+///
+
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "derive_from_str" ) ]
+#[ proc_macro_derive( FromStr, attributes( display, from_str ) ) ]
+pub fn derive_from_str( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
+{
+  let result = from_str::from_str( input );
+  match result
+  {
+    Ok( stream ) => stream.into(),
+    Err( err ) => err.to_compile_error().into(),
+  }
+}
+
+///
+/// Derive methods `is_x()` checking whether an enum is variant `X`, for each variant.
+///
+/// ### Sample :: trivial.
+///
+/// ```ignore
+/// use derive_tools::*;
+///
+/// #[ derive( IsVariant ) ]
+/// enum Shape
+/// {
+///   Circle( f32 ),
+///   Square { side : f32 },
+///   Empty,
+/// }
+///
+/// assert!( Shape::Circle( 1.0 ).is_circle() );
+/// assert!( !Shape::Empty.is_square() );
+/// ```
+///
+
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "derive_is_variant" ) ]
+#[ proc_macro_derive( IsVariant ) ]
+pub fn derive_is_variant( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
+{
+  let result = is_variant::is_variant( input );
+  match result
+  {
+    Ok( stream ) => stream.into(),
+    Err( err ) => err.to_compile_error().into(),
+  }
+}
+
+///
+/// Derive methods `as_x()`, `as_x_mut()`, `into_x()` and `unwrap_x()` giving access to fields of variant `X`, for each variant with fields.
+///
+/// The only field is returned as is, several fields are returned as a tuple.
+///
+/// ### Sample :: trivial.
+///
+/// ```ignore
+/// use derive_tools::*;
+///
+/// #[ derive( Unwrap ) ]
+/// enum Shape
+/// {
+///   Circle( f32 ),
+///   Rect { width : f32, height : f32 },
+/// }
+///
+/// assert_eq!( Shape::Circle( 1.0 ).as_circle(), Some( &1.0 ) );
+/// assert_eq!( Shape::Circle( 1.0 ).into_rect(), None );
+/// assert_eq!( Shape::Rect { width : 1.0, height : 2.0 }.unwrap_rect(), ( 1.0, 2.0 ) );
+/// ```
+///
+
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "derive_unwrap" ) ]
+#[ proc_macro_derive( Unwrap ) ]
+pub fn derive_unwrap( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
+{
+  let result = unwrap::unwrap( input );
+  match result
+  {
+    Ok( stream ) => stream.into(),
+    Err( err ) => err.to_compile_error().into(),
+  }
+}
+
+///
+/// Derive method `try_into_{variant}` and `TryFrom< Enum >` for fields of each variant with fields, so an enum could be tried into them.
+///
+/// The only field is converted as is, several fields are converted into a tuple. On mismatch the enum is given back as error.
+/// Variant marked with `#[ try_into( skip ) ]` is skipped. Orphan rules forbid `TryFrom` for fields mentioning type parameters
+/// of the enum, such variants get only the method.
+///
+/// ### Sample :: trivial.
+///
+/// ```ignore
+/// use derive_tools::*;
+///
+/// #[ derive( Debug, PartialEq, TryInto ) ]
+/// enum Value
+/// {
+///   Int( i32 ),
+///   Text( String ),
+/// }
+///
+/// let got : Result< i32, _ > = Value::Int( 13 ).try_into();
+/// assert_eq!( got, Ok( 13 ) );
+/// let got : Result< String, _ > = Value::Int( 13 ).try_into();
+/// assert_eq!( got, Err( Value::Int( 13 ) ) );
+/// assert_eq!( Value::Text( "a".into() ).try_into_text(), Ok( "a".to_string() ) );
+/// ```
+///
+
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "derive_try_into" ) ]
+#[ proc_macro_derive( TryInto, attributes( try_into ) ) ]
+pub fn derive_try_into( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
+{
+  let result = try_into::try_into( input );
+  match result
+  {
+    Ok( stream ) => stream.into(),
+    Err( err ) => err.to_compile_error().into(),
+  }
+}
@@ -145,6 +145,38 @@ pub( crate ) mod private
 
   }
 
+  ///
+  /// Apply macro for each element of a list, implemented by procedural macro of crate `for_each_meta`.
+  ///
+  /// Accepts all forms of macro `for_each!` and extends them with placeholder `@Index`, option `@Product` and named bindings.
+  /// Placeholder `@Index` is replaced in elements too, so unlike `for_each!` it is not passed to the callback as is.
+  ///
+  /// ```rust
+  /// use for_each::*;
+  /// let mut got = vec![];
+  /// for_each_proc!
+  /// {
+  ///   for ( i, x ) in [ 10, 20 ] =>
+  ///   {
+  ///     got.push( i + x );
+  ///   }
+  /// };
+  /// assert_eq!( got, vec![ 10, 21 ] );
+  /// ```
+  ///
+
+  #[ cfg( feature = "proc_macro" ) ]
+  #[ macro_export ]
+  macro_rules! for_each_proc
+  {
+    (
+      $( $Rest : tt )*
+    ) =>
+    {
+      $crate::dependency::for_each_meta::for_each!{ $crate ; $( $Rest )* }
+    };
+  }
+
   ///
   /// Unwrap braces of token tree and pass its content to the passed callback. If token tree in not braced then it passed to callback as is.
   ///
@@ -476,11 +508,21 @@ pub( crate ) mod private
   //
 
   pub use for_each;
+  #[ cfg( feature = "proc_macro" ) ]
+  pub use for_each_proc;
   pub use braces_unwrap;
   pub use identity;
 
 }
 
+/// Dependencies.
+#[ cfg( feature = "enabled" ) ]
+pub mod dependency
+{
+  #[ cfg( feature = "proc_macro" ) ]
+  pub use ::for_each_meta;
+}
+
 #[ cfg( feature = "enabled" ) ]
 #[ doc( inline ) ]
 #[ allow( unused_imports ) ]
@@ -520,6 +562,10 @@ pub mod prelude
   #[ doc( inline ) ]
   #[ allow( unused_imports ) ]
   pub use super::private::for_each;
+  #[ cfg( feature = "proc_macro" ) ]
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::private::for_each_proc;
   #[ doc( inline ) ]
   #[ allow( unused_imports ) ]
   pub use super::private::braces_unwrap;
@@ -1,4 +1,5 @@
-
 use super::*;
 // #[ path = "./impl/for_each_test.rs" ]
 pub mod for_each_test;
+#[ cfg( feature = "proc_macro" ) ]
+pub mod proc_macro_test;
@@ -0,0 +1,226 @@
+use super::*;
+
+std::thread_local!
+{
+  /// What callbacks of tests wrote.
+  static GOT : core::cell::RefCell< String > = core::cell::RefCell::new( String::new() );
+}
+
+/// Take what callbacks wrote.
+fn got() -> String
+{
+  GOT.with( | got | got.take() )
+}
+
+macro_rules! test_with
+{
+  (
+    $( $Arg : tt )*
+  ) =>
+  {{
+    GOT.with( | got | *got.borrow_mut() += &format!( "{};", stringify!( $( $Arg )* ) ) );
+  }};
+}
+
+macro_rules! sum
+{
+  ( $Type : ty, $Value : expr ) =>
+  {
+    GOT.with( | got | *got.borrow_mut() += &format!( "{};", < $Type >::from( $Value ) + < $Type >::from( $Value ) ) );
+  };
+}
+
+tests_impls!
+{
+
+  //
+
+  fn index_test()
+  {
+    /* test.case( "map-style" ) */
+
+    {
+      TheModule::for_each_proc!
+      {
+        test_with where
+        @Prefix { @Index => }
+        @Each a b c
+      }
+      let exp = "0 => a;1 => b;2 => c;";
+      a_id!( got(), exp );
+    }
+
+    /* test.case( "callbackless" ) */
+
+    {
+      let mut got = vec![];
+      TheModule::for_each_proc!
+      {
+        @Prefix { got.push }
+        @Postfix { ; }
+        @Each { ( ( @Index, "a" ) ) } { ( ( @Index, "b" ) ) }
+      }
+      a_id!( got, vec![ ( 0, "a" ), ( 1, "b" ) ] );
+    }
+
+    /* test.case( "function-style" ) */
+
+    {
+      TheModule::for_each_proc!( test_with, { a @Index }, { b @Index } );
+      let exp = "a 0;b 1;";
+      a_id!( got(), exp );
+    }
+
+  }
+
+  //
+
+  fn product_test()
+  {
+    /* test.case( "two lists" ) */
+
+    {
+      TheModule::for_each_proc!
+      {
+        test_with where
+        @Product { i32 i64 } { add sub }
+      }
+      let exp = "i32, add;i32, sub;i64, add;i64, sub;";
+      a_id!( got(), exp );
+    }
+
+    /* test.case( "with fixes and index" ) */
+
+    {
+      TheModule::for_each_proc!
+      {
+        test_with where
+        @Prefix { @Index : }
+        @Postfix { end }
+        @Product { a b } { { f32 u8 } f64 } { x }
+      }
+      let exp = "0 : a, f32 u8, x end;1 : a, f64, x end;2 : b, f32 u8, x end;3 : b, f64, x end;";
+      a_id!( got(), exp );
+    }
+
+    /* test.case( "generated functions" ) */
+
+    {
+      TheModule::for_each_proc!
+      {
+        sum where
+        @Product { u16 f64 } { 1u8 2u8 }
+      }
+      let exp = "2;4;2;4;";
+      a_id!( got(), exp );
+    }
+
+  }
+
+  //
+
+  fn named_binding_test()
+  {
+    /* test.case( "single binding" ) */
+
+    {
+      TheModule::for_each_proc!
+      {
+        for x in [ a, b c, { d, e } ] => { test_with!( x ); }
+      }
+      let exp = "a;b c;d, e;";
+      a_id!( got(), exp );
+    }
+
+    /* test.case( "product of bindings with indices" ) */
+
+    {
+      TheModule::for_each_proc!
+      {
+        for ( i, t ) in [ i32, i64 ], op in [ add, sub ] =>
+        {
+          test_with!( @Index i t op );
+        }
+      }
+      let exp = "0 0 i32 add;1 0 i32 sub;2 1 i64 add;3 1 i64 sub;";
+      a_id!( got(), exp );
+    }
+
+    /* test.case( "element is an expression" ) */
+
+    {
+      let mut got = vec![];
+      TheModule::for_each_proc!
+      {
+        for x in [ ( 1 + 1 ), 3 ] => { got.push( x * 2 ); }
+      }
+      a_id!( got, vec![ 4, 6 ] );
+    }
+
+    /* test.case( "several templates" ) */
+
+    {
+      TheModule::for_each_proc!
+      {
+        for x in [ a, b ] => { test_with!( x ); }
+        for y in [ c ] => { test_with!( y @Index ); }
+      }
+      let exp = "a;b;c 0;";
+      a_id!( got(), exp );
+    }
+
+  }
+
+  //
+
+  fn nested_test()
+  {
+    /* test.case( "index of nested call is its own" ) */
+
+    {
+      TheModule::for_each_proc!
+      {
+        for t in [ f32, f64 ] =>
+        {
+          TheModule::for_each_proc!
+          {
+            for op in [ add, sub, mul ] => { test_with!( t op @Index ); }
+          }
+        }
+      }
+      let exp = "f32 add 0;f32 sub 1;f32 mul 2;f64 add 0;f64 sub 1;f64 mul 2;";
+      a_id!( got(), exp );
+    }
+
+    /* test.case( "map-style inside of named binding" ) */
+
+    {
+      TheModule::for_each_proc!
+      {
+        for ( i, t ) in [ u8, u16 ] =>
+        {
+          TheModule::for_each_proc!
+          {
+            test_with where
+            @Prefix { t i @Index }
+            @Each x y
+          }
+        }
+      }
+      let exp = "u8 0 0 x;u8 0 1 y;u16 1 0 x;u16 1 1 y;";
+      a_id!( got(), exp );
+    }
+
+  }
+
+}
+
+//
+
+tests_index!
+{
+  index_test,
+  product_test,
+  named_binding_test,
+  nested_test,
+}
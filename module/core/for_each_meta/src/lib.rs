@@ -0,0 +1,36 @@
+// #![ cfg_attr( feature = "no_std", no_std ) ]
+#![ doc( html_logo_url = "https://raw.githubusercontent.com/Wandalen/wTools/master/asset/img/logo_v3_trans_square.png" ) ]
+#![ doc( html_favicon_url = "https://raw.githubusercontent.com/Wandalen/wTools/alpha/asset/img/logo_v3_trans_square_icon_small_v2.ico" ) ]
+#![ doc( html_root_url = "https://docs.rs/for_each_meta/latest/for_each_meta/" ) ]
+// #![ deny( rust_2018_idioms ) ]
+// #![ deny( missing_debug_implementations ) ]
+// #![ deny( missing_docs ) ]
+
+//!
+//! Procedural macro of for_each with indices, products of lists and named bindings.
+//!
+
+#![ doc = include_str!( concat!( env!( "CARGO_MANIFEST_DIR" ), "/", "Readme.md" ) ) ]
+
+#[ cfg( feature = "enabled" ) ]
+mod meta_impl;
+
+///
+/// Apply macro or template for each element of a list with the syntax of macro `for_each::for_each!`.
+///
+/// Input starts with path to crate `for_each` followed by `;`, what is done by macro `for_each_proc!` of `for_each`.
+/// Besides function-style and map-style calls it accepts option `@Product` to iterate over cartesian product of lists,
+/// placeholder `@Index` replaced by index of the element and named bindings `for x in [ .. ], y in [ .. ] => { .. }`.
+///
+
+#[ cfg( feature = "enabled" ) ]
+#[ proc_macro ]
+pub fn for_each( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
+{
+  let result = meta_impl::for_each( input );
+  match result
+  {
+    Ok( stream ) => stream.into(),
+    Err( err ) => err.to_compile_error().into(),
+  }
+}
@@ -0,0 +1,398 @@
+use macro_tools::prelude::*;
+use macro_tools::Token;
+use macro_tools::proc_macro2::{ TokenStream, TokenTree, Delimiter, Group, Literal };
+use macro_tools::syn::parse::{ Parse, ParseStream };
+pub type Result< T > = std::result::Result< T, syn::Error >;
+
+///
+/// Elements to iterate over in map-style call.
+///
+
+enum Source
+{
+  /// Elements of option `@Each`, each is a token tree.
+  Each( Vec< TokenTree > ),
+  /// Lists of option `@Product`, each list is written in braces.
+  Product( Vec< Vec< TokenTree > > ),
+}
+
+///
+/// Named binding of a list, like `x in [ a, b ]` or `( i, x ) in [ a, b ]`.
+///
+
+struct Binding
+{
+  /// Name replaced by index of the element.
+  index : Option< syn::Ident >,
+  /// Name replaced by the element.
+  name : syn::Ident,
+  /// Elements separated by commas, outermost braces of an element are removed.
+  elements : Vec< TokenStream >,
+}
+
+impl Parse for Binding
+{
+  fn parse( input : ParseStream< '_ > ) -> Result< Self >
+  {
+    let ( index, name ) = if input.peek( syn::token::Paren )
+    {
+      let content;
+      syn::parenthesized!( content in input );
+      let index = content.parse()?;
+      content.parse::< Token![ , ] >()?;
+      let name = content.parse()?;
+      ( Some( index ), name )
+    }
+    else
+    {
+      ( None, input.parse()? )
+    };
+    input.parse::< Token![ in ] >()?;
+
+    let content;
+    syn::bracketed!( content in input );
+    let mut elements = vec![];
+    while !content.is_empty()
+    {
+      let mut element = TokenStream::new();
+      while !content.is_empty() && !content.peek( Token![ , ] )
+      {
+        element.extend( std::iter::once( content.parse::< TokenTree >()? ) );
+      }
+      if element.is_empty()
+      {
+        return Err( content.error( "Expects element of the list, but got `,`" ) );
+      }
+      elements.push( braces_unwrap( element ) );
+      if !content.is_empty()
+      {
+        content.parse::< Token![ , ] >()?;
+      }
+    }
+
+    Ok( Self { index, name, elements } )
+  }
+}
+
+///
+/// Form of the call.
+///
+
+enum Call
+{
+  /// `callback, a, b, c`
+  Function
+  {
+    callback : syn::Path,
+    elements : Vec< TokenTree >,
+  },
+  /// `callback where @Prefix { .. } @Postfix { .. } @Each a b c`, callback could be omitted along with `where`.
+  Map
+  {
+    callback : Option< syn::Path >,
+    prefix : Option< TokenTree >,
+    postfix : Option< TokenTree >,
+    source : Source,
+  },
+  /// `for x in [ a, b ], y in [ c, d ] => { .. }`, maybe repeated.
+  Named
+  {
+    templates : Vec< ( Vec< Binding >, TokenStream ) >,
+  },
+}
+
+impl Parse for Call
+{
+  fn parse( input : ParseStream< '_ > ) -> Result< Self >
+  {
+
+    if input.peek( Token![ for ] )
+    {
+      let mut templates = vec![];
+      while !input.is_empty()
+      {
+        input.parse::< Token![ for ] >()?;
+        let mut bindings = vec![ input.parse::< Binding >()? ];
+        while input.peek( Token![ , ] )
+        {
+          input.parse::< Token![ , ] >()?;
+          bindings.push( input.parse()? );
+        }
+        input.parse::< Token![ => ] >()?;
+        let body : Group = input.parse()?;
+        templates.push( ( bindings, body.stream() ) );
+      }
+      return Ok( Call::Named { templates } );
+    }
+
+    let callback = if input.peek( Token![ @ ] ) { None } else { Some( input.parse::< syn::Path >()? ) };
+
+    if let Some( callback ) = &callback
+    {
+      if input.peek( Token![ , ] )
+      {
+        let mut elements = vec![];
+        while !input.is_empty()
+        {
+          input.parse::< Token![ , ] >()?;
+          if input.is_empty()
+          {
+            break;
+          }
+          elements.push( input.parse()? );
+        }
+        return Ok( Call::Function { callback : callback.clone(), elements } );
+      }
+      input.parse::< Token![ where ] >()?;
+    }
+
+    let mut prefix = None;
+    let mut postfix = None;
+    let mut source = None;
+    while !input.is_empty()
+    {
+      input.parse::< Token![ @ ] >()?;
+      let key : syn::Ident = input.parse()?;
+      match key.to_string().as_str()
+      {
+        "Prefix" | "Postfix" =>
+        {
+          if postfix.is_some() || ( key == "Prefix" && prefix.is_some() )
+          {
+            return Err( syn_err!( key, "Order of options should be `@Prefix`, `@Postfix` and then either `@Each` or `@Product`" ) );
+          }
+          let value = Some( input.parse()? );
+          if key == "Prefix" { prefix = value } else { postfix = value }
+        },
+        "Each" =>
+        {
+          let mut elements = vec![];
+          while !input.is_empty()
+          {
+            elements.push( input.parse()? );
+          }
+          source = Some( Source::Each( elements ) );
+        },
+        "Product" =>
+        {
+          let mut lists = vec![];
+          while !input.is_empty()
+          {
+            let list : Group = input.parse()?;
+            if list.delimiter() != Delimiter::Brace
+            {
+              return Err( syn_err!( list.span(), "Expects list of elements in braces `{{ .. }}` after `@Product`" ) );
+            }
+            lists.push( list.stream().into_iter().collect() );
+          }
+          source = Some( Source::Product( lists ) );
+        },
+        _ => return Err( syn_err!
+        (
+          key,
+          "Unknown option `@{}`. Expects one of : `@Prefix`, `@Postfix`, `@Each`, `@Product`",
+          key
+        )),
+      }
+    }
+
+    let source = match source
+    {
+      Some( source ) => source,
+      None => return Err( input.error( "Expects option `@Each` or `@Product` with elements" ) ),
+    };
+    Ok( Call::Map { callback, prefix, postfix, source } )
+  }
+}
+
+///
+/// Input of the macro : path to crate `for_each`, `;` and the call.
+///
+
+struct ForEachInput
+{
+  krate : TokenStream,
+  call : Call,
+}
+
+impl Parse for ForEachInput
+{
+  fn parse( input : ParseStream< '_ > ) -> Result< Self >
+  {
+    let mut krate = TokenStream::new();
+    while !input.peek( Token![ ; ] )
+    {
+      if input.is_empty()
+      {
+        return Err( input.error( "Expects path to crate `for_each` followed by `;`. Don't use the macro directly, use `for_each::for_each_proc!` instead" ) );
+      }
+      krate.extend( std::iter::once( input.parse::< TokenTree >()? ) );
+    }
+    input.parse::< Token![ ; ] >()?;
+    let call = input.parse()?;
+    Ok( Self { krate, call } )
+  }
+}
+
+//
+
+pub fn for_each( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+{
+  let ForEachInput { krate, call } = syn::parse::< ForEachInput >( input )?;
+  let result = match call
+  {
+    Call::Function { callback, elements } =>
+    {
+      elements.into_iter().enumerate().map( | ( index, element ) |
+      {
+        let element = substitute( element.into(), &[], Some( index ) );
+        qt!{ #krate::braces_unwrap!( #callback, #element ); }
+      }).collect()
+    },
+    Call::Map { callback, prefix, postfix, source } =>
+    {
+      let callback = match callback
+      {
+        Some( callback ) => qt!{ #callback },
+        None => qt!{ #krate::identity },
+      };
+      let elements = match source
+      {
+        Source::Each( elements ) => elements.into_iter().map( TokenStream::from ).collect(),
+        Source::Product( lists ) => product( &lists ).into_iter().map( | combination |
+        {
+          let combination = combination.into_iter().map( | element | braces_unwrap( element.into() ) );
+          qt!{ { #( #combination ),* } }
+        }).collect::< Vec< _ > >(),
+      };
+      elements.into_iter().enumerate().map( | ( index, element ) |
+      {
+        let prefix = prefix.clone().map( | prefix | substitute( prefix.into(), &[], Some( index ) ) ).map( | prefix | qt!{ @Prefix{ #prefix } } );
+        let postfix = postfix.clone().map( | postfix | substitute( postfix.into(), &[], Some( index ) ) ).map( | postfix | qt!{ @Postfix{ #postfix } } );
+        let element = substitute( element, &[], Some( index ) );
+        qt!
+        {
+          #krate::braces_unwrap!
+          (
+            #callback where
+            #prefix
+            #postfix
+            @SRC{ #element }
+          );
+        }
+      }).collect()
+    },
+    Call::Named { templates } =>
+    {
+      templates.into_iter().flat_map( | ( bindings, body ) |
+      {
+        let indices : Vec< Vec< usize > > = product( &bindings.iter().map( | binding | ( 0..binding.elements.len() ).collect() ).collect::< Vec< _ > >() );
+        indices.into_iter().enumerate().map( move | ( index, combination ) |
+        {
+          let mut names = vec![];
+          for ( binding, i ) in bindings.iter().zip( combination )
+          {
+            names.push( ( binding.name.to_string(), binding.elements[ i ].clone() ) );
+            if let Some( index ) = &binding.index
+            {
+              names.push( ( index.to_string(), TokenTree::from( Literal::usize_unsuffixed( i ) ).into() ) );
+            }
+          }
+          substitute( body.clone(), &names, Some( index ) )
+        }).collect::< Vec< _ > >()
+      }).collect()
+    },
+  };
+  Ok( result )
+}
+
+///
+/// Cartesian product of lists, the last list changes the fastest.
+///
+
+fn product< T : Clone >( lists : &[ Vec< T > ] ) -> Vec< Vec< T > >
+{
+  lists.iter().fold( vec![ vec![] ], | combinations, list |
+  {
+    combinations.iter().flat_map( | combination |
+    {
+      list.iter().map( move | element |
+      {
+        let mut combination = combination.clone();
+        combination.push( element.clone() );
+        combination
+      })
+    }).collect()
+  })
+}
+
+///
+/// Remove outermost braces if the stream is a single braced group.
+///
+
+fn braces_unwrap( stream : TokenStream ) -> TokenStream
+{
+  let mut iter = stream.clone().into_iter();
+  match ( iter.next(), iter.next() )
+  {
+    ( Some( TokenTree::Group( group ) ), None ) if group.delimiter() == Delimiter::Brace => group.stream(),
+    _ => stream,
+  }
+}
+
+///
+/// Replace names by their values, inserted as is, and placeholder `@Index` by the index. Placeholder `@Index` of nested
+/// `for_each_proc!` is left for the nested call, while names are replaced everywhere.
+///
+
+fn substitute( stream : TokenStream, names : &[ ( String, TokenStream ) ], index : Option< usize > ) -> TokenStream
+{
+  let mut result = TokenStream::new();
+  let mut iter = stream.into_iter().peekable();
+  let mut nested = false;
+  while let Some( tree ) = iter.next()
+  {
+    match &tree
+    {
+      TokenTree::Punct( punct ) if punct.as_char() == '@' && index.is_some() =>
+      {
+        match iter.peek()
+        {
+          Some( TokenTree::Ident( ident ) ) if ident == "Index" =>
+          {
+            let mut literal = Literal::usize_unsuffixed( index.unwrap() );
+            literal.set_span( ident.span() );
+            iter.next();
+            result.extend( std::iter::once( TokenTree::from( literal ) ) );
+          },
+          _ => result.extend( std::iter::once( tree ) ),
+        }
+      },
+      TokenTree::Ident( ident ) =>
+      {
+        nested = ident == "for_each_proc" && matches!( iter.peek(), Some( TokenTree::Punct( punct ) ) if punct.as_char() == '!' );
+        match names.iter().find( | ( name, _ ) | ident == name )
+        {
+          Some( ( _, value ) ) => result.extend( value.clone() ),
+          None => result.extend( std::iter::once( tree ) ),
+        }
+        continue;
+      },
+      TokenTree::Group( group ) =>
+      {
+        let index = if nested { None } else { index };
+        let mut substituted = Group::new( group.delimiter(), substitute( group.stream(), names, index ) );
+        substituted.set_span( group.span() );
+        result.extend( std::iter::once( TokenTree::from( substituted ) ) );
+      },
+      TokenTree::Punct( punct ) if punct.as_char() == '!' && nested =>
+      {
+        result.extend( std::iter::once( tree ) );
+        continue;
+      },
+      _ => result.extend( std::iter::once( tree ) ),
+    }
+    nested = false;
+  }
+  result
+}
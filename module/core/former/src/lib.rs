@@ -15,9 +15,51 @@
 
 #![ doc = include_str!( concat!( env!( "CARGO_MANIFEST_DIR" ), "/", "Readme.md" ) ) ]
 
+#[ cfg( all( feature = "no_std", feature = "use_alloc" ) ) ]
+extern crate alloc;
+
 /// Former - variation of builder pattern. Implementation of its runtime.
 pub mod runtime;
 
+/// Keep items generated by derive `Former` which need allocation, such as `try_form`.
+#[ doc( hidden ) ]
+#[ macro_export ]
+#[ cfg( any( not( feature = "no_std" ), feature = "use_alloc" ) ) ]
+macro_rules! __if_alloc
+{
+  ( $( $Item : tt )* ) => { $( $Item )* };
+}
+
+/// Drop items generated by derive `Former` which need allocation, such as `try_form`.
+#[ doc( hidden ) ]
+#[ macro_export ]
+#[ cfg( all( feature = "no_std", not( feature = "use_alloc" ) ) ) ]
+macro_rules! __if_alloc
+{
+  ( $( $Item : tt )* ) => {};
+}
+
+/// Keep items generated by option `deserialize` of derive `Former`.
+#[ doc( hidden ) ]
+#[ macro_export ]
+#[ cfg( feature = "serde" ) ]
+macro_rules! __if_serde
+{
+  ( $( $Item : tt )* ) => { $( $Item )* };
+}
+
+/// Report that option `deserialize` of derive `Former` is used without feature `serde`.
+#[ doc( hidden ) ]
+#[ macro_export ]
+#[ cfg( not( feature = "serde" ) ) ]
+macro_rules! __if_serde
+{
+  ( $( $Item : tt )* ) =>
+  {
+    compile_error!( "Option `deserialize` of attribute `former` requires feature `serde` of crate `former`" );
+  };
+}
+
 /// Namespace with dependencies.
 #[ cfg( feature = "enabled" ) ]
 pub mod dependency
@@ -26,6 +68,8 @@ pub mod dependency
   // pub use former_runtime;
   // #[ cfg( any( feature = "meta", feature = "former_meta" ) ) ]
   pub use former_meta;
+  #[ cfg( feature = "serde" ) ]
+  pub use ::serde;
 }
 
 /// Protected namespace of the module.
@@ -0,0 +1,52 @@
+///
+/// Handler called on the end of forming. Gets formed entity and context of forming and returns the context.
+///
+/// Former of a structure calls the handler on `end()`. Subformer uses it to put formed entity into its superformer and to get back to the superformer.
+///
+
+pub trait ToSuperFormer< T, Context >
+{
+  /// Handle formed entity returning context of forming.
+  fn call( &self, formed : T, context : core::option::Option< Context > ) -> Context;
+}
+
+impl< T, Context, F > ToSuperFormer< T, Context > for F
+where
+  F : Fn( T, core::option::Option< Context > ) -> Context,
+{
+  #[ inline( always ) ]
+  fn call( &self, formed : T, context : core::option::Option< Context > ) -> Context
+  {
+    self( formed, context )
+  }
+}
+
+///
+/// Handler which returns formed entity ignoring context. Used by default, when a former is not a subformer.
+///
+
+#[ derive( Debug, Default ) ]
+pub struct ReturnFormed;
+
+impl< T > ToSuperFormer< T, T > for ReturnFormed
+{
+  #[ inline( always ) ]
+  fn call( &self, formed : T, _context : core::option::Option< T > ) -> T
+  {
+    formed
+  }
+}
+
+///
+/// Marker of a required field which is set. Used by formers in typestate mode.
+///
+
+#[ derive( Debug, Default, Clone, Copy ) ]
+pub struct Set;
+
+///
+/// Marker of a required field which is not set yet. Used by formers in typestate mode.
+///
+
+#[ derive( Debug, Default, Clone, Copy ) ]
+pub struct Unset;
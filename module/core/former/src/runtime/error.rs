@@ -0,0 +1,97 @@
+#[ cfg( feature = "no_std" ) ]
+use alloc::{ vec::Vec, string::{ String, ToString } };
+#[ cfg( not( feature = "no_std" ) ) ]
+use std::{ vec::Vec, string::{ String, ToString } };
+
+///
+/// Error of forming an entity. Lists every field which is not initialized and every failed validation.
+///
+
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct FormError
+{
+  /// Name of the formed entity.
+  pub entity : &'static str,
+  /// Names of fields which were neither set nor have default value.
+  pub missing : Vec< &'static str >,
+  /// Failed validations.
+  pub invalid : Vec< FormInvalid >,
+}
+
+impl FormError
+{
+
+  /// Make a new error of forming the entity with no problem found so far. It should be called by a former generated for your structure.
+  pub fn new( entity : &'static str ) -> Self
+  {
+    Self
+    {
+      entity,
+      missing : Vec::new(),
+      invalid : Vec::new(),
+    }
+  }
+
+  /// Is no problem found.
+  pub fn is_empty( &self ) -> bool
+  {
+    self.missing.is_empty() && self.invalid.is_empty()
+  }
+
+}
+
+impl core::fmt::Display for FormError
+{
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+    write!( f, "Failed to form {}", self.entity )?;
+    for field in self.missing.iter()
+    {
+      write!( f, "\n  Field '{}' isn't initialized", field )?;
+    }
+    for invalid in self.invalid.iter()
+    {
+      write!( f, "\n  {}", invalid )?;
+    }
+    Ok( () )
+  }
+}
+
+#[ cfg( not( feature = "no_std" ) ) ]
+impl std::error::Error for FormError {}
+
+///
+/// Failed validation of a field or of the whole entity.
+///
+
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct FormInvalid
+{
+  /// Name of the field. `None` if validation of the whole entity failed.
+  pub field : core::option::Option< &'static str >,
+  /// Message of the validator.
+  pub message : String,
+}
+
+impl FormInvalid
+{
+
+  /// Make a failed validation out of message of the validator. It should be called by a former generated for your structure.
+  pub fn new( field : core::option::Option< &'static str >, message : &dyn core::fmt::Display ) -> Self
+  {
+    Self { field, message : message.to_string() }
+  }
+
+}
+
+impl core::fmt::Display for FormInvalid
+{
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+    match self.field
+    {
+      Some( field ) => write!( f, "Field '{}' is invalid : {}", field, self.message ),
+      None => write!( f, "Entity is invalid : {}", self.message ),
+    }
+  }
+}
@@ -4,8 +4,6 @@
 ///
 
 pub trait HashMapLike< K, E >
-where
-  K : core::cmp::Eq + core::hash::Hash,
 {
   /// Inserts a key-value pair into the map.
   fn insert( &mut self, k : K, e : E ) -> Option< E >;
@@ -21,6 +19,16 @@ where
   }
 }
 
+impl< K, E > HashMapLike< K, E > for std::collections::BTreeMap< K, E >
+where
+  K : core::cmp::Ord,
+{
+  fn insert( &mut self, k : K, e : E ) -> Option< E >
+  {
+    std::collections::BTreeMap::insert( self, k, e )
+  }
+}
+
 ///
 /// Class for forming hashmap-like fields.
 ///
@@ -28,7 +36,6 @@ where
 #[derive( Debug, Default )]
 pub struct HashMapFormer< K, E, HashMap, Former, ContainerEnd >
 where
-  K : core::cmp::Eq + core::hash::Hash,
   HashMap : HashMapLike< K, E > + core::default::Default,
   ContainerEnd : Fn( &mut Former, core::option::Option< HashMap > ),
 {
@@ -42,7 +49,6 @@ where
 impl< K, E, HashMap, Former, ContainerEnd >
 HashMapFormer< K, E, HashMap, Former, ContainerEnd >
 where
-  K : core::cmp::Eq + core::hash::Hash,
   HashMap : HashMapLike< K, E > + core::default::Default,
   ContainerEnd : Fn( &mut Former, core::option::Option< HashMap > ),
 {
@@ -4,10 +4,8 @@
 ///
 
 pub trait HashSetLike< E >
-where
-  E : core::cmp::Eq + core::hash::Hash,
 {
-  /// Inserts a key-value pair into the map.
+  /// Inserts a value into the set, replacing the existing equal value. Returns the replaced value.
   fn insert( &mut self, e : E ) -> Option< E >;
 }
 
@@ -21,6 +19,16 @@ where
   }
 }
 
+impl< E > HashSetLike< E > for std::collections::BTreeSet< E >
+where
+  E : core::cmp::Ord,
+{
+  fn insert( &mut self, e : E ) -> Option< E >
+  {
+    std::collections::BTreeSet::replace( self, e )
+  }
+}
+
 ///
 /// Class for forming hashset-like fields.
 ///
@@ -28,7 +36,6 @@ where
 #[derive( Debug, Default )]
 pub struct HashSetFormer< E, HashSet, Former, ContainerEnd >
 where
-  E : core::cmp::Eq + core::hash::Hash,
   HashSet : HashSetLike< E > + core::default::Default,
   ContainerEnd : Fn( &mut Former, core::option::Option< HashSet > ),
 {
@@ -41,7 +48,6 @@ where
 impl< E, HashSet, Former, ContainerEnd >
 HashSetFormer< E, HashSet, Former, ContainerEnd >
 where
-  E : core::cmp::Eq + core::hash::Hash,
   HashSet : HashSetLike< E > + core::default::Default,
   ContainerEnd : Fn( &mut Former, core::option::Option< HashSet > ),
 {
@@ -74,7 +80,7 @@ where
     self.former
   }
 
-  /// Inserts a key-value pair into the map. Make a new container if it was not made so far.
+  /// Inserts a value into the set. Make a new container if it was not made so far.
   pub fn insert< E2 >( mut self, e : E2 ) -> Self
   where
     E2 : core::convert::Into< E >,
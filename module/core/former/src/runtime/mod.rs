@@ -3,6 +3,11 @@
 //! Former - variation of builder pattern. Implementation of its runtime.
 //!
 
+/// Handlers of the end of forming.
+mod axiomatic;
+/// Error of forming.
+#[ cfg( any( not( feature = "no_std" ), feature = "use_alloc" ) ) ]
+mod error;
 /// Former of a fector.
 #[ cfg( not( feature = "no_std" ) ) ]
 mod vector;
@@ -25,6 +30,13 @@ pub mod protected
   pub use super::exposed::*;
   #[ doc( inline ) ]
   #[ allow( unused_imports ) ]
+  pub use super::axiomatic::*;
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  #[ cfg( any( not( feature = "no_std" ), feature = "use_alloc" ) ) ]
+  pub use super::error::*;
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
   #[ cfg( not( feature = "no_std" ) ) ]
   pub use super::vector::*;
   #[ doc( inline ) ]
@@ -17,6 +17,32 @@ impl< E > VectorLike< E > for std::vec::Vec< E >
   }
 }
 
+impl< E > VectorLike< E > for std::collections::VecDeque< E >
+{
+  fn push( &mut self, e : E )
+  {
+    std::collections::VecDeque::push_back( self, e );
+  }
+}
+
+impl< E > VectorLike< E > for std::collections::LinkedList< E >
+{
+  fn push( &mut self, e : E )
+  {
+    std::collections::LinkedList::push_back( self, e );
+  }
+}
+
+impl< E > VectorLike< E > for std::collections::BinaryHeap< E >
+where
+  E : core::cmp::Ord,
+{
+  fn push( &mut self, e : E )
+  {
+    std::collections::BinaryHeap::push( self, e );
+  }
+}
+
 ///
 /// Class for forming vector-like fields.
 ///
@@ -24,7 +50,7 @@ impl< E > VectorLike< E > for std::vec::Vec< E >
 #[derive( Debug, Default )]
 pub struct VectorFormer< E, Vector, Former, ContainerEnd >
 where
-  Vector : VectorLike< E > + core::fmt::Debug + core::cmp::PartialEq + core::default::Default,
+  Vector : VectorLike< E > + core::default::Default,
   ContainerEnd : Fn( &mut Former, core::option::Option< Vector > ),
 {
   container : Option< Vector >,
@@ -35,7 +61,7 @@ where
 
 impl< E, Vector, Former, ContainerEnd > VectorFormer< E, Vector, Former, ContainerEnd >
 where
-  Vector : VectorLike< E > + core::fmt::Debug + core::cmp::PartialEq + core::default::Default,
+  Vector : VectorLike< E > + core::default::Default,
   ContainerEnd : Fn( &mut Former, core::option::Option< Vector > ),
 {
 
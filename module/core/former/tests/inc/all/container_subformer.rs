@@ -0,0 +1,111 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+use std::collections::{ BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque };
+
+///
+/// User collection which is not recognized as container by its name.
+///
+
+#[ derive( Debug, Default, PartialEq ) ]
+pub struct Stack< E >
+{
+  items : Vec< E >,
+}
+
+impl< E > TheModule::runtime::VectorLike< E > for Stack< E >
+{
+  fn push( &mut self, e : E )
+  {
+    self.items.push( e );
+  }
+}
+
+#[ derive( Debug, TheModule::Former ) ]
+pub struct Struct1
+{
+  #[ container ]
+  vec_1 : Vec< String >,
+  #[ container ]
+  deque_1 : VecDeque< i32 >,
+  #[ container ]
+  list_1 : LinkedList< i32 >,
+  #[ container ]
+  heap_1 : Option< BinaryHeap< i32 > >,
+  #[ container ]
+  btreemap_1 : BTreeMap< String, i32 >,
+  #[ container ]
+  #[ alias( tags ) ]
+  btreeset_1 : BTreeSet< String >,
+  #[ container( adapter = TheModule::runtime::VectorLike ) ]
+  stack_1 : Stack< u8 >,
+}
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+#[ former( typestate ) ]
+pub struct Batch
+{
+  id : u32,
+  #[ container ]
+  items : Vec< String >,
+}
+
+//
+
+tests_impls!
+{
+  fn containers()
+  {
+    let got = Struct1::former()
+    .vec_1().push( "a" ).push( "b" ).end()
+    .deque_1().push( 1 ).push( 2 ).end()
+    .list_1().replace( LinkedList::from( [ 3 ] ) ).push( 4 ).end()
+    .heap_1().push( 5 ).push( 9 ).end()
+    .btreemap_1().insert( "k2", 2 ).insert( "k1", 1 ).end()
+    .tags().insert( "x" ).insert( "x" ).end()
+    .stack_1().push( 7u8 ).end()
+    .form();
+
+    a_id!( got.vec_1, vec![ "a".to_string(), "b".to_string() ] );
+    a_id!( got.deque_1, VecDeque::from( [ 1, 2 ] ) );
+    a_id!( got.list_1, LinkedList::from( [ 3, 4 ] ) );
+    a_id!( got.heap_1.map( | heap | heap.into_sorted_vec() ), Some( vec![ 5, 9 ] ) );
+    a_id!( got.btreemap_1, BTreeMap::from( [ ( "k1".to_string(), 1 ), ( "k2".to_string(), 2 ) ] ) );
+    a_id!( got.btreeset_1, BTreeSet::from( [ "x".to_string() ] ) );
+    a_id!( got.stack_1, Stack { items : vec![ 7 ] } );
+  }
+
+  //
+
+  fn not_set()
+  {
+    let got = Struct1::former()
+    .vec_1().end()
+    .form();
+
+    a_id!( got.vec_1, Vec::< String >::new() );
+    a_id!( got.deque_1, VecDeque::new() );
+    a_id!( got.heap_1.is_none(), true );
+    a_id!( got.stack_1, Stack::default() );
+  }
+
+  //
+
+  fn typestate()
+  {
+    let got = Batch::former().items().push( "a" ).push( "b" ).end().id( 1u32 ).form();
+    a_id!( got, Batch { id : 1, items : vec![ "a".to_string(), "b".to_string() ] } );
+
+    let got = Batch::former().id( 2u32 ).items().end().form();
+    a_id!( got, Batch { id : 2, items : vec![] } );
+  }
+}
+
+//
+
+tests_index!
+{
+  containers,
+  not_set,
+  typestate,
+}
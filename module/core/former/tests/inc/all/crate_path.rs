@@ -0,0 +1,57 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+/// Facade which re-exports crate `former` under another name.
+pub mod facade
+{
+  pub use ::former as the_former;
+}
+
+#[ derive( Debug, PartialEq, facade::the_former::Former ) ]
+#[ former( crate = crate::inc::crate_path::facade::the_former ) ]
+pub struct Command
+{
+  name : String,
+  #[ subformer ]
+  options : Options,
+  #[ container ]
+  args : Vec< String >,
+}
+
+#[ derive( Debug, PartialEq, Default, facade::the_former::Former ) ]
+#[ former( crate = crate::inc::crate_path::facade::the_former, typestate ) ]
+pub struct Options
+{
+  verbose : bool,
+}
+
+//
+
+tests_impls!
+{
+  fn crate_path()
+  {
+    let got = Command::former()
+    .name( "run" )
+    .options().verbose( true ).end()
+    .args().push( "a" ).end()
+    .form();
+    let exp = Command
+    {
+      name : "run".to_string(),
+      options : Options { verbose : true },
+      args : vec![ "a".to_string() ],
+    };
+    a_id!( got, exp );
+
+    let got = Options::former().verbose( false ).try_form().unwrap();
+    a_id!( got, Options { verbose : false } );
+  }
+}
+
+//
+
+tests_index!
+{
+  crate_path,
+}
@@ -0,0 +1,70 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+#[ former( deserialize ) ]
+#[ serde( rename_all = "kebab-case" ) ]
+pub struct Config
+{
+  #[ default( "localhost" ) ]
+  host : String,
+  #[ default( 8080 ) ]
+  port : i32,
+  #[ serde( alias = "login" ) ]
+  user_name : Option< String >,
+  verbose : bool,
+}
+
+//
+
+tests_impls!
+{
+  fn partial()
+  {
+    let former : ConfigFormer = serde_json::from_str( r#"{ "port" : 80 }"# ).unwrap();
+    let got = former.form();
+    let expected = Config
+    {
+      host : "localhost".to_string(),
+      port : 80,
+      user_name : None,
+      verbose : false,
+    };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn layers()
+  {
+    let defaults : ConfigFormer = serde_json::from_str( r#"{ "host" : "example.com", "port" : 80 }"# ).unwrap();
+    let file : ConfigFormer = serde_json::from_str( r#"{ "port" : 8000, "user-name" : "alice" }"# ).unwrap();
+    let env : ConfigFormer = serde_json::from_str( r#"{ "login" : "bob" }"# ).unwrap();
+    let got = defaults.merge( file ).merge( env ).verbose( true ).form();
+    let expected = Config
+    {
+      host : "example.com".to_string(),
+      port : 8000,
+      user_name : Some( "bob".to_string() ),
+      verbose : true,
+    };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn wrong_type()
+  {
+    let got = serde_json::from_str::< ConfigFormer >( r#"{ "port" : "80" }"# );
+    a_true!( got.is_err() );
+  }
+}
+
+//
+
+tests_index!
+{
+  partial,
+  layers,
+  wrong_type,
+}
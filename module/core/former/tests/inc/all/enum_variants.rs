@@ -0,0 +1,118 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+pub enum Shape
+{
+  Circle
+  {
+    radius : i32,
+  },
+  RoundedRect
+  {
+    width : i32,
+    height : i32,
+    #[ default( 2 ) ]
+    #[ alias( r ) ]
+    corner_radius : i32,
+    label : Option< String >,
+  },
+  Point,
+  IOPoint2d,
+  Crate,
+  Type,
+}
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+pub enum Tree< 'a, T >
+{
+  Leaf
+  {
+    value : T,
+  },
+  Named
+  {
+    name : &'a str,
+  },
+  Empty,
+}
+
+//
+
+tests_impls!
+{
+  fn named_variant()
+  {
+    let got = Shape::circle().radius( 3 ).form();
+    let expected = Shape::Circle { radius : 3 };
+    a_id!( got, expected );
+
+    let got = Shape::circle().form();
+    let expected = Shape::Circle { radius : 0 };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn attributes()
+  {
+    let got = Shape::rounded_rect().width( 4 ).height( 5 ).form();
+    let expected = Shape::RoundedRect { width : 4, height : 5, corner_radius : 2, label : None };
+    a_id!( got, expected );
+
+    let got = Shape::rounded_rect().r( 1 ).label( "box" ).form();
+    let expected = Shape::RoundedRect { width : 0, height : 0, corner_radius : 1, label : Some( "box".to_string() ) };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn unit_variant()
+  {
+    let got = Shape::point().form();
+    let expected = Shape::Point;
+    a_id!( got, expected );
+
+    let got = Shape::point().perform();
+    a_id!( got, expected );
+
+    let got = Shape::io_point2d().form();
+    a_id!( got, Shape::IOPoint2d );
+  }
+
+  //
+
+  fn keyword_variant()
+  {
+    let got = Shape::crate_().form();
+    a_id!( got, Shape::Crate );
+
+    let got = Shape::r#type().form();
+    a_id!( got, Shape::Type );
+  }
+
+  //
+
+  fn generic_variant()
+  {
+    let got = Tree::< '_, i32 >::leaf().value( 13 ).form();
+    a_id!( got, Tree::Leaf { value : 13 } );
+
+    let got = Tree::< '_, i32 >::named().name( "a" ).form();
+    a_id!( got, Tree::Named { name : "a" } );
+
+    let got = Tree::< '_, i32 >::empty().form();
+    a_id!( got, Tree::Empty );
+  }
+}
+
+//
+
+tests_index!
+{
+  named_variant,
+  attributes,
+  unit_variant,
+  keyword_variant,
+  generic_variant,
+}
@@ -0,0 +1,70 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+pub struct Config
+{
+  #[ default( "localhost" ) ]
+  host : String,
+  #[ default( 8080 ) ]
+  port : i32,
+  user : Option< String >,
+  verbose : bool,
+}
+
+//
+
+tests_impls!
+{
+  fn merge_overrides()
+  {
+    let defaults = Config::former().host( "example.com" ).port( 80 );
+    let file = Config::former().port( 8000 ).user( "alice" );
+    let cli = Config::former().verbose( true );
+    let got = defaults.merge( file ).merge( cli ).form();
+    let expected = Config
+    {
+      host : "example.com".to_string(),
+      port : 8000,
+      user : Some( "alice".to_string() ),
+      verbose : true,
+    };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn merge_keeps_unset()
+  {
+    let got = Config::former().user( "alice" ).merge( Config::former() ).form();
+    let expected = Config
+    {
+      host : "localhost".to_string(),
+      port : 8080,
+      user : Some( "alice".to_string() ),
+      verbose : false,
+    };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn merge_then_set()
+  {
+    let got = Config::former()
+    .merge( Config::former().port( 1 ) )
+    .verbose( true )
+    .form();
+    a_id!( got.port, 1 );
+    a_id!( got.verbose, true );
+  }
+}
+
+//
+
+tests_index!
+{
+  merge_overrides,
+  merge_keeps_unset,
+  merge_then_set,
+}
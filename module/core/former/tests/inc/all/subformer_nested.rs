@@ -0,0 +1,109 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+pub struct Endpoint
+{
+  host : String,
+  #[ default( 80 ) ]
+  port : i32,
+}
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+pub struct Server
+{
+  name : String,
+  #[ subformer ]
+  endpoint : Endpoint,
+  #[ subformer ]
+  #[ alias( fallback ) ]
+  backup : Option< Endpoint >,
+}
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+pub struct Cluster
+{
+  #[ subformer ]
+  primary : Server,
+  size : usize,
+}
+
+//
+
+tests_impls!
+{
+  fn nested()
+  {
+    let got = Server::former()
+    .name( "main" )
+    .endpoint().host( "localhost" ).port( 8080 ).end()
+    .form();
+    let expected = Server
+    {
+      name : "main".to_string(),
+      endpoint : Endpoint { host : "localhost".to_string(), port : 8080 },
+      backup : None,
+    };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn optional_and_alias()
+  {
+    let got = Server::former()
+    .fallback().host( "backup" ).end()
+    .endpoint().end()
+    .form();
+    let expected = Server
+    {
+      name : "".to_string(),
+      endpoint : Endpoint { host : "".to_string(), port : 80 },
+      backup : Some( Endpoint { host : "backup".to_string(), port : 80 } ),
+    };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn deep()
+  {
+    let got = Cluster::former()
+    .size( 3usize )
+    .primary()
+      .name( "a" )
+      .endpoint().host( "h" ).end()
+    .end()
+    .form();
+    let expected = Cluster
+    {
+      primary : Server
+      {
+        name : "a".to_string(),
+        endpoint : Endpoint { host : "h".to_string(), port : 80 },
+        backup : None,
+      },
+      size : 3,
+    };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn end_without_superformer()
+  {
+    let got = Endpoint::former().host( "x" ).end();
+    let expected = Endpoint { host : "x".to_string(), port : 80 };
+    a_id!( got, expected );
+  }
+}
+
+//
+
+tests_index!
+{
+  nested,
+  optional_and_alias,
+  deep,
+  end_without_superformer,
+}
@@ -0,0 +1,139 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+#[ derive( Debug, PartialEq ) ]
+pub enum State
+{
+  On,
+  Off,
+}
+
+fn port_validate( port : &i32 ) -> Result< (), String >
+{
+  if *port > 0 && *port < 65536
+  {
+    Ok( () )
+  }
+  else
+  {
+    Err( format!( "port {} is out of range", port ) )
+  }
+}
+
+fn device_validate( device : &Device ) -> Result< (), &'static str >
+{
+  if device.name.is_empty() && device.label.is_none()
+  {
+    Err( "either name or label should be set" )
+  }
+  else
+  {
+    Ok( () )
+  }
+}
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+#[ validate( device_validate ) ]
+pub struct Device
+{
+  name : String,
+  label : Option< String >,
+  state : State,
+  mode : State,
+  #[ default( 80 ) ]
+  #[ validate( port_validate ) ]
+  port : i32,
+}
+
+//
+
+tests_impls!
+{
+  fn formed()
+  {
+    let got = Device::former()
+    .name( "a" )
+    .state( State::On )
+    .mode( State::Off )
+    .try_form();
+    let expected = Device
+    {
+      name : "a".to_string(),
+      label : None,
+      state : State::On,
+      mode : State::Off,
+      port : 80,
+    };
+    a_id!( got, Ok( expected ) );
+  }
+
+  //
+
+  fn missing()
+  {
+    let got = Device::former().name( "a" ).try_form().unwrap_err();
+    a_id!( got.entity, "Device" );
+    a_id!( got.missing, vec![ "state", "mode" ] );
+    a_id!( got.invalid, vec![] );
+    a_id!( got.to_string(), "Failed to form Device\n  Field 'state' isn't initialized\n  Field 'mode' isn't initialized" );
+  }
+
+  //
+
+  fn invalid_field()
+  {
+    let got = Device::former()
+    .name( "a" )
+    .state( State::On )
+    .mode( State::On )
+    .port( 0 )
+    .try_form()
+    .unwrap_err();
+    let expected = vec!
+    [
+      TheModule::runtime::FormInvalid { field : Some( "port" ), message : "port 0 is out of range".to_string() },
+    ];
+    a_id!( got.missing, Vec::< &str >::new() );
+    a_id!( got.invalid, expected );
+  }
+
+  //
+
+  fn invalid_struct()
+  {
+    let got = Device::former()
+    .state( State::On )
+    .mode( State::On )
+    .try_form()
+    .unwrap_err();
+    let expected = vec!
+    [
+      TheModule::runtime::FormInvalid { field : None, message : "either name or label should be set".to_string() },
+    ];
+    a_id!( got.invalid, expected );
+  }
+
+  //
+
+  #[ should_panic( expected = "Field 'port' is invalid : port 70000 is out of range" ) ]
+  fn form_panics_on_invalid()
+  {
+    Device::former()
+    .name( "a" )
+    .state( State::On )
+    .mode( State::On )
+    .port( 70000 )
+    .form();
+  }
+}
+
+//
+
+tests_index!
+{
+  formed,
+  missing,
+  invalid_field,
+  invalid_struct,
+  form_panics_on_invalid,
+}
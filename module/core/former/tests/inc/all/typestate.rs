@@ -0,0 +1,92 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+#[ derive( Debug, PartialEq ) ]
+pub enum Kind
+{
+  Ping,
+  Data,
+}
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+#[ former( typestate ) ]
+pub struct Message
+{
+  id : u32,
+  #[ alias( k ) ]
+  kind : Kind,
+  payload : Option< String >,
+  #[ default( 16 ) ]
+  ttl : u8,
+}
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+pub struct Header
+{
+  name : String,
+}
+
+#[ derive( Debug, PartialEq, TheModule::Former ) ]
+#[ former( typestate ) ]
+pub struct Envelope
+{
+  id : u32,
+  #[ subformer ]
+  header : Header,
+}
+
+//
+
+tests_impls!
+{
+  fn required_set()
+  {
+    let got = Message::former()
+    .id( 13u32 )
+    .kind( Kind::Ping )
+    .form();
+    let expected = Message { id : 13, kind : Kind::Ping, payload : None, ttl : 16 };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn any_order()
+  {
+    let got = Message::former()
+    .payload( "abc" )
+    .k( Kind::Data )
+    .ttl( 1u8 )
+    .id( 3u32 )
+    .form();
+    let expected = Message { id : 3, kind : Kind::Data, payload : Some( "abc".to_string() ), ttl : 1 };
+    a_id!( got, expected );
+
+    let got = Message::former()
+    .kind( Kind::Data )
+    .id( 3u32 )
+    .end();
+    let expected = Message { id : 3, kind : Kind::Data, payload : None, ttl : 16 };
+    a_id!( got, expected );
+  }
+
+  //
+
+  fn subformer_required()
+  {
+    let got = Envelope::former().header().name( "a" ).end().id( 1u32 ).form();
+    a_id!( got, Envelope { id : 1, header : Header { name : "a".to_string() } } );
+
+    let got = Envelope::former().id( 1u32 ).header().name( "b" ).end().form();
+    a_id!( got, Envelope { id : 1, header : Header { name : "b".to_string() } } );
+  }
+}
+
+//
+
+tests_index!
+{
+  required_set,
+  any_order,
+  subformer_required,
+}
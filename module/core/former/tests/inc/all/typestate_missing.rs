@@ -0,0 +1,14 @@
+use former::Former;
+
+#[ derive( Former ) ]
+#[ former( typestate ) ]
+pub struct Message
+{
+  id : u32,
+  kind : String,
+}
+
+fn main()
+{
+  let _message = Message::former().id( 1u32 ).form();
+}
@@ -0,0 +1,21 @@
+use former::Former;
+
+#[ derive( Former ) ]
+pub struct Header
+{
+  name : String,
+}
+
+#[ derive( Former ) ]
+#[ former( typestate ) ]
+pub struct Envelope
+{
+  id : u32,
+  #[ subformer ]
+  header : Header,
+}
+
+fn main()
+{
+  let _envelope = Envelope::former().id( 1u32 ).form();
+}
@@ -32,6 +32,26 @@ mod unsigned_primitive_types;
 mod default_container;
 #[ path = "./all/perform.rs" ]
 mod perform;
+#[ path = "./all/enum_variants.rs" ]
+mod enum_variants;
+#[ path = "./all/subformer_nested.rs" ]
+mod subformer_nested;
+#[ cfg( any( not( feature = "no_std" ), feature = "use_alloc" ) ) ]
+#[ path = "./all/try_form.rs" ]
+mod try_form;
+#[ cfg( not( feature = "no_std" ) ) ]
+#[ path = "./all/container_subformer.rs" ]
+mod container_subformer;
+#[ path = "./all/typestate.rs" ]
+mod typestate;
+#[ path = "./all/merge.rs" ]
+mod merge;
+#[ cfg( not( feature = "no_std" ) ) ]
+#[ path = "./all/crate_path.rs" ]
+mod crate_path;
+#[ cfg( feature = "serde" ) ]
+#[ path = "./all/deserialize.rs" ]
+mod deserialize;
 
 //
 
@@ -53,6 +73,8 @@ only_for_terminal_module!
     t.compile_fail( "tests/inc/all/former_bad_attr.rs" );
     t.pass( "tests/inc/all/former_hashmap_without_parameter.rs" );
     t.pass( "tests/inc/all/former_vector_without_parameter.rs" );
+    t.compile_fail( "tests/inc/all/typestate_missing.rs" );
+    t.compile_fail( "tests/inc/all/typestate_subformer_missing.rs" );
 
   }
 
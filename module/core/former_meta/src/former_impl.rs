@@ -30,6 +30,10 @@ struct Attributes
   default : Option< AttributeDefault >,
   setter : Option< AttributeSetter >,
   alias : Option< AttributeAlias >,
+  subformer : Option< AttributeSubformer >,
+  container : Option< AttributeContainer >,
+  validate : Vec< AttributeValidate >,
+  serde : Vec< syn::Attribute >,
 }
 
 impl Attributes
@@ -39,6 +43,10 @@ impl Attributes
     let mut default = None;
     let mut setter = None;
     let mut alias = None;
+    let mut subformer = None;
+    let mut container = None;
+    let mut validate = Vec::new();
+    let mut serde = Vec::new();
     for attr in attributes
     {
       let key_ident = attr.path.get_ident()
@@ -61,6 +69,25 @@ impl Attributes
           let attr_alias = syn::parse2::< AttributeAlias >( attr.tokens.clone() )?;
           alias.replace( attr_alias );
         }
+        "subformer" =>
+        {
+          let attr_subformer = syn::parse2::< AttributeSubformer >( attr.tokens.clone() )?;
+          subformer.replace( attr_subformer );
+        }
+        "container" =>
+        {
+          let attr_container = syn::parse2::< AttributeContainer >( attr.tokens.clone() )?;
+          container.replace( attr_container );
+        }
+        "validate" =>
+        {
+          let attr_validate = syn::parse2::< AttributeValidate >( attr.tokens.clone() )?;
+          validate.push( attr_validate );
+        }
+        "serde" =>
+        {
+          serde.push( attr.clone() );
+        }
         "doc" =>
         {
         }
@@ -71,7 +98,13 @@ impl Attributes
       }
     }
 
-    Ok( Attributes { default, setter, alias } )
+    if let ( Some( _ ), Some( _ ) ) = ( &subformer, &container )
+    {
+      let attr = attributes.iter().find( | attr | attr.path.is_ident( "container" ) ).unwrap();
+      return Err( syn_err!( attr, "Attributes `subformer` and `container` can't be used together" ) );
+    }
+
+    Ok( Attributes { default, setter, alias, subformer, container, validate, serde } )
   }
 }
 
@@ -181,6 +214,146 @@ impl syn::parse::Parse for AttributeAlias
   }
 }
 
+///
+/// Attribute to generate subformer for a field which type derives former too.
+///
+/// `#[ subformer ]`
+///
+
+struct AttributeSubformer;
+
+impl syn::parse::Parse for AttributeSubformer
+{
+  fn parse( input : syn::parse::ParseStream< '_ > ) -> Result< Self >
+  {
+    if !input.is_empty()
+    {
+      return Err( input.error( "Attribute `subformer` does not expect arguments" ) );
+    }
+    Ok( Self )
+  }
+}
+
+///
+/// Attribute to generate subformer for a field which is a container.
+///
+/// `#[ container ]`
+/// `#[ container( adapter = former::runtime::HashMapLike ) ]`
+/// `#[ container( adapter = former::runtime::VectorLike< u8 > ) ]`
+///
+/// Without adapter kind of the container is deduced from its type. Adapter is one of traits
+/// `VectorLike`, `HashMapLike` or `HashSetLike`, which the type of the field implements.
+/// If parameters of the adapter are not specified then parameters of type of the field are used.
+///
+
+struct AttributeContainer
+{
+  adapter : Option< syn::Path >,
+}
+
+impl syn::parse::Parse for AttributeContainer
+{
+  fn parse( input : syn::parse::ParseStream< '_ > ) -> Result< Self >
+  {
+    if input.is_empty()
+    {
+      return Ok( Self { adapter : None } );
+    }
+    let input2;
+    syn::parenthesized!( input2 in input );
+    let key : syn::Ident = input2.parse()?;
+    if key != "adapter"
+    {
+      return Err( syn_err!( key, "Unknown parameter of attribute `container` : {}, expected `adapter`", key ) );
+    }
+    input2.parse::< syn::Token![ = ] >()?;
+    let adapter : syn::Path = input2.parse()?;
+    Ok( Self { adapter : Some( adapter ) } )
+  }
+}
+
+///
+/// Attribute to validate a field or the whole structure before it is formed.
+///
+/// `#[ validate( path::to::validator ) ]`
+///
+/// Validator takes reference on the field or the structure and returns `Result< (), E >` where `E : Display`.
+///
+
+#[allow( dead_code )]
+struct AttributeValidate
+{
+  paren_token : syn::token::Paren,
+  path : syn::Path,
+}
+
+impl syn::parse::Parse for AttributeValidate
+{
+  fn parse( input : syn::parse::ParseStream< '_ > ) -> Result< Self >
+  {
+    let input2;
+    Ok( Self
+    {
+      paren_token : syn::parenthesized!( input2 in input ),
+      path : input2.parse()?,
+    })
+  }
+}
+
+///
+/// Attribute to set options of the former.
+///
+/// `#[ former( typestate ) ]`
+/// `#[ former( deserialize ) ]`
+/// `#[ former( crate = my_facade::former ) ]`
+///
+
+struct AttributeFormer
+{
+  typestate : bool,
+  deserialize : bool,
+  krate : Option< syn::Path >,
+}
+
+impl syn::parse::Parse for AttributeFormer
+{
+  fn parse( input : syn::parse::ParseStream< '_ > ) -> Result< Self >
+  {
+    let input2;
+    syn::parenthesized!( input2 in input );
+    let mut typestate = false;
+    let mut deserialize = false;
+    let mut krate = None;
+    while !input2.is_empty()
+    {
+      let span = input2.span();
+      if let Some( path ) = crate_option( &input2 )?
+      {
+        if krate.is_some()
+        {
+          return Err( syn::Error::new( span, "Option `crate` of attribute `former` is set several times" ) );
+        }
+        krate = Some( path );
+      }
+      else
+      {
+        let option : syn::Ident = input2.parse()?;
+        match option.to_string().as_ref()
+        {
+          "typestate" => typestate = true,
+          "deserialize" => deserialize = true,
+          _ => return Err( syn_err!( option, "Unknown option of attribute `former` : {}, expected `typestate`, `deserialize` or `crate = path`", option ) ),
+        }
+      }
+      if !input2.is_empty()
+      {
+        input2.parse::< syn::Token![ , ] >()?;
+      }
+    }
+    Ok( Self { typestate, deserialize, krate } )
+  }
+}
+
 ///
 /// Is type under Option.
 ///
@@ -387,211 +560,1036 @@ fn field_form_map( field : &FormerField< '_ > ) -> Result< proc_macro2::TokenStr
 }
 
 ///
-/// Extract name of a field out.
-///
-
-#[inline]
-fn field_name_map( field : &FormerField< '_ > ) -> syn::Ident
-{
-  field.ident.clone()
-}
-
+/// Generate code converting a field of the former to the field of the structure without panic.
 ///
-/// Generate a former setter for the field.
+/// Field which is neither set nor has default value is registered as missing in `error`.
+/// Returns code extracting the field and code unwrapping it, which is empty if the field can't be missing.
 ///
 /// ### Basic use-case. of output
 ///
 /// ```compile_fail
-/// pub fn int_1< Src >( mut self, src : Src ) -> Self
-/// where Src : Into< i32 >,
+/// let state = if self.state.is_some()
 /// {
-///   debug_assert!( self.int_1.is_none() );
-///   self.int_1 = Some( src.into() );
-///   self
+///   Some( self.state.take().unwrap() )
+/// }
+/// else
+/// {
+///   None
+/// };
+/// if state.is_none()
+/// {
+///   error.missing.push( "state" );
 /// }
+/// // ...
+/// let state = state.unwrap();
 /// ```
 ///
 
 #[inline]
-fn field_setter_map( field : &FormerField< '_ > ) -> Result< proc_macro2::TokenStream >
+fn field_try_form_map( field : &FormerField< '_ > ) -> Result< ( proc_macro2::TokenStream, proc_macro2::TokenStream ) >
 {
-  let ident = &field.ident;
-  if let Some( setter_attr ) = &field.attrs.setter
+  if field.is_optional || field.attrs.default.is_some()
   {
-    if !setter_attr.condition.value()
-    {
-      return Ok( qt!{ } );
-    }
+    return Ok( ( field_form_map( field )?, qt!{} ) );
   }
 
-  let non_optional_ty = &field.non_optional_ty;
-  let setter_tokens = field_setter( ident, non_optional_ty, ident );
-  if let Some( alias_attr ) = &field.attrs.alias
-  {
-    let alias_tokens = field_setter( ident, non_optional_ty, &alias_attr.alias );
+  let ident = field.ident;
+  let ident_str = ident.to_string();
+  let ty = field.ty;
 
-    let token = qt!
+  let tokens = qt!
+  {
+    let #ident = if self.#ident.is_some()
     {
-      #setter_tokens
+      ::core::option::Option::Some( self.#ident.take().unwrap() )
+    }
+    else
+    {
+      // Autoref specialization
+      trait NotDefault< T >
+      {
+        fn maybe_default( self : &Self ) -> ::core::option::Option< T > { ::core::option::Option::None }
+      }
 
-      #alias_tokens
+      trait WithDefault< T >
+      {
+        fn maybe_default( self : &Self ) -> ::core::option::Option< T >;
+      }
+
+      impl< T > NotDefault< T >
+      for & ::core::marker::PhantomData< T >
+      {}
+
+      impl< T > WithDefault< T >
+      for ::core::marker::PhantomData< T >
+      where T : ::core::default::Default,
+      {
+        fn maybe_default( self : &Self ) -> ::core::option::Option< T >
+        {
+          ::core::option::Option::Some( T::default() )
+        }
+      }
+
+      ( &::core::marker::PhantomData::< #ty > ).maybe_default()
     };
-    return Ok( token );
-  }
+    if #ident.is_none()
+    {
+      error.missing.push( #ident_str );
+    }
+  };
 
-  Ok( setter_tokens )
+  let unwrap = qt!
+  {
+    let #ident = #ident.unwrap();
+  };
+
+  Ok( ( tokens, unwrap ) )
 }
 
 ///
-/// Generate a setter for the 'field_ident' with the 'setter_name' name.
+/// Generate code calling validators of a field.
+///
+/// If `fallible` then failed validation is registered in `error`, otherwise it panics.
+///
+/// ### Basic use-case. of output
+///
+/// ```compile_fail
+/// if let Err( err ) = port_validate( &port )
+/// {
+///   error.invalid.push( former::runtime::FormInvalid::new( Some( "port" ), &err ) );
+/// }
+/// ```
 ///
 
 #[inline]
-fn field_setter( field_ident: &syn::Ident, non_optional_type: &syn::Type, setter_name: &syn::Ident ) -> proc_macro2::TokenStream
+fn field_validate_map( field : &FormerField< '_ >, krate : &syn::Path, fallible : bool ) -> proc_macro2::TokenStream
 {
-  qt!
+  let ident = field.ident;
+  let ident_str = ident.to_string();
+  let validators = field.attrs.validate.iter().map( | attr_validate |
   {
-    /// Setter for the '#field_ident' field.
-    #[inline]
-    pub fn #setter_name< Src >( mut self, src : Src ) -> Self
-    where Src : ::core::convert::Into< #non_optional_type >,
+    let path = &attr_validate.path;
+    let on_invalid = if fallible
     {
-      debug_assert!( self.#field_ident.is_none() );
-      self.#field_ident = ::core::option::Option::Some( src.into() );
-      self
+      qt!
+      {
+        error.invalid.push( #krate::runtime::FormInvalid::new( ::core::option::Option::Some( #ident_str ), &err ) );
+      }
+    }
+    else
+    {
+      qt!
+      {
+        panic!( "Field '{}' is invalid : {}", #ident_str, err );
+      }
+    };
+    qt!
+    {
+      if let ::core::result::Result::Err( err ) = #path( &#ident )
+      {
+        #on_invalid
+      }
     }
+  });
+
+  qt!
+  {
+    #( #validators )*
   }
 }
 
 ///
-/// Generate documentation for the former.
+/// Is the field required to be set before forming in typestate mode.
+///
+/// Field is required if it is neither optional nor has default value.
 ///
 
-fn doc_generate( name_ident : &syn::Ident ) -> ( String, String )
-{
-
-  let doc_former_mod = format!
-  (
-r#" Implementation of former for [{}].
-"#,
-    name_ident
-  );
-
-  let doc_example1 =
-r#"
-use former::Former;
-#[derive( Former )]
-pub struct Struct1
+#[inline]
+fn field_is_required( field : &FormerField< '_ > ) -> bool
 {
-  #[default( 31 )]
-  field1 : i32,
+  !field.is_optional
+  && field.attrs.default.is_none()
 }
-"#;
-
-  let doc_former_struct = format!
-  (
-r#" Object to form [{}]. If field's values is not set then default value of the field is set.
 
-For specifing custom default value use attribute `default`. For example:
-```
-{}
-```
-"#,
-    name_ident, doc_example1
-  );
+///
+/// Generate name of parameter of the former holding state of the field in typestate mode.
+///
+/// ### Basic use-case. of output
+///
+/// `int_1` -> `__Int1`
+///
 
-  ( doc_former_mod, doc_former_struct )
+#[inline]
+fn field_state_ident( field : &FormerField< '_ > ) -> syn::Ident
+{
+  let name : String = syn::ext::IdentExt::unraw( field.ident ).to_string()
+  .split( '_' )
+  .map( | word |
+  {
+    let mut chars = word.chars();
+    match chars.next()
+    {
+      Some( first ) => first.to_uppercase().chain( chars ).collect(),
+      None => String::new(),
+    }
+  })
+  .collect();
+  format_ident!( "__{}", name, span = field.ident.span() )
 }
 
-//
+///
+/// Generate setters of required field in typestate mode. Setter returns the former with the field marked as set.
+///
+/// Subformer and container setters mark the field as set right away, their `end()` is the only way back to the former and it sets the field.
+///
+/// ### Basic use-case. of output
+///
+/// ```compile_fail
+/// pub fn int_1< Src >( self, src : Src ) -> Struct1Former< Context, End, former::runtime::Set, __String1 >
+/// where Src : Into< i32 >,
+/// {
+///   Struct1Former
+///   {
+///     int_1 : Some( src.into() ),
+///     string_1 : self.string_1,
+///     _context : self._context,
+///     _on_end : self._on_end,
+///     _typestate : PhantomData,
+///   }
+/// }
+/// ```
+///
 
-pub fn former( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+#[inline]
+fn field_setter_typestate_map
+(
+  field : &FormerField< '_ >,
+  former_name_ident : &syn::Ident,
+  former_set : &proc_macro2::TokenStream,
+  fields_names : &[ syn::Ident ],
+  phantom_init : &proc_macro2::TokenStream,
+  krate : &syn::Path,
+)
+-> Result< proc_macro2::TokenStream >
 {
-
-  let ast = match syn::parse::< syn::DeriveInput >( input )
+  let field_ident = field.ident;
+  if let Some( setter_attr ) = &field.attrs.setter
   {
-    Ok( syntax_tree ) => syntax_tree,
-    Err( err ) => return Err( err ),
-  };
-
-  let name_ident = &ast.ident;
-  let generics = &ast.generics;
-  let former_name = format!( "{}Former", name_ident );
-  let former_name_ident = syn::Ident::new( &former_name, name_ident.span() );
-
-  // use heck::ToSnakeCase;
-  // let former_snake = name_ident.to_string().to_snake_case();
-  // let former_mod = format!( "{}_former", former_snake );
-  // let former_mod_ident = syn::Ident::new( &former_mod, name_ident.span() );
-
-  /* structure attribute */
+    if !setter_attr.condition.value()
+    {
+      return Err( syn_err!
+      (
+        field_ident,
+        "Field '{}' is required in typestate mode, so its setter can't be disabled",
+        field_ident
+      ));
+    }
+  }
 
-  let mut perform = qt!
+  let non_optional_type = field.non_optional_ty;
+  let fields_other : Vec< _ > = fields_names.iter().filter( | ident | *ident != field_ident ).collect();
+  let former_marked = qt!
   {
-    return result;
+    #former_name_ident
+    {
+      #field_ident : self.#field_ident,
+      #( #fields_other : self.#fields_other, )*
+      _context : self._context,
+      _on_end : self._on_end,
+      _typestate : ::core::marker::PhantomData,
+      #phantom_init
+    }
+  };
+  let setter = | setter_name : &syn::Ident | -> Result< proc_macro2::TokenStream >
+  {
+    if let Some( attr_container ) = &field.attrs.container
+    {
+      let ( container_former, parameters ) = container_former_of( non_optional_type, attr_container, krate )?;
+      return Ok( qt!
+      {
+        /// Subformer setter for the '#field_ident' field. Call `end()` of the subformer to get back to this former.
+        #[inline]
+        pub fn #setter_name( mut self ) -> #container_former
+        <
+          #( #parameters, )*
+          #non_optional_type,
+          #former_set,
+          impl Fn( &mut #former_set, ::core::option::Option< #non_optional_type > ),
+        >
+        {
+          let container = self.#field_ident.take();
+          let on_end = | former : &mut #former_set, container : ::core::option::Option< #non_optional_type > |
+          {
+            former.#field_ident = ::core::option::Option::Some( container.unwrap_or_default() );
+          };
+          let former : #former_set = #former_marked;
+          #container_former::new( former, container, on_end )
+        }
+      });
+    }
+    if field.attrs.subformer.is_some()
+    {
+      let context = qt!{ #former_set };
+      let ( subformer_type, subformer_path ) = subformer_of( non_optional_type, &context, krate )?;
+      return Ok( qt!
+      {
+        /// Subformer setter for the '#field_ident' field. Call `end()` of the subformer to get back to this former.
+        #[inline]
+        pub fn #setter_name( self ) -> #subformer_type
+        {
+          let on_end = | formed : #non_optional_type, former : ::core::option::Option< #former_set > | -> #former_set
+          {
+            let mut former = former.unwrap();
+            former.#field_ident = ::core::option::Option::Some( formed );
+            former
+          };
+          let former : #former_set = #former_marked;
+          #subformer_path::begin( ::core::option::Option::Some( former ), on_end )
+        }
+      });
+    }
+    Ok( qt!
+    {
+      /// Setter for the '#field_ident' field.
+      #[inline]
+      pub fn #setter_name< Src >( self, src : Src ) -> #former_set
+      where Src : ::core::convert::Into< #non_optional_type >,
+      {
+        #former_name_ident
+        {
+          #field_ident : ::core::option::Option::Some( src.into() ),
+          #( #fields_other : self.#fields_other, )*
+          _context : self._context,
+          _on_end : self._on_end,
+          _typestate : ::core::marker::PhantomData,
+          #phantom_init
+        }
+      }
+    })
+  };
+
+  let setter_tokens = setter( field_ident )?;
+  if let Some( alias_attr ) = &field.attrs.alias
+  {
+    let alias_tokens = setter( &alias_attr.alias )?;
+    return Ok( qt!
+    {
+      #setter_tokens
+
+      #alias_tokens
+    });
+  }
+
+  Ok( setter_tokens )
+}
+
+///
+/// Extract name of a field out.
+///
+
+#[inline]
+fn field_name_map( field : &FormerField< '_ > ) -> syn::Ident
+{
+  field.ident.clone()
+}
+
+///
+/// Generate a former setter for the field.
+///
+/// ### Basic use-case. of output
+///
+/// ```compile_fail
+/// pub fn int_1< Src >( mut self, src : Src ) -> Self
+/// where Src : Into< i32 >,
+/// {
+///   debug_assert!( self.int_1.is_none() );
+///   self.int_1 = Some( src.into() );
+///   self
+/// }
+/// ```
+///
+
+#[inline]
+fn field_setter_map( field : &FormerField< '_ >, krate : &syn::Path ) -> Result< proc_macro2::TokenStream >
+{
+  let ident = &field.ident;
+  if let Some( setter_attr ) = &field.attrs.setter
+  {
+    if !setter_attr.condition.value()
+    {
+      return Ok( qt!{ } );
+    }
+  }
+
+  let non_optional_ty = &field.non_optional_ty;
+  let setter = | setter_name : &syn::Ident |
+  {
+    if let Some( attr_container ) = &field.attrs.container
+    {
+      field_container_setter( ident, non_optional_ty, setter_name, attr_container, krate )
+    }
+    else if field.attrs.subformer.is_some()
+    {
+      field_subformer_setter( ident, non_optional_ty, setter_name, krate )
+    }
+    else
+    {
+      field_setter( ident, non_optional_ty, setter_name )
+    }
+  };
+  let setter_tokens = setter( ident )?;
+  if let Some( alias_attr ) = &field.attrs.alias
+  {
+    let alias_tokens = setter( &alias_attr.alias )?;
+
+    let token = qt!
+    {
+      #setter_tokens
+
+      #alias_tokens
+    };
+    return Ok( token );
+  }
+
+  Ok( setter_tokens )
+}
+
+///
+/// Generate a setter for the 'field_ident' with the 'setter_name' name.
+///
+
+#[inline]
+fn field_setter( field_ident: &syn::Ident, non_optional_type: &syn::Type, setter_name: &syn::Ident ) -> Result< proc_macro2::TokenStream >
+{
+  Ok( qt!
+  {
+    /// Setter for the '#field_ident' field.
+    #[inline]
+    pub fn #setter_name< Src >( mut self, src : Src ) -> Self
+    where Src : ::core::convert::Into< #non_optional_type >,
+    {
+      debug_assert!( self.#field_ident.is_none() );
+      self.#field_ident = ::core::option::Option::Some( src.into() );
+      self
+    }
+  })
+}
+
+///
+/// Generate a setter for the 'field_ident' with the 'setter_name' name, which returns former of the field.
+///
+/// ### Basic use-case. of output
+///
+/// ```compile_fail
+/// pub fn child( self ) -> ChildFormer< Self, impl former::runtime::ToSuperFormer< Child, Self > >
+/// {
+///   let on_end = | formed : Child, former : core::option::Option< Self > | -> Self
+///   {
+///     let mut former = former.unwrap();
+///     former.child = Some( formed );
+///     former
+///   };
+///   ChildFormer::begin( Some( self ), on_end )
+/// }
+/// ```
+///
+
+#[inline]
+fn field_subformer_setter
+(
+  field_ident : &syn::Ident,
+  non_optional_type : &syn::Type,
+  setter_name : &syn::Ident,
+  krate : &syn::Path,
+)
+-> Result< proc_macro2::TokenStream >
+{
+  let ( subformer_type, subformer_path ) = subformer_of( non_optional_type, &qt!{ Self }, krate )?;
+  Ok( qt!
+  {
+    /// Subformer setter for the '#field_ident' field. Call `end()` of the subformer to get back to this former.
+    #[inline]
+    pub fn #setter_name( self ) -> #subformer_type
+    {
+      let on_end = | formed : #non_optional_type, former : ::core::option::Option< Self > | -> Self
+      {
+        let mut former = former.unwrap();
+        former.#field_ident = ::core::option::Option::Some( formed );
+        former
+      };
+      #subformer_path::begin( ::core::option::Option::Some( self ), on_end )
+    }
+  })
+}
+
+///
+/// Generate a setter for the 'field_ident' with the 'setter_name' name, which returns former of the container.
+///
+/// ### Basic use-case. of output
+///
+/// ```compile_fail
+/// pub fn vec_1( mut self ) -> former::runtime::VectorFormer
+/// <
+///   String,
+///   Vec< String >,
+///   Self,
+///   impl Fn( &mut Self, core::option::Option< Vec< String > > ),
+/// >
+/// {
+///   let container = self.vec_1.take();
+///   let on_end = | former : &mut Self, container : core::option::Option< Vec< String > > |
+///   {
+///     former.vec_1 = container;
+///   };
+///   former::runtime::VectorFormer::new( self, container, on_end )
+/// }
+/// ```
+///
+
+#[inline]
+fn field_container_setter
+(
+  field_ident : &syn::Ident,
+  non_optional_type : &syn::Type,
+  setter_name : &syn::Ident,
+  attr_container : &AttributeContainer,
+  krate : &syn::Path,
+)
+-> Result< proc_macro2::TokenStream >
+{
+  let ( container_former, parameters ) = container_former_of( non_optional_type, attr_container, krate )?;
+  Ok( qt!
+  {
+    /// Subformer setter for the '#field_ident' field. Call `end()` of the subformer to get back to this former.
+    #[inline]
+    pub fn #setter_name( mut self ) -> #container_former
+    <
+      #( #parameters, )*
+      #non_optional_type,
+      Self,
+      impl Fn( &mut Self, ::core::option::Option< #non_optional_type > ),
+    >
+    {
+      let container = self.#field_ident.take();
+      let on_end = | former : &mut Self, container : ::core::option::Option< #non_optional_type > |
+      {
+        former.#field_ident = container;
+      };
+      #container_former::new( self, container, on_end )
+    }
+  })
+}
+
+///
+/// Make path to former of a container and types of its elements.
+///
+/// ### Basic use-case. of output
+///
+/// `Vec< String >` -> ( `former::runtime::VectorFormer`, [ `String` ] )
+///
+
+fn container_former_of< 'a >
+(
+  non_optional_type : &'a syn::Type,
+  attr_container : &'a AttributeContainer,
+  krate : &syn::Path,
+)
+-> Result< ( proc_macro2::TokenStream, Vec< &'a syn::Type > ) >
+{
+  let ( kind, adapter_parameters ) = match &attr_container.adapter
+  {
+    Some( adapter ) =>
+    {
+      let last = adapter.segments.last()
+      .ok_or_else( || syn_err!( adapter, "Expects adapter of container, but got:\n  {}", qt!{ #adapter } ) )?;
+      let kind = match last.ident.to_string().as_ref()
+      {
+        "VectorLike" => macro_tools::ContainerKind::Vector,
+        "HashMapLike" => macro_tools::ContainerKind::HashMap,
+        "HashSetLike" => macro_tools::ContainerKind::HashSet,
+        _ => return Err( syn_err!
+        (
+          adapter,
+          "Expects one of adapters `VectorLike`, `HashMapLike`, `HashSetLike`, but got:\n  {}",
+          qt!{ #adapter }
+        )),
+      };
+      let parameters : Vec< &syn::Type > = match &last.arguments
+      {
+        syn::PathArguments::AngleBracketed( arguments ) => arguments.args.iter().filter_map( | arg |
+        {
+          if let syn::GenericArgument::Type( ty ) = arg { Some( ty ) } else { None }
+        }).collect(),
+        _ => Vec::new(),
+      };
+      ( kind, parameters )
+    },
+    None => ( macro_tools::type_container_kind( non_optional_type ), Vec::new() ),
+  };
+
+  let ( container_former, parameters_number ) = match kind
+  {
+    macro_tools::ContainerKind::Vector => ( qt!{ #krate::runtime::VectorFormer }, 1 ),
+    macro_tools::ContainerKind::HashMap => ( qt!{ #krate::runtime::HashMapFormer }, 2 ),
+    macro_tools::ContainerKind::HashSet => ( qt!{ #krate::runtime::HashSetFormer }, 1 ),
+    macro_tools::ContainerKind::No => return Err( syn_err!
+    (
+      non_optional_type,
+      "Can't deduce kind of container, specify adapter, for example `#[ container( adapter = former::runtime::VectorLike ) ]`, for type:\n  {}",
+      qt!{ #non_optional_type }
+    )),
+  };
+
+  let parameters = if adapter_parameters.is_empty()
+  {
+    macro_tools::type_parameters( non_optional_type, 0 ..= parameters_number - 1 )
+  }
+  else
+  {
+    adapter_parameters
+  };
+  if parameters.len() as isize != parameters_number
+  {
+    return Err( syn_err!
+    (
+      non_optional_type,
+      "Expects {} parameter(s) of elements of container, specify them in adapter, for example `#[ container( adapter = former::runtime::VectorLike< u8 > ) ]`, for type:\n  {}",
+      parameters_number,
+      qt!{ #non_optional_type }
+    ));
+  }
+
+  Ok( ( container_former, parameters ) )
+}
+
+///
+/// Make type and path of former of a type which derives former. Type of the former is parametrized by `context`, the former of the field.
+///
+/// ### Basic use-case. of output
+///
+/// `module::Child< 'a >` -> ( `module::ChildFormer< 'a, Self, impl former::runtime::ToSuperFormer< module::Child< 'a >, Self > >`, `module::ChildFormer` )
+///
+
+fn subformer_of
+(
+  ty : &syn::Type,
+  context : &proc_macro2::TokenStream,
+  krate : &syn::Path,
+)
+-> Result< ( proc_macro2::TokenStream, proc_macro2::TokenStream ) >
+{
+  let error = || syn_err!( ty, "Expects path to a type which derives Former, but got:\n  {}", qt!{ #ty } );
+  let path = match ty
+  {
+    syn::Type::Path( type_path ) if type_path.qself.is_none() => &type_path.path,
+    _ => return Err( error() ),
   };
-  let mut perform_output = qt!{ #name_ident #generics };
-  let mut perform_generics = qt!{};
-  for attr in ast.attrs.iter()
-  {
-    if let Some( ident ) = attr.path.get_ident()
-    {
-      let ident_string = format!( "{}", ident );
-      if ident_string == "perform"
-      {
-        let attr_perform = syn::parse2::< AttributeFormAfter >( attr.tokens.clone() )?;
-        let signature = &attr_perform.signature;
-        let generics = &signature.generics;
-        perform_generics = qt!{ #generics };
-        let perform_ident = &signature.ident;
-        let output = &signature.output;
-        if let syn::ReturnType::Type( _, boxed_type ) = output
+
+  let mut subformer_type = path.clone();
+  let last = subformer_type.segments.last_mut().ok_or_else( error )?;
+  last.ident = syn::Ident::new( &format!( "{}Former", last.ident ), last.ident.span() );
+  let mut args = match &last.arguments
+  {
+    syn::PathArguments::AngleBracketed( arguments ) => arguments.args.clone(),
+    syn::PathArguments::None => syn::punctuated::Punctuated::new(),
+    syn::PathArguments::Parenthesized( _ ) => return Err( error() ),
+  };
+  args.push( parse_qt!{ #context } );
+  args.push( parse_qt!{ impl #krate::runtime::ToSuperFormer< #ty, #context > } );
+  last.arguments = syn::PathArguments::AngleBracketed( parse_qt!{ < #args > } );
+
+  let mut subformer_path = subformer_type.clone();
+  for segment in subformer_path.segments.iter_mut()
+  {
+    segment.arguments = syn::PathArguments::None;
+  }
+
+  Ok( ( qt!{ #subformer_type }, qt!{ #subformer_path } ) )
+}
+
+///
+/// Generate documentation for the former.
+///
+
+fn doc_generate( name : &str ) -> ( String, String )
+{
+
+  let doc_former_mod = format!
+  (
+r#" Implementation of former for [{}].
+"#,
+    name
+  );
+
+  let doc_example1 =
+r#"
+use former::Former;
+#[derive( Former )]
+pub struct Struct1
+{
+  #[default( 31 )]
+  field1 : i32,
+}
+"#;
+
+  let doc_former_struct = format!
+  (
+r#" Object to form [{}]. If field's values is not set then default value of the field is set.
+
+For specifing custom default value use attribute `default`. For example:
+```
+{}
+```
+"#,
+    name, doc_example1
+  );
+
+  ( doc_former_mod, doc_former_struct )
+}
+
+///
+/// Information about method to call after form, extracted from attribute `perform` of the structure.
+///
+
+struct FormPerform
+{
+  perform : proc_macro2::TokenStream,
+  output : proc_macro2::TokenStream,
+  generics : proc_macro2::TokenStream,
+}
+
+impl FormPerform
+{
+  fn parse( attributes : &[ syn::Attribute ], output : proc_macro2::TokenStream ) -> Result< Self >
+  {
+    let mut perform = qt!
+    {
+      return result;
+    };
+    let mut output = output;
+    let mut generics = qt!{};
+    for attr in attributes.iter()
+    {
+      if let Some( ident ) = attr.path.get_ident()
+      {
+        let ident_string = format!( "{}", ident );
+        if ident_string == "perform"
         {
-          perform_output = qt!{ #boxed_type };
+          let attr_perform = syn::parse2::< AttributeFormAfter >( attr.tokens.clone() )?;
+          let signature = &attr_perform.signature;
+          let signature_generics = &signature.generics;
+          generics = qt!{ #signature_generics };
+          let perform_ident = &signature.ident;
+          if let syn::ReturnType::Type( _, boxed_type ) = &signature.output
+          {
+            output = qt!{ #boxed_type };
+          }
+          perform = qt!
+          {
+            return result.#perform_ident();
+          };
+        }
+      }
+      else
+      {
+        return Err( syn_err!( "Unknown structure attribute:\n{}", qt!{ attr } ) );
+      }
+    }
+    Ok( Self { perform, output, generics } )
+  }
+}
+
+///
+/// Attributes of the structure.
+///
+
+struct StructAttributes
+{
+  perform : FormPerform,
+  validators : Vec< AttributeValidate >,
+  typestate : bool,
+  deserialize : bool,
+  serde : Vec< syn::Attribute >,
+  /// Path to crate `former` used by generated code, `::former` by default.
+  krate : syn::Path,
+}
+
+impl StructAttributes
+{
+  fn parse( attributes : &[ syn::Attribute ], output : proc_macro2::TokenStream ) -> Result< Self >
+  {
+    let perform = FormPerform::parse( attributes, output )?;
+    let mut validators = Vec::new();
+    let mut typestate = false;
+    let mut deserialize = false;
+    let mut serde = Vec::new();
+    let mut krate = None;
+    let mut former = None;
+    for attr in attributes.iter()
+    {
+      if attr.path.is_ident( "validate" )
+      {
+        validators.push( syn::parse2::< AttributeValidate >( attr.tokens.clone() )? );
+      }
+      else if attr.path.is_ident( "former" )
+      {
+        let attr_former = syn::parse2::< AttributeFormer >( attr.tokens.clone() )?;
+        typestate = typestate || attr_former.typestate;
+        deserialize = deserialize || attr_former.deserialize;
+        if attr_former.krate.is_some()
+        {
+          if krate.is_some()
+          {
+            return Err( syn_err!( attr, "Option `crate` of attribute `former` is set several times" ) );
+          }
+          krate = attr_former.krate;
+        }
+        former = Some( attr );
+      }
+      else if attr.path.is_ident( "serde" )
+      {
+        serde.push( attr.clone() );
+      }
+    }
+    if let Some( attr ) = former
+    {
+      if typestate && deserialize
+      {
+        return Err( syn_err!( attr, "Options `typestate` and `deserialize` of attribute `former` can't be used together" ) );
+      }
+    }
+    let krate = krate.unwrap_or_else( || crate_path_default( "former" ) );
+    Ok( Self { perform, validators, typestate, deserialize, serde, krate } )
+  }
+}
+
+///
+/// Make descriptors of fields of a structure or of a variant of an enum.
+///
+
+fn former_fields_parse( fields : &syn::punctuated::Punctuated< syn::Field, syn::token::Comma > ) -> Result< Vec< FormerField< '_ > > >
+{
+  let former_fields : Vec< Result< FormerField< '_ > > > = fields.iter().map( | field |
+  {
+    let attrs = Attributes::parse( &field.attrs )?;
+    let vis = &field.vis;
+    let ident = field.ident.as_ref()
+    .ok_or_else( || syn_err!( field, "Expected that each field has key, but some does not:\n  {}", qt!{ #field } ) )?;
+    let colon_token = &field.colon_token;
+    let ty = &field.ty;
+    let is_optional = is_optional( ty );
+    let type_container_kind = macro_tools::type_container_kind( ty );
+    let non_optional_ty : &syn::Type = if is_optional { parameter_internal_first( ty )? } else { ty };
+    let former_field = FormerField { attrs, vis, ident, colon_token, ty, non_optional_ty, is_optional, type_container_kind };
+    Ok( former_field )
+  }).collect();
+
+  process_results( former_fields, | iter | iter.collect() )
+}
+
+///
+/// Generate type of marker carrying parameters of the formed entity, if it has any.
+///
+/// Fields of a variant of an enum may not use every parameter of the enum, but the former forms the enum, so it has all of them.
+///
+
+fn generics_phantom( generics : &syn::Generics ) -> Option< proc_macro2::TokenStream >
+{
+  let params : Vec< _ > = generics.params.iter().filter_map( | param | match param
+  {
+    syn::GenericParam::Lifetime( param ) => { let lifetime = &param.lifetime; Some( qt!{ &#lifetime () } ) },
+    syn::GenericParam::Type( param ) => { let ident = &param.ident; Some( qt!{ #ident } ) },
+    syn::GenericParam::Const( _ ) => None,
+  }).collect();
+  if params.is_empty()
+  {
+    None
+  }
+  else
+  {
+    Some( qt!{ ::core::marker::PhantomData< fn() -> ( #( #params, )* ) > } )
+  }
+}
+
+///
+/// Generate name of constructor of a variant, which is name of the variant in snake case.
+///
+/// ### Basic use-case. of output
+///
+/// `Circle` -> `circle`, `RoundedRect` -> `rounded_rect`, `Type` -> `r#type`, `Crate` -> `crate_`.
+///
+/// Keywords which could not be raw identifiers, like `crate` or `super`, get suffix `_`.
+///
+
+fn variant_constructor_ident( variant_ident : &syn::Ident ) -> Result< syn::Ident >
+{
+  let name = macro_tools::to_snake_case( &syn::ext::IdentExt::unraw( variant_ident ).to_string() );
+  let span = variant_ident.span();
+
+  if syn::parse_str::< syn::Ident >( &name ).is_ok()
+  {
+    Ok( syn::Ident::new( &name, span ) )
+  }
+  else if [ "crate", "self", "super", "Self", "_" ].contains( &name.as_str() )
+  {
+    Ok( syn::Ident::new( &format!( "{}_", name ), span ) )
+  }
+  else if syn::parse_str::< syn::Ident >( &format!( "{}_", name ) ).is_ok()
+  {
+    // other keywords are valid raw identifiers
+    Ok( syn::Ident::new_raw( &name, span ) )
+  }
+  else
+  {
+    Err( syn_err!( variant_ident, "Name of constructor `{}` of variant `{}` is not an identifier", name, variant_ident ) )
+  }
+}
+
+///
+/// Generate implementation of `serde::Deserialize` for the former.
+///
+/// Every field of the former is optional, so the former could be deserialized from partial input.
+/// Deserialization goes through a hidden structure which has the same fields as the former and forwards `serde` attributes.
+///
+
+fn former_deserialize_generate
+(
+  former_name_ident : &syn::Ident,
+  name_ident : &syn::Ident,
+  generics : &syn::Generics,
+  attrs : &StructAttributes,
+  former_fields : &[ FormerField< '_ > ],
+)
+-> proc_macro2::TokenStream
+{
+  let partial_name_ident = syn::Ident::new( &format!( "{}Partial", former_name_ident ), former_name_ident.span() );
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+
+  let fields_names : Vec< _ > = former_fields.iter().map( | field | field.ident ).collect();
+  let fields_types : Vec< _ > = former_fields.iter().map( | field |
+  {
+    let ty = field.ty;
+    if field.is_optional
+    {
+      qt!{ #ty }
+    }
+    else
+    {
+      qt!{ ::core::option::Option< #ty > }
+    }
+  }).collect();
+  let fields_serde : Vec< _ > = former_fields.iter().map( | field |
+  {
+    let serde = &field.attrs.serde;
+    qt!{ #( #serde )* }
+  }).collect();
+  let struct_serde = &attrs.serde;
+  let krate = &attrs.krate;
+  let serde = qt!{ #krate::dependency::serde };
+  let serde_crate = syn::LitStr::new( &serde.to_string(), proc_macro2::Span::call_site() );
+
+  let former_args : Vec< proc_macro2::TokenStream > = generics.params.iter().map( | param | match param
+  {
+    syn::GenericParam::Lifetime( param ) => { let lifetime = &param.lifetime; qt!{ #lifetime } },
+    syn::GenericParam::Type( param ) => { let ident = &param.ident; qt!{ #ident } },
+    syn::GenericParam::Const( param ) => { let ident = &param.ident; qt!{ #ident } },
+  }).collect();
+
+  let ( phantom_field, phantom_init ) = match generics_phantom( generics )
+  {
+    Some( phantom ) => ( qt!{ #[ serde( skip ) ] _phantom : #phantom, }, qt!{ _phantom : ::core::marker::PhantomData, } ),
+    None => ( qt!{}, qt!{} ),
+  };
+
+  let mut de_generics = generics.clone();
+  de_generics.params.insert( 0, parse_qt!{ 'de } );
+  de_generics.make_where_clause().predicates.push
+  (
+    parse_qt!{ #partial_name_ident #ty_generics : #serde::Deserialize< 'de > }
+  );
+  let ( de_impl_generics, _, de_where_clause ) = de_generics.split_for_impl();
+
+  // Feature `serde` is a feature of crate `former`, so the check is deferred to its macro.
+  qt!
+  {
+    #krate::__if_serde!
+    {
+      const _ : () =
+      {
+
+        #[ derive( #serde::Deserialize ) ]
+        #[ serde( crate = #serde_crate ) ]
+        #( #struct_serde )*
+        struct #partial_name_ident #generics #where_clause
+        {
+          #(
+            #fields_serde
+            #fields_names : #fields_types,
+          )*
+          #phantom_field
+        }
+
+        impl #impl_generics ::core::default::Default for #partial_name_ident #ty_generics #where_clause
+        {
+          fn default() -> Self
+          {
+            Self
+            {
+              #( #fields_names : ::core::option::Option::None, )*
+              #phantom_init
+            }
+          }
+        }
+
+        impl #de_impl_generics #serde::Deserialize< 'de >
+        for #former_name_ident< #( #former_args, )* #name_ident #ty_generics, #krate::runtime::ReturnFormed >
+        #de_where_clause
+        {
+          fn deserialize< D >( deserializer : D ) -> ::core::result::Result< Self, D::Error >
+          where
+            D : #serde::Deserializer< 'de >,
+          {
+            let partial = < #partial_name_ident #ty_generics as #serde::Deserialize< 'de > >::deserialize( deserializer )?;
+            ::core::result::Result::Ok( Self
+            {
+              #( #fields_names : partial.#fields_names, )*
+              _context : ::core::option::Option::None,
+              _on_end : ::core::option::Option::Some( #krate::runtime::ReturnFormed ),
+              #phantom_init
+            })
+          }
         }
-        perform = qt!
-        {
-          return result.#perform_ident();
-        };
-      }
-    }
-    else
-    {
-      return Err( syn_err!( "Unknown structure attribute:\n{}", qt!{ attr } ) );
+
+      };
     }
   }
+}
 
-  /* */
-
-  let fields = match ast.data
-  {
-    syn::Data::Struct( ref data_struct ) => match data_struct.fields
-    {
-      syn::Fields::Named( ref fields_named ) =>
-      {
-        &fields_named.named
-      },
-      _ => return Err( syn_err!( ast, "Unknown format of data, expected syn::Fields::Named( ref fields_named )\n  {}", qt!{ #ast } ) ),
-    },
-    _ => return Err( syn_err!( ast, "Unknown format of data, expected syn::Data::Struct( ref data_struct )\n  {}", qt!{ #ast } ) ),
-  };
-
-  let former_fields : Vec< Result< FormerField< '_ > > > = fields.iter().map( | field |
-  {
-    let attrs = Attributes::parse( &field.attrs )?;
-    let vis = &field.vis;
-    let ident = field.ident.as_ref()
-    .ok_or_else( || syn_err!( field, "Expected that each field has key, but some does not:\n  {}", qt!{ #field } ) )?;
-    let colon_token = &field.colon_token;
-    let ty = &field.ty;
-    let is_optional = is_optional( ty );
-    let type_container_kind = macro_tools::type_container_kind( ty );
-    let non_optional_ty : &syn::Type = if is_optional { parameter_internal_first( ty )? } else { ty };
-    let former_field = FormerField { attrs, vis, ident, colon_token, ty, non_optional_ty, is_optional, type_container_kind };
-    Ok( former_field )
-  }).collect();
+///
+/// Generate former for fields of a structure or of a variant of an enum.
+///
+/// Former is parametrized by `Context` and `End` in addition to parameters of the formed entity.
+/// Method `end` passes formed entity and the context to `End`, what makes possible to use a former as subformer of another former.
+/// By default the context is the formed entity itself and `end` just returns formed entity.
+///
+/// Returns expression to make a former with no field set and definition of the former.
+/// Parameter `target` is path to constructor of the formed entity, for example `Struct1` or `Enum1::Variant1`.
+///
 
-  let former_fields : Vec< _ > = process_results( former_fields, | iter | iter.collect() )?;
+fn former_generate
+(
+  former_name_ident : &syn::Ident,
+  target : &proc_macro2::TokenStream,
+  name_ident : &syn::Ident,
+  generics : &syn::Generics,
+  attrs : &StructAttributes,
+  former_fields : &[ FormerField< '_ > ],
+  entity_name : &str,
+  doc_former_struct : &str,
+)
+-> Result< ( proc_macro2::TokenStream, proc_macro2::TokenStream ) >
+{
+  let krate = &attrs.krate;
 
   let ( fields_none, fields_optional, fields_form, fields_names, fields_setter )
   : ( Vec< _ >, Vec< _ >, Vec< _ >, Vec< _ >, Vec< _ > )
@@ -601,88 +1599,530 @@ pub fn former( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenSt
     field_optional_map( former_field ),
     field_form_map( former_field ),
     field_name_map( former_field ),
-    field_setter_map( former_field ),
+    field_setter_map( former_field, krate ),
   )}).multiunzip();
 
-  let ( _doc_former_mod, doc_former_struct ) = doc_generate( name_ident );
   let fields_setter : Vec< _ > = process_results( fields_setter, | iter | iter.collect() )?;
   let fields_form : Vec< _ > = process_results( fields_form, | iter | iter.collect() )?;
 
-  let result = qt!
+  let fields_try_form : Vec< _ > = process_results( former_fields.iter().map( field_try_form_map ), | iter | iter.collect() )?;
+  let ( fields_try_form, fields_unwrap ) : ( Vec< _ >, Vec< _ > ) = fields_try_form.into_iter().unzip();
+  let fields_validate : Vec< _ > = former_fields.iter().map( | field | field_validate_map( field, krate, false ) ).collect();
+  let fields_try_validate : Vec< _ > = former_fields.iter().map( | field | field_validate_map( field, krate, true ) ).collect();
+  let validators : Vec< _ > = attrs.validators.iter().map( | attr_validate | &attr_validate.path ).collect();
+  let typestate = attrs.typestate;
+
+  let FormPerform { perform, output : perform_output, generics : perform_generics } = &attrs.perform;
+
+  /* typestate */
+
+  let required : Vec< bool > = former_fields.iter().map( | field | typestate && field_is_required( field ) ).collect();
+  let states : Vec< syn::Ident > = former_fields.iter().zip( required.iter() )
+  .filter( | ( _, required ) | **required )
+  .map( | ( field, _ ) | field_state_ident( field ) )
+  .collect();
+
+  let ( _, ty_generics, where_clause ) = generics.split_for_impl();
+  let mut former_generics = generics.clone();
+  former_generics.params.push( parse_qt!{ Context = #name_ident #ty_generics } );
+  former_generics.params.push( parse_qt!{ End = #krate::runtime::ReturnFormed } );
+  for state in states.iter()
   {
+    former_generics.params.push( parse_qt!{ #state = #krate::runtime::Unset } );
+  }
 
-    impl #generics #name_ident #generics
+  // Make generics of implementation and arguments of the former. State which is `None` is left generic.
+  let generics_args : Vec< proc_macro2::TokenStream > = generics.params.iter().map( | param | match param
+  {
+    syn::GenericParam::Lifetime( param ) => { let lifetime = &param.lifetime; qt!{ #lifetime } },
+    syn::GenericParam::Type( param ) => { let ident = &param.ident; qt!{ #ident } },
+    syn::GenericParam::Const( param ) => { let ident = &param.ident; qt!{ #ident } },
+  }).collect();
+  let former_impl = | states_fixed : &[ Option< proc_macro2::TokenStream > ] |
+  {
+    let mut impl_generics = generics.clone();
+    impl_generics.params.push( parse_qt!{ Context } );
+    impl_generics.params.push( parse_qt!{ End } );
+    let mut args = generics_args.clone();
+    args.push( qt!{ Context } );
+    args.push( qt!{ End } );
+    for ( state, fixed ) in states.iter().zip( states_fixed.iter() )
     {
-      ///
-      /// Make former, variation of builder pattern to form structure defining values of fields step by step.
-      ///
-      #[inline]
-      pub fn former() -> #former_name_ident #generics
+      match fixed
       {
-        #former_name_ident
+        Some( fixed ) => args.push( fixed.clone() ),
+        None =>
         {
-          #( #fields_none, )*
-        }
+          impl_generics.params.push( parse_qt!{ #state } );
+          args.push( qt!{ #state } );
+        },
       }
     }
+    impl_generics.make_where_clause().predicates.push
+    (
+      parse_qt!{ End : #krate::runtime::ToSuperFormer< #name_ident #ty_generics, Context > }
+    );
+    let ( impl_generics, _, where_clause ) = impl_generics.split_for_impl();
+    qt!{ impl #impl_generics #former_name_ident< #( #args ),* > #where_clause }
+  };
+
+  let typestate_field = if typestate
+  {
+    qt!{ _typestate : ::core::marker::PhantomData< ( #( #states, )* ) >, }
+  }
+  else
+  {
+    qt!{}
+  };
+  let typestate_init = if typestate
+  {
+    qt!{ _typestate : ::core::marker::PhantomData, }
+  }
+  else
+  {
+    qt!{}
+  };
+  let ( phantom_field, phantom_init ) = match generics_phantom( generics )
+  {
+    Some( phantom ) => ( qt!{ _phantom : #phantom, }, qt!{ _phantom : ::core::marker::PhantomData, } ),
+    None => ( qt!{}, qt!{} ),
+  };
 
-    // #[doc = #doc_former_mod]
-    // mod #former_mod_ident
-    // {
-      // use;
-      // use super::*;
-      // use super::#name_ident;
-      // #[cfg( feature = "in_wtools" )]
-      // use wtools::former;
+  /* methods */
+
+  let method_begin = qt!
+  {
+    ///
+    /// Begin the process of forming. Expects context of forming and handler to call on `end`.
+    ///
+    #[inline]
+    pub fn begin
+    (
+      context : ::core::option::Option< Context >,
+      on_end : End,
+    ) -> Self
+    {
+      Self
+      {
+        #( #fields_none, )*
+        _context : context,
+        _on_end : ::core::option::Option::Some( on_end ),
+        #typestate_init
+        #phantom_init
+      }
+    }
+  };
 
-      #[doc = #doc_former_struct]
-      pub struct #former_name_ident #generics
+  // Error of forming needs allocation, so without `std` and `alloc` there is no `try_form`.
+  // Features are those of crate `former`, so the decision is deferred to its macro.
+  let method_try_form = qt!
+  {
+    #krate::__if_alloc!
+    {
+      ///
+      /// Finish setting options and return formed entity or error listing every field which is not initialized and every failed validation.
+      ///
+      /// Unlike `form` it does not panic.
+      ///
+      #[inline]
+      pub fn try_form( mut self ) -> ::core::result::Result< #name_ident #ty_generics, #krate::runtime::FormError >
       {
+        #[ allow( unused_mut ) ]
+        let mut error = #krate::runtime::FormError::new( #entity_name );
+        #( #fields_try_form )*
+        if !error.is_empty()
+        {
+          return ::core::result::Result::Err( error );
+        }
+        #( #fields_unwrap )*
+        #( #fields_try_validate )*
+        if !error.is_empty()
+        {
+          return ::core::result::Result::Err( error );
+        }
+        let result = #target
+        {
+          #( #fields_names, )*
+        };
         #(
-          /// A field
-          #fields_optional,
+          if let ::core::result::Result::Err( err ) = #validators( &result )
+          {
+            error.invalid.push( #krate::runtime::FormInvalid::new( ::core::option::Option::None, &err ) );
+          }
         )*
+        if !error.is_empty()
+        {
+          return ::core::result::Result::Err( error );
+        }
+        ::core::result::Result::Ok( result )
       }
+    }
+  };
 
-      impl #generics #former_name_ident #generics
+  let methods_form = qt!
+  {
+    ///
+    /// Finish setting options and call perform on formed entity.
+    ///
+    /// If `perform` defined then associated method is called and its result returned instead of entity.
+    /// For example `perform()` of structure with : `#[ perform( fn after1< 'a >() -> Option< &'a str > )` returns `Option< &'a str >`.
+    ///
+    #[inline]
+    pub fn perform #perform_generics ( self ) -> #perform_output
+    {
+      let result = self.form();
+      #perform
+    }
+
+    ///
+    /// Finish setting options and return formed entity.
+    ///
+    /// `perform` has no effect on method `form`, but change behavior and returned type of mehod `perform`.
+    ///
+    #[inline]
+    pub fn form( mut self ) -> #name_ident #ty_generics
+    {
+      #( #fields_form )*
+      #( #fields_validate )*
+      let result = #target
       {
-        ///
-        /// Finish setting options and call perform on formed entity.
-        ///
-        /// If `perform` defined then associated method is called and its result returned instead of entity.
-        /// For example `perform()` of structure with : `#[ perform( fn after1< 'a >() -> Option< &'a str > )` returns `Option< &'a str >`.
-        ///
-        #[inline]
-        pub fn perform #perform_generics ( self ) -> #perform_output
+        #( #fields_names, )*
+      };
+      #(
+        if let ::core::result::Result::Err( err ) = #validators( &result )
         {
-          let result = self.form();
-          #perform
+          panic!( "{} is invalid : {}", #entity_name, err );
         }
+      )*
+      return result;
+    }
 
-        ///
-        /// Finish setting options and return formed entity.
-        ///
-        /// `perform` has no effect on method `form`, but change behavior and returned type of mehod `perform`.
-        ///
-        #[inline]
-        pub fn form( mut self ) -> #name_ident #generics
+    #method_try_form
+
+    ///
+    /// End the process of forming returning context of forming, which is formed entity by default.
+    ///
+    #[inline]
+    pub fn end( mut self ) -> Context
+    {
+      let on_end = self._on_end.take().unwrap();
+      let context = self._context.take();
+      let formed = self.form();
+      on_end.call( formed, context )
+    }
+  };
+
+  let method_merge = qt!
+  {
+    ///
+    /// Merge another former into this one. Fields set in `other` override fields set in `self`.
+    ///
+    /// Context and handler of `end` of `self` are kept.
+    ///
+    #[inline]
+    #[ allow( unused_mut ) ]
+    pub fn merge( mut self, other : Self ) -> Self
+    {
+      #(
+        if other.#fields_names.is_some()
         {
-          #( #fields_form )*
-          let result = #name_ident
-          {
-            #( #fields_names, )*
-          };
-          return result;
+          self.#fields_names = other.#fields_names;
+        }
+      )*
+      self
+    }
+  };
+
+  let former_impls = if typestate
+  {
+    let fields_setter_optional : Vec< _ > = fields_setter.iter().zip( required.iter() )
+    .filter( | ( _, required ) | !**required )
+    .map( | ( setter, _ ) | setter )
+    .collect();
+
+    let mut impls_required = Vec::with_capacity( states.len() );
+    for ( field, _ ) in former_fields.iter().zip( required.iter() ).filter( | ( _, required ) | **required )
+    {
+      let index = impls_required.len();
+      let states_unset : Vec< _ > = ( 0 .. states.len() ).map( | i | if i == index { Some( qt!{ #krate::runtime::Unset } ) } else { None } ).collect();
+      let states_set : Vec< _ > = ( 0 .. states.len() ).map( | i | if i == index { Some( qt!{ #krate::runtime::Set } ) } else { Some( { let state = &states[ i ]; qt!{ #state } } ) } ).collect();
+      let mut args = generics_args.clone();
+      args.push( qt!{ Context } );
+      args.push( qt!{ End } );
+      args.extend( states_set.into_iter().map( Option::unwrap ) );
+      let former_set = qt!{ #former_name_ident< #( #args ),* > };
+      let setters = field_setter_typestate_map( field, former_name_ident, &former_set, &fields_names, &phantom_init, krate )?;
+      let header = former_impl( &states_unset );
+      impls_required.push( qt!
+      {
+        #header
+        {
+          #setters
         }
+      });
+    }
+
+    let header_all = former_impl( &vec![ None; states.len() ] );
+    let header_unset = former_impl( &vec![ Some( qt!{ #krate::runtime::Unset } ); states.len() ] );
+    let header_set = former_impl( &vec![ Some( qt!{ #krate::runtime::Set } ); states.len() ] );
+
+    qt!
+    {
+
+      #header_unset
+      {
+        #method_begin
+      }
+
+      #header_set
+      {
+        #methods_form
+      }
+
+      #header_all
+      {
+        #method_merge
+
+        #(
+          #fields_setter_optional
+        )*
+      }
+
+      #( #impls_required )*
+
+    }
+  }
+  else
+  {
+    let header = former_impl( &[] );
+    qt!
+    {
+      #header
+      {
+        #methods_form
+
+        #method_begin
+
+        #method_merge
 
         #(
           #fields_setter
         )*
+      }
+    }
+  };
+
+  let former_deserialize = if attrs.deserialize
+  {
+    former_deserialize_generate( former_name_ident, name_ident, generics, attrs, former_fields )
+  }
+  else
+  {
+    qt!{}
+  };
+
+  let former_make = qt!
+  {
+    #former_name_ident::begin( ::core::option::Option::None, #krate::runtime::ReturnFormed )
+  };
+
+  let former_definition = qt!
+  {
+
+    #[doc = #doc_former_struct]
+    pub struct #former_name_ident #former_generics #where_clause
+    {
+      #(
+        /// A field
+        #fields_optional,
+      )*
+      _context : ::core::option::Option< Context >,
+      _on_end : ::core::option::Option< End >,
+      #typestate_field
+      #phantom_field
+    }
+
+    #former_impls
+
+    #former_deserialize
+
+  };
+
+  Ok( ( former_make, former_definition ) )
+}
+
+//
+
+pub fn former( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+{
+
+  let ast = match syn::parse::< syn::DeriveInput >( input )
+  {
+    Ok( syntax_tree ) => syntax_tree,
+    Err( err ) => return Err( err ),
+  };
+
+  let name_ident = &ast.ident;
+  let generics = &ast.generics;
+
+  /* structure attribute */
+
+  let ( _, ty_generics, _ ) = generics.split_for_impl();
+  let attrs = StructAttributes::parse( &ast.attrs, qt!{ #name_ident #ty_generics } )?;
+
+  /* */
+
+  match ast.data
+  {
+    syn::Data::Struct( ref data_struct ) => match data_struct.fields
+    {
+      syn::Fields::Named( ref fields_named ) =>
+      {
+        former_for_struct( name_ident, generics, &attrs, &fields_named.named )
+      },
+      _ => Err( syn_err!( ast, "Unknown format of data, expected syn::Fields::Named( ref fields_named )\n  {}", qt!{ #ast } ) ),
+    },
+    syn::Data::Enum( ref data_enum ) =>
+    {
+      former_for_enum( name_ident, generics, &attrs, data_enum )
+    },
+    _ => Err( syn_err!( ast, "Unknown format of data, expected syn::Data::Struct( ref data_struct ) or syn::Data::Enum( ref data_enum )\n  {}", qt!{ #ast } ) ),
+  }
+}
+
+///
+/// Generate former for a structure with named fields.
+///
+
+fn former_for_struct
+(
+  name_ident : &syn::Ident,
+  generics : &syn::Generics,
+  attrs : &StructAttributes,
+  fields : &syn::punctuated::Punctuated< syn::Field, syn::token::Comma >,
+)
+-> Result< proc_macro2::TokenStream >
+{
+  let former_name = format!( "{}Former", name_ident );
+  let former_name_ident = syn::Ident::new( &former_name, name_ident.span() );
+
+  let former_fields = former_fields_parse( fields )?;
+  let entity_name = name_ident.to_string();
+  let ( _doc_former_mod, doc_former_struct ) = doc_generate( &entity_name );
+  let target = qt!{ #name_ident };
+  let ( former_make, former_definition ) = former_generate
+  (
+    &former_name_ident,
+    &target,
+    name_ident,
+    generics,
+    attrs,
+    &former_fields,
+    &entity_name,
+    &doc_former_struct,
+  )?;
+
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+  let result = qt!
+  {
+
+    impl #impl_generics #name_ident #ty_generics #where_clause
+    {
+      ///
+      /// Make former, variation of builder pattern to form structure defining values of fields step by step.
+      ///
+      #[inline]
+      pub fn former() -> #former_name_ident #ty_generics
+      {
+        #former_make
+      }
+    }
+
+    #former_definition
+
+  };
+
+  Ok( result )
+}
+
+///
+/// Generate former for each variant of an enum.
+///
+/// Each variant gets its own former and constructor named after the variant in snake case.
+/// For example variant `Circle` of enum `Shape` gets `Shape::circle()` returning `ShapeCircleFormer`.
+///
 
+fn former_for_enum
+(
+  name_ident : &syn::Ident,
+  generics : &syn::Generics,
+  attrs : &StructAttributes,
+  data_enum : &syn::DataEnum,
+)
+-> Result< proc_macro2::TokenStream >
+{
+  let no_fields = syn::punctuated::Punctuated::new();
+  let mut constructors = Vec::with_capacity( data_enum.variants.len() );
+  let mut definitions = Vec::with_capacity( data_enum.variants.len() );
+
+  let ( impl_generics, ty_generics, where_clause ) = generics.split_for_impl();
+  for variant in data_enum.variants.iter()
+  {
+    let variant_ident = &variant.ident;
+    let fields = match variant.fields
+    {
+      syn::Fields::Named( ref fields_named ) => &fields_named.named,
+      syn::Fields::Unit => &no_fields,
+      syn::Fields::Unnamed( _ ) => return Err( syn_err!( variant, "Unknown format of variant, expected named fields or unit variant\n  {}", qt!{ #variant } ) ),
+    };
+
+    let former_name = format!( "{}{}Former", name_ident, variant_ident );
+    let former_name_ident = syn::Ident::new( &former_name, variant_ident.span() );
+    let constructor_ident = variant_constructor_ident( variant_ident )?;
+
+    let former_fields = former_fields_parse( fields )?;
+    let entity_name = format!( "{}::{}", name_ident, variant_ident );
+    let ( _doc_former_mod, doc_former_struct ) = doc_generate( &entity_name );
+    let doc_constructor = format!
+    (
+      " Make former of variant [{}::{}], variation of builder pattern to form the variant defining values of fields step by step.",
+      name_ident,
+      variant_ident,
+    );
+    let target = qt!{ #name_ident::#variant_ident };
+    let ( former_make, former_definition ) = former_generate
+    (
+      &former_name_ident,
+      &target,
+      name_ident,
+      generics,
+      attrs,
+      &former_fields,
+      &entity_name,
+      &doc_former_struct,
+    )?;
+
+    constructors.push( qt!
+    {
+      #[doc = #doc_constructor]
+      #[inline]
+      pub fn #constructor_ident() -> #former_name_ident #ty_generics
+      {
+        #former_make
       }
+    });
+    definitions.push( former_definition );
+  }
+
+  let result = qt!
+  {
+
+    impl #impl_generics #name_ident #ty_generics #where_clause
+    {
+      #( #constructors )*
+    }
 
-    // }
-    // pub use #former_mod_ident::#former_name_ident;
+    #( #definitions )*
 
   };
 
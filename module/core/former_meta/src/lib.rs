@@ -23,9 +23,12 @@ mod former_impl;
 ///
 /// Derive macro to generate former for a structure. Former is variation of Builder Pattern.
 ///
+/// Generated code refers to crate `former` by path `::former`. If the crate is used through a facade crate,
+/// specify path to it with attribute of the structure `#[ former( crate = facade::former ) ]`.
+///
 
 // #[ cfg( not( feature = "no_std" ) ) ]
-#[ proc_macro_derive( Former, attributes( perform, default, setter, alias, doc ) ) ]
+#[ proc_macro_derive( Former, attributes( former, perform, default, setter, alias, subformer, container, validate, serde, doc ) ) ]
 pub fn former( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
 {
   let result = former_impl::former( input );
@@ -17,11 +17,11 @@ pub( crate ) mod private
   {
     /// Not a container.
     No,
-    /// Vector-like.
+    /// Vector-like : `Vec`, `VecDeque`, `LinkedList`, `BinaryHeap`.
     Vector,
-    /// Hash map-like.
+    /// Hash map-like : `HashMap`, `BTreeMap`.
     HashMap,
-    /// Hash set-like.
+    /// Hash set-like : `HashSet`, `BTreeSet`.
     HashSet,
   }
 
@@ -29,6 +29,7 @@ pub( crate ) mod private
   ///
   /// Good to verify `alloc::vec::Vec< i32 >` is vector.
   /// Good to verify `std::collections::HashMap< i32, i32 >` is hash map.
+  /// Good to verify `std::collections::BTreeMap< i32, i32 >` is hash map-like too.
   ///
   /// ### Basic use-case.
   /// ```
@@ -53,9 +54,9 @@ pub( crate ) mod private
       }
       match last.unwrap().ident.to_string().as_ref()
       {
-        "Vec" => { return ContainerKind::Vector }
-        "HashMap" => { return ContainerKind::HashMap }
-        "HashSet" => { return ContainerKind::HashSet }
+        "Vec" | "VecDeque" | "LinkedList" | "BinaryHeap" => { return ContainerKind::Vector }
+        "HashMap" | "BTreeMap" => { return ContainerKind::HashMap }
+        "HashSet" | "BTreeSet" => { return ContainerKind::HashSet }
         _ => { return ContainerKind::No }
       }
     }
@@ -0,0 +1,133 @@
+//!
+//! Path to a crate used by generated code, given by option `crate = path` of an attribute.
+//!
+
+/// Internal namespace.
+pub( crate ) mod private
+{
+  use crate::exposed::*;
+  use crate::Result;
+
+  ///
+  /// Default path to the crate : the name of the crate from the root, like `::former`, so a local item with the same name is not used instead.
+  ///
+
+  pub fn crate_path_default( crate_name : &str ) -> syn::Path
+  {
+    let ident = syn::Ident::new( crate_name, proc_macro2::Span::call_site() );
+    parse_qt!{ ::#ident }
+  }
+
+  ///
+  /// Parse option `crate = path` if it is the next in the stream, otherwise nothing is parsed and `None` is returned.
+  ///
+  /// For parsers of attributes having other options too.
+  ///
+
+  pub fn crate_option( input : ParseStream< '_ > ) -> Result< Option< syn::Path > >
+  {
+    if !( input.peek( Token![ crate ] ) && input.peek2( Token![ = ] ) )
+    {
+      return Ok( None );
+    }
+    input.parse::< Token![ crate ] >()?;
+    input.parse::< Token![ = ] >()?;
+    Ok( Some( input.parse()? ) )
+  }
+
+  ///
+  /// Path to the crate given by option `crate = path` of attributes with the name, like `#[ reflect( crate = my_facade::derive_tools ) ]`.
+  /// Other options of the attributes are skipped. By default `::` followed by the name of the crate.
+  ///
+
+  pub fn crate_path( attrs : &[ syn::Attribute ], attr_name : &str, crate_name : &str ) -> Result< syn::Path >
+  {
+    let mut result : Option< syn::Path > = None;
+    for attr in attrs.iter().filter( | attr | attr.path.is_ident( attr_name ) )
+    {
+      let parenthesized = matches!
+      (
+        attr.tokens.clone().into_iter().next(),
+        Some( proc_macro2::TokenTree::Group( group ) ) if group.delimiter() == proc_macro2::Delimiter::Parenthesis
+      );
+      if !parenthesized
+      {
+        continue;
+      }
+      let parser = | input : ParseStream< '_ > | -> Result< () >
+      {
+        let input2;
+        syn::parenthesized!( input2 in input );
+        while !input2.is_empty()
+        {
+          let span = input2.span();
+          if let Some( path ) = crate_option( &input2 )?
+          {
+            if result.is_some()
+            {
+              return Err( syn::Error::new( span, format!( "Option `crate` of attribute `{}` is set several times", attr_name ) ) );
+            }
+            result = Some( path );
+          }
+          else
+          {
+            while !input2.is_empty() && !input2.peek( Token![ , ] )
+            {
+              input2.parse::< proc_macro2::TokenTree >()?;
+            }
+          }
+          if !input2.is_empty()
+          {
+            input2.parse::< Token![ , ] >()?;
+          }
+        }
+        Ok( () )
+      };
+      syn::parse::Parser::parse2( parser, attr.tokens.clone() )?;
+    }
+    Ok( result.unwrap_or_else( || crate_path_default( crate_name ) ) )
+  }
+
+}
+
+#[ doc( inline ) ]
+#[ allow( unused_imports ) ]
+pub use protected::*;
+
+/// Protected namespace of the module.
+pub mod protected
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::orphan::*;
+}
+
+/// Orphan namespace of the module.
+pub mod orphan
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::exposed::*;
+}
+
+/// Exposed namespace of the module.
+pub mod exposed
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::prelude::*;
+
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::private::
+  {
+    crate_path_default,
+    crate_option,
+    crate_path,
+  };
+}
+
+/// Prelude to use essentials: `use my_module::prelude::*`.
+pub mod prelude
+{
+}
@@ -14,11 +14,13 @@
 #![ doc = include_str!( concat!( env!( "CARGO_MANIFEST_DIR" ), "/", "Readme.md" ) ) ]
 
 pub mod container_kind;
+pub mod crate_path;
 pub mod helper;
 pub mod name;
 pub mod quantifier;
 pub mod syntax;
 pub mod generic_analyze;
+pub mod variant;
 
 ///
 /// Dependencies of the module.
@@ -71,11 +73,13 @@ pub mod exposed
   {
     prelude::*,
     container_kind::exposed::*,
+    crate_path::exposed::*,
     generic_analyze::exposed::*,
     helper::exposed::*,
     name::exposed::*,
     quantifier::exposed::*,
     syntax::exposed::*,
+    variant::exposed::*,
   };
   #[ doc( inline ) ]
   #[ allow( unused_imports ) ]
@@ -142,6 +146,7 @@ pub mod prelude
     name::prelude::*,
     quantifier::prelude::*,
     syntax::prelude::*,
+    variant::prelude::*,
   };
 
 }
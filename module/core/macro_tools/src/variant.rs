@@ -0,0 +1,202 @@
+//!
+//! Parse variants of an enum to generate code for each of them.
+//!
+
+/// Internal namespace.
+pub( crate ) mod private
+{
+  use crate::exposed::*;
+
+  ///
+  /// Variant of an enum prepared to generate code for it.
+  ///
+
+  #[ derive( Debug, Clone ) ]
+  pub struct VariantParsed
+  {
+    /// Name of the variant.
+    pub ident : syn::Ident,
+    /// Fields of the variant.
+    pub fields : syn::Fields,
+    /// Attributes of the variant.
+    pub attrs : Vec< syn::Attribute >,
+  }
+
+  impl VariantParsed
+  {
+
+    /// Name of the variant in snake case to name methods generated for it.
+    pub fn snake_name( &self ) -> String
+    {
+      to_snake_case( &self.ident.to_string() )
+    }
+
+    /// Types of fields of the variant.
+    pub fn field_types( &self ) -> Vec< &syn::Type >
+    {
+      self.fields.iter().map( | field | &field.ty ).collect()
+    }
+
+    /// Is the variant marked to be skipped by attribute `#[ name( skip ) ]`.
+    ///
+    /// ### Basic use-case.
+    /// ```
+    /// use macro_tools::*;
+    ///
+    /// let ast : syn::DeriveInput = syn::parse_quote!{ enum E { #[ from( skip ) ] A( i32 ), B( i32 ) } };
+    /// let variants = enum_variants( &ast, "From" ).unwrap();
+    /// assert!( variants[ 0 ].is_skipped( "from" ).unwrap() );
+    /// assert!( !variants[ 1 ].is_skipped( "from" ).unwrap() );
+    /// ```
+
+    pub fn is_skipped( &self, name : &str ) -> Result< bool >
+    {
+      let mut result = false;
+      for attr in self.attrs.iter().filter( | attr | attr.path.is_ident( name ) )
+      {
+        let arg : syn::Ident = attr.parse_args()
+        .map_err( | _ | syn_err!( attr, "Expects #[ {}( skip ) ]", name ) )?;
+        if arg != "skip"
+        {
+          return Err( syn_err!( arg, "Unknown argument `{}`, expects #[ {}( skip ) ]", arg, name ) );
+        }
+        result = true;
+      }
+      Ok( result )
+    }
+
+    /// Pattern matching the variant of enum given by path and binding its fields to given identifiers,
+    /// like `Self::A( a, b )` or `Self::B { x : a }`.
+    pub fn pattern( &self, path : &proc_macro2::TokenStream, bindings : &[ syn::Ident ] ) -> proc_macro2::TokenStream
+    {
+      let ident = &self.ident;
+      match &self.fields
+      {
+        syn::Fields::Named( fields ) =>
+        {
+          let names = fields.named.iter().map( | field | &field.ident );
+          qt!{ #path::#ident { #( #names : #bindings ),* } }
+        },
+        syn::Fields::Unnamed( _ ) => qt!{ #path::#ident( #( #bindings ),* ) },
+        syn::Fields::Unit => qt!{ #path::#ident },
+      }
+    }
+
+    /// Identifiers to bind fields of the variant to.
+    pub fn bindings( &self ) -> Vec< syn::Ident >
+    {
+      ( 0 .. self.fields.len() ).map( | index | format_ident!( "field_{}", index ) ).collect()
+    }
+
+  }
+
+  ///
+  /// Parse variants of an enum. If the item is not an enum, error is spanned at the item and names the derive.
+  ///
+  /// ### Basic use-case.
+  /// ```
+  /// use macro_tools::*;
+  ///
+  /// let ast : syn::DeriveInput = syn::parse_quote!{ enum E { HttpRequest( i32 ), Unit } };
+  /// let variants = enum_variants( &ast, "IsVariant" ).unwrap();
+  /// assert_eq!( variants[ 0 ].snake_name(), "http_request" );
+  /// assert_eq!( variants[ 1 ].fields.len(), 0 );
+  /// ```
+
+  pub fn enum_variants( ast : &syn::DeriveInput, derive : &str ) -> Result< Vec< VariantParsed > >
+  {
+    match &ast.data
+    {
+      syn::Data::Enum( data ) => Ok( data.variants.iter().map( | variant | VariantParsed
+      {
+        ident : variant.ident.clone(),
+        fields : variant.fields.clone(),
+        attrs : variant.attrs.clone(),
+      }).collect() ),
+      _ => Err( syn_err!( ast.ident, "{} could be derived only for enum", derive ) ),
+    }
+  }
+
+  ///
+  /// Convert name in `CamelCase` into `snake_case`. Abbreviation is treated as a single word.
+  ///
+  /// ### Basic use-case.
+  /// ```
+  /// use macro_tools::*;
+  ///
+  /// assert_eq!( to_snake_case( "HttpRequest" ), "http_request" );
+  /// assert_eq!( to_snake_case( "IOError" ), "io_error" );
+  /// ```
+
+  pub fn to_snake_case( src : &str ) -> String
+  {
+    let chars : Vec< char > = src.chars().collect();
+    let mut result = String::with_capacity( src.len() + 4 );
+    for ( i, c ) in chars.iter().enumerate()
+    {
+      if c.is_uppercase()
+      {
+        let prev = if i > 0 { Some( chars[ i - 1 ] ) } else { None };
+        let next = chars.get( i + 1 );
+        let word_start = match prev
+        {
+          None | Some( '_' ) => false,
+          Some( prev ) => prev.is_lowercase() || prev.is_ascii_digit() || next.is_some_and( | next | next.is_lowercase() ),
+        };
+        if word_start
+        {
+          result.push( '_' );
+        }
+        result.extend( c.to_lowercase() );
+      }
+      else
+      {
+        result.push( *c );
+      }
+    }
+    result
+  }
+
+}
+
+#[ doc( inline ) ]
+#[ allow( unused_imports ) ]
+pub use protected::*;
+
+/// Protected namespace of the module.
+pub mod protected
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::orphan::*;
+}
+
+/// Orphan namespace of the module.
+pub mod orphan
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::exposed::*;
+}
+
+/// Exposed namespace of the module.
+pub mod exposed
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::prelude::*;
+
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::private::
+  {
+    VariantParsed,
+    enum_variants,
+    to_snake_case,
+  };
+}
+
+/// Prelude to use essentials: `use my_module::prelude::*`.
+pub mod prelude
+{
+}
@@ -195,6 +195,36 @@ TokenStream [
     let got = TheModule::type_container_kind( &tree_type );
     a_id!( got, TheModule::ContainerKind::HashSet );
 
+    // test.case( "vector deque" );
+    let code = qt!( std::collections::VecDeque< i32 > );
+    let tree_type = syn::parse2::< syn::Type >( code ).unwrap();
+    let got = TheModule::type_container_kind( &tree_type );
+    a_id!( got, TheModule::ContainerKind::Vector );
+
+    // test.case( "linked list" );
+    let code = qt!( std::collections::LinkedList< i32 > );
+    let tree_type = syn::parse2::< syn::Type >( code ).unwrap();
+    let got = TheModule::type_container_kind( &tree_type );
+    a_id!( got, TheModule::ContainerKind::Vector );
+
+    // test.case( "binary heap" );
+    let code = qt!( std::collections::BinaryHeap< i32 > );
+    let tree_type = syn::parse2::< syn::Type >( code ).unwrap();
+    let got = TheModule::type_container_kind( &tree_type );
+    a_id!( got, TheModule::ContainerKind::Vector );
+
+    // test.case( "btree map" );
+    let code = qt!( std::collections::BTreeMap< i32, i32 > );
+    let tree_type = syn::parse2::< syn::Type >( code ).unwrap();
+    let got = TheModule::type_container_kind( &tree_type );
+    a_id!( got, TheModule::ContainerKind::HashMap );
+
+    // test.case( "btree set" );
+    let code = qt!( std::collections::BTreeSet< i32 > );
+    let tree_type = syn::parse2::< syn::Type >( code ).unwrap();
+    let got = TheModule::type_container_kind( &tree_type );
+    a_id!( got, TheModule::ContainerKind::HashSet );
+
   }
 
   //
@@ -11,3 +11,4 @@ use TheModule::{ qt, Result };
 mod basic_test;
 mod quantifier_test;
 mod syntax_test;
+mod variant_test;
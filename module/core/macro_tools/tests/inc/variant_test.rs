@@ -0,0 +1,70 @@
+
+use super::*;
+
+//
+
+tests_impls!
+{
+
+  //
+
+  fn to_snake_case()
+  {
+    a_id!( TheModule::to_snake_case( "A" ), "a" );
+    a_id!( TheModule::to_snake_case( "Http" ), "http" );
+    a_id!( TheModule::to_snake_case( "HttpRequest" ), "http_request" );
+    a_id!( TheModule::to_snake_case( "IOError" ), "io_error" );
+    a_id!( TheModule::to_snake_case( "Utf8Error" ), "utf8_error" );
+    a_id!( TheModule::to_snake_case( "Version2X" ), "version2_x" );
+    a_id!( TheModule::to_snake_case( "already_snake" ), "already_snake" );
+  }
+
+  //
+
+  fn enum_variants() -> Result< () >
+  {
+
+    let ast : syn::DeriveInput = syn::parse2( qt!
+    {
+      enum E
+      {
+        #[ from( skip ) ]
+        Tuple( i32, bool ),
+        Named { a : i32 },
+        UnitVariant,
+      }
+    })?;
+    let variants = TheModule::enum_variants( &ast, "From" )?;
+    a_id!( variants.len(), 3 );
+    a_id!( variants[ 0 ].is_skipped( "from" )?, true );
+    a_id!( variants[ 1 ].is_skipped( "from" )?, false );
+    a_id!( variants[ 2 ].snake_name(), "unit_variant" );
+
+    let bindings = variants[ 0 ].bindings();
+    a_id!( variants[ 0 ].pattern( &qt!{ Self }, &bindings ).to_string(), qt!{ Self::Tuple( field_0, field_1 ) }.to_string() );
+    let bindings = variants[ 1 ].bindings();
+    a_id!( variants[ 1 ].pattern( &qt!{ Self }, &bindings ).to_string(), qt!{ Self::Named { a : field_0 } }.to_string() );
+    a_id!( variants[ 2 ].pattern( &qt!{ E }, &[] ).to_string(), qt!{ E::UnitVariant }.to_string() );
+
+    // not an enum
+    let ast : syn::DeriveInput = syn::parse2( qt!{ struct S; } )?;
+    let err = TheModule::enum_variants( &ast, "IsVariant" ).unwrap_err();
+    a_id!( err.to_string(), "IsVariant could be derived only for enum" );
+
+    // bad argument
+    let ast : syn::DeriveInput = syn::parse2( qt!{ enum E { #[ from( other ) ] A( i32 ) } } )?;
+    let err = TheModule::enum_variants( &ast, "From" )?[ 0 ].is_skipped( "from" ).unwrap_err();
+    a_id!( err.to_string(), "Unknown argument `other`, expects #[ from( skip ) ]" );
+
+    Ok( () )
+  }
+
+}
+
+//
+
+tests_index!
+{
+  to_snake_case,
+  enum_variants,
+}
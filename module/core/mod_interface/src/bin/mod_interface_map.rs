@@ -0,0 +1,45 @@
+//!
+//! Render maps of namespaces of modules written by `mod_interface!` at build time with feature `map` as a tree.
+//!
+//! Usage : `mod_interface_map [ DIR ] [ --crate NAME ]`. Without `DIR` maps are looked for in `$MOD_INTERFACE_MAP_DIR`
+//! or in `mod_interface_map` inside of the closest `target` directory.
+//!
+
+use mod_interface::map;
+
+fn main()
+{
+  let mut dir = None;
+  let mut crate_name = None;
+  let mut args = std::env::args().skip( 1 );
+  while let Some( arg ) = args.next()
+  {
+    match arg.as_str()
+    {
+      "--crate" => crate_name = args.next(),
+      "-h" | "--help" =>
+      {
+        println!( "Usage : mod_interface_map [ DIR ] [ --crate NAME ]" );
+        return;
+      },
+      _ => dir = Some( std::path::PathBuf::from( arg ) ),
+    }
+  }
+
+  let dir = dir.unwrap_or_else( || map::map_dir( &std::env::current_dir().unwrap_or_default() ) );
+  let mut maps = match map::load( &dir )
+  {
+    Ok( maps ) => maps,
+    Err( err ) =>
+    {
+      eprintln!( "Failed to read maps from {:?} : {}\nBuild with feature `mod_interface/map` to write them.", dir, err );
+      std::process::exit( 1 );
+    },
+  };
+  if let Some( crate_name ) = crate_name
+  {
+    maps.retain( | map | map.crate_name == crate_name );
+  }
+
+  print!( "{}", map::render( &maps ) );
+}
@@ -23,6 +23,11 @@ pub mod dependency
   pub use mod_interface_meta;
 }
 
+/// Maps of namespaces of modules written at build time.
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "map" ) ]
+pub mod map;
+
 /// Protected namespace of the module.
 #[ cfg( feature = "enabled" ) ]
 pub mod protected
@@ -0,0 +1,322 @@
+//!
+//! Read maps of namespaces of modules written by `mod_interface!` at build time and render them as a tree.
+//!
+
+/// Internal namespace.
+pub( crate ) mod private
+{
+  use std::collections::HashSet;
+  use std::fmt::Write;
+  use std::path::{ Component, Path, PathBuf };
+
+  ///
+  /// Element of a namespace of a module : what is re-exported there and by which record.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct MapEntry
+  {
+    /// Kind of the record : `layer`, `mod` or `use`.
+    pub kind : String,
+    /// Path of what is re-exported relative to the module, like `layer_a::orphan::*`.
+    pub path : String,
+    /// Attributes of the record except documentation, like `#[cfg(feature = "x")]`.
+    pub attrs : Vec< String >,
+  }
+
+  ///
+  /// Child module declared by a module : layer or micro module.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct MapChild
+  {
+    /// Kind of the record : `layer` or `mod`.
+    pub kind : String,
+    /// Name of the child module.
+    pub name : String,
+    /// File of the child module.
+    pub file : String,
+    /// Attributes of the record except documentation.
+    pub attrs : Vec< String >,
+  }
+
+  ///
+  /// Map of namespaces of a module produced by a single call of `mod_interface!`.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct ModuleMap
+  {
+    /// Name of the crate the module belongs to.
+    pub crate_name : String,
+    /// File in which `mod_interface!` is called.
+    pub file : String,
+    /// Line of the file at which `mod_interface!` is called, 0 if unknown.
+    pub line : usize,
+    /// Declared layers and micro modules.
+    pub children : Vec< MapChild >,
+    /// Namespaces `protected`, `orphan`, `exposed`, `prelude` with their entries.
+    pub namespaces : Vec< ( String, Vec< MapEntry > ) >,
+  }
+
+  impl ModuleMap
+  {
+
+    /// Parse map from its JSON.
+    pub fn from_json( src : &str ) -> Result< Self, String >
+    {
+      let value : serde_json::Value = serde_json::from_str( src ).map_err( | err | err.to_string() )?;
+      let str_of = | value : &serde_json::Value, key : &str | -> Result< String, String >
+      {
+        value[ key ].as_str().map( str::to_string ).ok_or_else( || format!( "Expects string `{}`", key ) )
+      };
+      let strs_of = | value : &serde_json::Value, key : &str | -> Vec< String >
+      {
+        value[ key ].as_array().into_iter().flatten().filter_map( | e | e.as_str().map( str::to_string ) ).collect()
+      };
+
+      let mut children = vec![];
+      for child in value[ "children" ].as_array().into_iter().flatten()
+      {
+        children.push( MapChild
+        {
+          kind : str_of( child, "kind" )?,
+          name : str_of( child, "name" )?,
+          file : str_of( child, "file" )?,
+          attrs : strs_of( child, "attrs" ),
+        });
+      }
+
+      let mut namespaces = vec![];
+      for ( name, entries ) in value[ "namespaces" ].as_object().into_iter().flatten()
+      {
+        let mut list = vec![];
+        for entry in entries.as_array().into_iter().flatten()
+        {
+          list.push( MapEntry
+          {
+            kind : str_of( entry, "kind" )?,
+            path : str_of( entry, "path" )?,
+            attrs : strs_of( entry, "attrs" ),
+          });
+        }
+        namespaces.push( ( name.clone(), list ) );
+      }
+      namespaces.sort_by_key( | ( name, _ ) | namespace_order( name ) );
+
+      Ok( Self
+      {
+        crate_name : str_of( &value, "crate" )?,
+        file : str_of( &value, "file" )?,
+        line : value[ "line" ].as_u64().unwrap_or( 0 ) as usize,
+        children,
+        namespaces,
+      })
+    }
+
+    /// Entries of namespace by its name.
+    pub fn namespace( &self, name : &str ) -> Option< &[ MapEntry ] >
+    {
+      self.namespaces.iter().find( | ( n, _ ) | n == name ).map( | ( _, entries ) | entries.as_slice() )
+    }
+
+  }
+
+  /// Order of namespaces from the widest one.
+  fn namespace_order( name : &str ) -> usize
+  {
+    [ "protected", "orphan", "exposed", "prelude" ].iter().position( | n | *n == name ).unwrap_or( usize::MAX )
+  }
+
+  ///
+  /// Directory maps are written to : `$MOD_INTERFACE_MAP_DIR` if defined, otherwise `mod_interface_map`
+  /// inside of `$CARGO_TARGET_DIR` or inside of the closest `target` directory above `start`.
+  ///
+
+  pub fn map_dir( start : &Path ) -> PathBuf
+  {
+    if let Ok( dir ) = std::env::var( "MOD_INTERFACE_MAP_DIR" )
+    {
+      return PathBuf::from( dir );
+    }
+    let target = match std::env::var( "CARGO_TARGET_DIR" )
+    {
+      Ok( dir ) => start.join( dir ),
+      Err( _ ) => start.ancestors()
+      .map( | dir | dir.join( "target" ) )
+      .find( | dir | dir.is_dir() )
+      .unwrap_or_else( || start.join( "target" ) ),
+    };
+    target.join( "mod_interface_map" )
+  }
+
+  ///
+  /// Read all maps from the directory, sorted by crate, file and line.
+  ///
+
+  pub fn load( dir : &Path ) -> std::io::Result< Vec< ModuleMap > >
+  {
+    let mut result = vec![];
+    for entry in std::fs::read_dir( dir )?
+    {
+      let path = entry?.path();
+      if path.extension().is_some_and( | ext | ext == "json" )
+      {
+        let map = ModuleMap::from_json( &std::fs::read_to_string( &path )? )
+        .map_err( | err | std::io::Error::new( std::io::ErrorKind::InvalidData, format!( "{:?} : {}", path, err ) ) )?;
+        result.push( map );
+      }
+    }
+    result.sort_by( | a, b | ( &a.crate_name, &a.file, a.line ).cmp( &( &b.crate_name, &b.file, b.line ) ) );
+    Ok( result )
+  }
+
+  /// Path without `.` and with `..` applied, to compare paths written differently.
+  fn normalize( path : &str ) -> PathBuf
+  {
+    let mut result = PathBuf::new();
+    for component in Path::new( path ).components()
+    {
+      match component
+      {
+        Component::CurDir => {},
+        Component::ParentDir if result.file_name().is_some() => { result.pop(); },
+        component => result.push( component ),
+      }
+    }
+    result
+  }
+
+  ///
+  /// Render maps as a tree. Each crate has its own tree starting from modules which are not layers or micro modules of other modules.
+  ///
+
+  pub fn render( maps : &[ ModuleMap ] ) -> String
+  {
+    let mut out = String::new();
+    let mut crates : Vec< &str > = maps.iter().map( | map | map.crate_name.as_str() ).collect();
+    crates.sort_unstable();
+    crates.dedup();
+    for crate_name in crates
+    {
+      let maps : Vec< &ModuleMap > = maps.iter().filter( | map | map.crate_name == crate_name ).collect();
+      let children : HashSet< PathBuf > = maps.iter()
+      .flat_map( | map | map.children.iter().map( | child | normalize( &child.file ) ) )
+      .collect();
+      let _ = writeln!( out, "{}", crate_name );
+      let roots : Vec< &&ModuleMap > = maps.iter().filter( | map | !children.contains( &normalize( &map.file ) ) ).collect();
+      for ( i, root ) in roots.iter().enumerate()
+      {
+        let last = i + 1 == roots.len();
+        let _ = writeln!( out, "{}{}", if last { "└── " } else { "├── " }, normalize( &root.file ).display() );
+        let mut visited = HashSet::new();
+        render_module( &mut out, &maps, root, if last { "    " } else { "│   " }, &mut visited );
+      }
+    }
+    out
+  }
+
+  fn render_module< 'a >
+  (
+    out : &mut String,
+    maps : &[ &'a ModuleMap ],
+    map : &'a ModuleMap,
+    prefix : &str,
+    visited : &mut HashSet< PathBuf >,
+  )
+  {
+    visited.insert( normalize( &map.file ) );
+    let count = map.namespaces.len() + map.children.len();
+    let mut index = 0;
+    let mut branch = | out : &mut String, label : String | -> String
+    {
+      index += 1;
+      let last = index == count;
+      let _ = writeln!( out, "{}{}{}", prefix, if last { "└── " } else { "├── " }, label );
+      format!( "{}{}", prefix, if last { "    " } else { "│   " } )
+    };
+
+    for ( name, entries ) in &map.namespaces
+    {
+      let prefix = branch( out, name.clone() );
+      for ( i, entry ) in entries.iter().enumerate()
+      {
+        let last = i + 1 == entries.len();
+        let _ = writeln!( out, "{}{}{}", prefix, if last { "└── " } else { "├── " }, label( &entry.path, &entry.attrs ) );
+      }
+    }
+
+    for child in &map.children
+    {
+      let prefix = branch( out, format!( "{} {} : {}", child.kind, label( &child.name, &child.attrs ), child.file ) );
+      let file = normalize( &child.file );
+      if visited.contains( &file )
+      {
+        continue;
+      }
+      if let Some( child_map ) = maps.iter().find( | map | normalize( &map.file ) == file )
+      {
+        render_module( out, maps, child_map, &prefix, visited );
+      }
+    }
+  }
+
+  /// Text of a node : name followed by its attributes if any.
+  fn label( name : &str, attrs : &[ String ] ) -> String
+  {
+    if attrs.is_empty()
+    {
+      name.to_string()
+    }
+    else
+    {
+      format!( "{} {}", name, attrs.join( " " ) )
+    }
+  }
+
+}
+
+#[ doc( inline ) ]
+#[ allow( unused_imports ) ]
+pub use protected::*;
+
+/// Protected namespace of the module.
+pub mod protected
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::orphan::*;
+}
+
+/// Orphan namespace of the module.
+pub mod orphan
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::exposed::*;
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::private::
+  {
+    MapEntry,
+    MapChild,
+    ModuleMap,
+    map_dir,
+    load,
+    render,
+  };
+}
+
+/// Exposed namespace of the module.
+pub mod exposed
+{
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::prelude::*;
+}
+
+/// Prelude to use essentials: `use my_module::prelude::*`.
+pub mod prelude
+{
+}
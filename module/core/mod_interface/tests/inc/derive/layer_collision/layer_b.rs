@@ -0,0 +1,22 @@
+use super::tools::*;
+
+/// Private namespace of the module.
+mod private
+{
+
+  /// f of layer_b
+  pub fn f() -> bool
+  {
+    true
+  }
+
+}
+
+//
+
+mod_interface!
+{
+
+  prelude use f;
+
+}
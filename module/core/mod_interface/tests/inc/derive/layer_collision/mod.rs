@@ -0,0 +1,22 @@
+
+use super::*;
+mod tools
+{
+  #[ allow( unused_imports ) ]
+  pub use super::super::*;
+}
+
+/// Private namespace of the module.
+mod private
+{
+}
+
+mod_interface!
+{
+
+  /// layer_a
+  layer layer_a;
+  /// layer_b
+  layer layer_b;
+
+}
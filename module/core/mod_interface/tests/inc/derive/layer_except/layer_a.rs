@@ -0,0 +1,34 @@
+use super::tools::*;
+
+/// Private namespace of the module.
+mod private
+{
+
+  /// layer_a_exposed
+  pub fn layer_a_exposed() -> bool
+  {
+    true
+  }
+
+  /// Name exported by both layers.
+  #[ derive( Debug, PartialEq ) ]
+  pub struct Shared( pub &'static str );
+
+  /// Name exported by both layers, returns name of the layer.
+  pub fn shared() -> &'static str
+  {
+    "layer_a"
+  }
+
+}
+
+//
+
+mod_interface!
+{
+
+  exposed use layer_a_exposed;
+  exposed use Shared;
+  prelude use shared;
+
+}
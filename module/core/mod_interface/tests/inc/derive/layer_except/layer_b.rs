@@ -0,0 +1,34 @@
+use super::tools::*;
+
+/// Private namespace of the module.
+mod private
+{
+
+  /// layer_b_exposed
+  pub fn layer_b_exposed() -> bool
+  {
+    true
+  }
+
+  /// Name exported by both layers.
+  #[ derive( Debug, PartialEq ) ]
+  pub struct Shared( pub &'static str );
+
+  /// Name exported by both layers, returns name of the layer.
+  pub fn shared() -> &'static str
+  {
+    "layer_b"
+  }
+
+}
+
+//
+
+mod_interface!
+{
+
+  exposed use layer_b_exposed;
+  exposed use Shared;
+  prelude use shared;
+
+}
@@ -0,0 +1,26 @@
+
+use super::*;
+mod tools
+{
+  #[ allow( unused_imports ) ]
+  pub use super::super::*;
+}
+
+/// Private namespace of the module.
+mod private
+{
+}
+
+mod_interface!
+{
+
+  /// layer_a
+  layer layer_a except { shared, Shared };
+  /// layer_b
+  layer layer_b;
+
+}
+
+//
+
+include!( "../../only_test/layer_except_only_test.rs" );
@@ -0,0 +1,19 @@
+
+use super::TheModule;
+
+/// Private namespace of the module.
+mod private
+{
+
+  /// f1
+  pub fn f1(){}
+
+}
+
+TheModule::mod_interface!
+{
+
+  /// f1
+  exposed use f1;
+
+}
@@ -0,0 +1,15 @@
+
+use super::*;
+
+/// Private namespace of the module.
+mod private
+{
+}
+
+TheModule::mod_interface!
+{
+
+  /// layer_a
+  layer layer_a except { f2 };
+
+}
@@ -0,0 +1,25 @@
+
+use super::tools::*;
+
+/// Private namespace of the module.
+mod private
+{
+
+  /// layer_a_exposed
+  pub fn layer_a_exposed() -> bool
+  {
+    true
+  }
+
+}
+
+//
+
+mod_interface!
+{
+
+  exposed use layer_a_exposed;
+  /// The same trait is exported by both layers.
+  exposed use ::core::fmt::Debug;
+
+}
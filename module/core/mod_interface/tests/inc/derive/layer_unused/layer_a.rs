@@ -0,0 +1,22 @@
+use super::tools::*;
+
+/// Private namespace of the module.
+mod private
+{
+
+  /// layer_a_protected
+  pub fn layer_a_protected() -> bool
+  {
+    true
+  }
+
+}
+
+//
+
+mod_interface!
+{
+
+  protected use layer_a_protected;
+
+}
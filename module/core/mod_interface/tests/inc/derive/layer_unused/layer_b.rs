@@ -0,0 +1,22 @@
+use super::tools::*;
+
+/// Private namespace of the module.
+mod private
+{
+
+  /// layer_b_exposed
+  pub fn layer_b_exposed() -> bool
+  {
+    true
+  }
+
+}
+
+//
+
+mod_interface!
+{
+
+  exposed use layer_b_exposed;
+
+}
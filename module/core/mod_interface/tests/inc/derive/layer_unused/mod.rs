@@ -0,0 +1,23 @@
+
+use super::*;
+mod tools
+{
+  #[ allow( unused_imports ) ]
+  pub use super::super::*;
+}
+
+/// Private namespace of the module.
+mod private
+{
+}
+
+mod_interface!
+{
+  #![ warn_unused_layers ]
+
+  /// layer_a
+  layer layer_a;
+  /// layer_b
+  layer layer_b;
+
+}
@@ -17,6 +17,8 @@ mod layer_have_layer_cfg;
 mod layer_have_mod_cfg;
 mod layer_use_cfg;
 mod layer_use_macro;
+mod layer_except;
+mod layer_same_item;
 
 mod use_layer;
 mod use_basic;
@@ -24,6 +26,8 @@ mod use_basic;
 mod use_as_derive;
 #[ path = "./use_as/manual.rs" ]
 mod use_as_manual;
+mod use_rename;
+mod use_except;
 
 // attr
 mod attr_debug;
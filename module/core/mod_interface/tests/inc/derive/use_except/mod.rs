@@ -0,0 +1,113 @@
+
+use super::*;
+mod tools
+{
+  #[ allow( unused_imports ) ]
+  pub use super::super::*;
+}
+
+/// Private namespace of the module.
+mod private
+{
+
+  /// Module whose items are used by glob.
+  pub mod tools
+  {
+
+    /// Returns name of the module.
+    pub fn one() -> &'static str
+    {
+      "tools"
+    }
+
+    /// Returns name of the module.
+    pub fn two() -> &'static str
+    {
+      "tools"
+    }
+
+  }
+
+  /// Module having module with the same name.
+  pub mod nested
+  {
+
+    /// Module whose items are used by glob, having the same name as the other one.
+    pub mod tools
+    {
+
+      /// Returns name of the module.
+      pub fn one() -> &'static str
+      {
+        "nested"
+      }
+
+      /// Returns name of the module.
+      pub fn three() -> &'static str
+      {
+        "nested"
+      }
+
+    }
+
+  }
+
+  /// Module having item with the same name.
+  pub mod other
+  {
+
+    /// Returns name of the module.
+    pub fn two() -> &'static str
+    {
+      "other"
+    }
+
+  }
+
+}
+
+/// layer_a
+mod layer_a;
+/// layer_b
+mod layer_b;
+
+mod_interface!
+{
+
+  /// layer_a
+  use super::layer_a except { shared, Shared };
+  /// layer_b
+  use super::layer_b;
+
+  exposed use tools::* except { two };
+  prelude use other::two;
+  prelude use nested::tools::* except { one };
+
+}
+
+//
+
+include!( "../../only_test/layer_except_only_test.rs" );
+
+//
+
+tests_impls!
+{
+  fn glob_except()
+  {
+    a_id!( exposed::one(), "tools" );
+    a_id!( exposed::two(), "other" );
+    a_id!( two(), "other" );
+    a_id!( private::tools::two(), "tools" );
+    a_id!( three(), "nested" );
+    a_id!( prelude::three(), "nested" );
+    a_id!( one(), "tools" );
+  }
+}
+
+//
+
+tests_index!
+{
+  glob_except,
+}
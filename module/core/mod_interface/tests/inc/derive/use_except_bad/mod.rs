@@ -0,0 +1,18 @@
+
+use super::*;
+
+/// Private
+mod private
+{
+
+  pub fn f1(){}
+
+}
+
+TheModule::mod_interface!
+{
+
+  /// f1
+  exposed use f1 except { f1 };
+
+}
@@ -0,0 +1,22 @@
+
+use super::*;
+
+/// Private
+mod private
+{
+
+  /// Module whose items are used by glob.
+  pub mod tools
+  {
+    pub fn f1(){}
+  }
+
+}
+
+TheModule::mod_interface!
+{
+
+  /// f1
+  exposed use tools::* except { f2 };
+
+}
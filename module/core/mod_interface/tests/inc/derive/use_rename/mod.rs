@@ -0,0 +1,52 @@
+
+use super::*;
+
+/// Private namespace of the module.
+mod private
+{
+
+  /// Returns its name.
+  pub fn f1() -> &'static str
+  {
+    "f1"
+  }
+
+  /// Returns its name.
+  pub fn f2() -> &'static str
+  {
+    "f2"
+  }
+
+  /// Inner module.
+  pub mod inner
+  {
+
+    /// Returns its name.
+    pub fn f3() -> &'static str
+    {
+      "f3"
+    }
+
+    /// Struct for testing purpose.
+    #[ derive( Debug, PartialEq ) ]
+    pub struct Struct1;
+
+  }
+
+}
+
+TheModule::mod_interface!
+{
+
+  /// f1
+  exposed use f1 as g1;
+  /// f2 and f3
+  prelude use { f2 as g2, inner::f3 as g3 };
+  /// Struct1
+  orphan use inner::Struct1 as Renamed;
+
+}
+
+//
+
+include!( "../../only_test/use_rename_only_test.rs" );
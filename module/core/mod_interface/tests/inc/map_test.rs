@@ -0,0 +1,93 @@
+use super::*;
+use TheModule::map;
+
+//
+
+tests_impls!
+{
+
+  fn from_json()
+  {
+    let src = r##"{"crate":"a","file":"src/lib.rs","line":3,"children":[{"kind":"layer","name":"b","file":"src/b.rs","attrs":[]}],"namespaces":{"prelude":[],"protected":[{"kind":"layer","path":"b::orphan::*","attrs":["#[cfg(feature = \"x\")]"]}],"orphan":[],"exposed":[]}}"##;
+    let got = map::ModuleMap::from_json( src ).unwrap();
+    a_id!( got.crate_name, "a" );
+    a_id!( got.line, 3 );
+    a_id!( got.children[ 0 ].name, "b" );
+    let names : Vec< &str > = got.namespaces.iter().map( | ( name, _ ) | name.as_str() ).collect();
+    a_id!( names, vec![ "protected", "orphan", "exposed", "prelude" ] );
+    let protected = got.namespace( "protected" ).unwrap();
+    a_id!( protected[ 0 ].path, "b::orphan::*" );
+    a_id!( protected[ 0 ].attrs, vec![ "#[cfg(feature = \"x\")]".to_string() ] );
+
+    a_true!( map::ModuleMap::from_json( "{}" ).is_err() );
+    a_true!( map::ModuleMap::from_json( "not json" ).is_err() );
+  }
+
+  //
+
+  fn render()
+  {
+    let entry = | path : &str | map::MapEntry { kind : "layer".into(), path : path.into(), attrs : vec![] };
+    let root = map::ModuleMap
+    {
+      crate_name : "a".into(),
+      file : "src/lib.rs".into(),
+      line : 1,
+      children : vec![ map::MapChild { kind : "layer".into(), name : "b".into(), file : "src/./b.rs".into(), attrs : vec![] } ],
+      namespaces : vec![ ( "protected".into(), vec![ entry( "b::orphan::*" ) ] ), ( "prelude".into(), vec![] ) ],
+    };
+    let child = map::ModuleMap
+    {
+      crate_name : "a".into(),
+      file : "src/b.rs".into(),
+      line : 1,
+      children : vec![],
+      namespaces : vec![ ( "prelude".into(), vec![ entry( "c::prelude::*" ) ] ) ],
+    };
+    let got = map::render( &[ child, root ] );
+    let exp = "\
+a
+└── src/lib.rs
+    ├── protected
+    │   └── b::orphan::*
+    ├── prelude
+    └── layer b : src/./b.rs
+        └── prelude
+            └── c::prelude::*
+";
+    a_id!( got, exp );
+  }
+
+  //
+
+  fn written()
+  {
+    let dir = map::map_dir( std::path::Path::new( env!( "CARGO_MANIFEST_DIR" ) ) );
+    let maps = map::load( &dir ).unwrap();
+    let got = maps.iter()
+    .find( | map | map.crate_name == "mod_interface_tests" && map.file.ends_with( "tests/inc/derive/layer/mod.rs" ) )
+    .unwrap();
+
+    let children : Vec< &str > = got.children.iter().map( | child | child.name.as_str() ).collect();
+    a_id!( children, vec![ "layer_a", "layer_b" ] );
+    a_true!( got.children[ 0 ].file.ends_with( "tests/inc/derive/layer/layer_a.rs" ) );
+    let protected : Vec< &str > = got.namespace( "protected" ).unwrap().iter().map( | entry | entry.path.as_str() ).collect();
+    a_id!( protected, vec![ "layer_a::orphan::*", "layer_b::orphan::*" ] );
+    let prelude : Vec< &str > = got.namespace( "prelude" ).unwrap().iter().map( | entry | entry.path.as_str() ).collect();
+    a_id!( prelude, vec![ "layer_a::prelude::*", "layer_b::prelude::*" ] );
+
+    let tree = map::render( &maps );
+    a_true!( tree.contains( "layer layer_a : " ) );
+    a_true!( tree.contains( "layer_b::exposed::*" ) );
+  }
+
+}
+
+//
+
+tests_index!
+{
+  from_json,
+  render,
+  written,
+}
@@ -5,3 +5,5 @@ use super::*;
 mod manual;
 mod derive;
 mod trybuild_test;
+#[ cfg( feature = "map" ) ]
+mod map_test;
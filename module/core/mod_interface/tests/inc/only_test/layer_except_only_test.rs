@@ -0,0 +1,43 @@
+// use super::*;
+
+//
+
+tests_impls!
+{
+  fn except()
+  {
+
+    /* test.case( "excluded names come from the other layer" ); */
+    {
+      a_id!( shared(), "layer_b" );
+      a_id!( protected::shared(), "layer_b" );
+      a_id!( orphan::shared(), "layer_b" );
+      a_id!( exposed::shared(), "layer_b" );
+      a_id!( prelude::shared(), "layer_b" );
+      a_id!( exposed::Shared( "x" ), layer_b::Shared( "x" ) );
+    }
+
+    /* test.case( "the rest of the layer is used" ); */
+    {
+      a_id!( layer_a_exposed(), true );
+      a_id!( exposed::layer_a_exposed(), true );
+      a_id!( layer_b_exposed(), true );
+      a_id!( exposed::layer_b_exposed(), true );
+    }
+
+    /* test.case( "excluded names are still in the layer" ); */
+    {
+      a_id!( layer_a::shared(), "layer_a" );
+      a_id!( layer_a::prelude::shared(), "layer_a" );
+      a_id!( layer_a::exposed::Shared( "x" ), layer_a::Shared( "x" ) );
+    }
+
+  }
+}
+
+//
+
+tests_index!
+{
+  except,
+}
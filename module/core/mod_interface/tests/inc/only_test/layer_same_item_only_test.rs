@@ -0,0 +1,36 @@
+// use super::*;
+
+//
+
+tests_impls!
+{
+  fn same_item()
+  {
+
+    /* test.case( "item exported by both layers is not a collision" ); */
+    {
+      fn debug_str< T : exposed::Debug >( src : T ) -> String
+      {
+        format!( "{:?}", src )
+      }
+      a_id!( debug_str( 13 ), "13" );
+      a_id!( debug_str( "x" ), "\"x\"" );
+    }
+
+    /* test.case( "the rest of layers is used" ); */
+    {
+      a_id!( layer_a_exposed(), true );
+      a_id!( exposed::layer_a_exposed(), true );
+      a_id!( layer_b_exposed(), true );
+      a_id!( exposed::layer_b_exposed(), true );
+    }
+
+  }
+}
+
+//
+
+tests_index!
+{
+  same_item,
+}
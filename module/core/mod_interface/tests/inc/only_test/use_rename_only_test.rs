@@ -0,0 +1,36 @@
+// use super::*;
+
+//
+
+tests_impls!
+{
+  fn rename()
+  {
+
+    /* test.case( "root" ); */
+    {
+      a_id!( g1(), "f1" );
+      a_id!( g2(), "f2" );
+      a_id!( g3(), "f3" );
+      a_id!( Renamed, private::inner::Struct1 );
+    }
+
+    /* test.case( "namespaces" ); */
+    {
+      a_id!( protected::g1(), "f1" );
+      a_id!( orphan::Renamed, private::inner::Struct1 );
+      a_id!( exposed::g1(), "f1" );
+      a_id!( exposed::g2(), "f2" );
+      a_id!( prelude::g2(), "f2" );
+      a_id!( prelude::g3(), "f3" );
+    }
+
+  }
+}
+
+//
+
+tests_index!
+{
+  rename,
+}
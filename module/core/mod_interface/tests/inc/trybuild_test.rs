@@ -51,12 +51,15 @@ use super::*;
       t.pass( current_dir.join( "tests/inc/derive/layer_use_cfg/trybuild.rs" ) );
       t.pass( current_dir.join( "tests/inc/derive/layer_have_mod_cfg/trybuild.rs" ) );
       t.pass( current_dir.join( "tests/inc/derive/layer_use_macro/trybuild.rs" ) );
+      t.pass( current_dir.join( "tests/inc/derive/layer_except/trybuild.rs" ) );
 
       // use
 
       t.pass( current_dir.join( "tests/inc/derive/use_basic/trybuild.rs" ) );
       t.pass( current_dir.join( "tests/inc/derive/use_layer/trybuild.rs" ) );
       t.pass( current_dir.join( "tests/inc/derive/use_as/trybuild.rs" ) );
+      t.pass( current_dir.join( "tests/inc/derive/use_rename/trybuild.rs" ) );
+      t.pass( current_dir.join( "tests/inc/derive/use_except/trybuild.rs" ) );
 
       // attr
 
@@ -102,6 +105,11 @@ only_for_terminal_module!
     t.compile_fail( current_dir.join( "tests/inc/derive/layer_unknown_vis/trybuild.rs" ) );
     t.compile_fail( current_dir.join( "tests/inc/derive/use_bad_vis/trybuild.rs" ) );
     t.compile_fail( current_dir.join( "tests/inc/derive/use_unknown_vis/trybuild.rs" ) );
+    t.compile_fail( current_dir.join( "tests/inc/derive/use_except_bad/trybuild.rs" ) );
+    t.compile_fail( current_dir.join( "tests/inc/derive/use_except_unknown/trybuild.rs" ) );
+    t.compile_fail( current_dir.join( "tests/inc/derive/layer_except_unknown/trybuild.rs" ) );
+    t.compile_fail( current_dir.join( "tests/inc/derive/layer_unused/trybuild.rs" ) );
+    t.compile_fail( current_dir.join( "tests/inc/derive/layer_collision/trybuild.rs" ) );
   }
 }
 
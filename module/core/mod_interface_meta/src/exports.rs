@@ -0,0 +1,492 @@
+/// Internal namespace.
+pub( crate ) mod private
+{
+  use crate::visibility::ClauseKind;
+  use macro_tools::exposed::*;
+
+  ///
+  /// Name a record brings into namespaces of the module.
+  ///
+
+  #[ derive( Debug, Clone ) ]
+  pub struct Export
+  {
+    /// Attributes of conditional compilation of the record.
+    pub cfg : proc_macro2::TokenStream,
+    /// The narrowest namespace the name is brought into.
+    pub namespace : ClauseKind,
+    /// Exported name, `*` if names are unknown, like names of a glob.
+    pub name : String,
+    /// Absolute path to the item, so the same item brought by several records is not a collision. Empty if unknown.
+    pub item : String,
+  }
+
+  ///
+  /// Module namespaces of which are used by a record, like a layer.
+  ///
+
+  #[ derive( Debug, Clone ) ]
+  pub struct Source
+  {
+    /// Attributes of conditional compilation of the record.
+    pub cfg : proc_macro2::TokenStream,
+    /// Path to the module from a module next to namespaces.
+    pub path : proc_macro2::TokenStream,
+    /// Names excluded by `except`.
+    pub except : Vec< String >,
+    /// The record in messages, like "layer `layer_a`".
+    pub description : String,
+    /// Name of the layer to warn about if it is unused, `None` for a module used by `use`.
+    pub layer : Option< syn::Ident >,
+    /// Span of the record to point at.
+    pub span : proc_macro2::Span,
+  }
+
+  ///
+  /// Names brought into namespaces of a module by its records, to find collisions of names and unused layers.
+  ///
+  /// Each `mod_interface!` defines hidden constant `__MOD_INTERFACE_EXPORTS` listing names which its namespaces bring to parent,
+  /// so parent gets names of its layers when the compiler evaluates constants. A layer written manually has no such constant,
+  /// so names it brings are unknown and it is supposed to bring something.
+  ///
+
+  #[ derive( Debug, Default ) ]
+  pub struct Exports
+  {
+    /// Names brought by `use` and `mod` records.
+    pub own : Vec< Export >,
+    /// Modules namespaces of which are used.
+    pub sources : Vec< Source >,
+  }
+
+  impl Exports
+  {
+
+    /// Exports of the module.
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Register names brought into the namespace by record `use`.
+    pub fn use_add( &mut self, tree : &syn::UseTree, leading_colon : bool, attrs : &[ &AttributesOuter ], namespace : ClauseKind )
+    {
+      let mut items = vec![];
+      use_tree_items( tree, String::new(), &mut items );
+      for ( name, item ) in items
+      {
+        // only a path from root of a crate identifies the item
+        let item = if leading_colon { format!( "::{}", item ) } else if item.starts_with( "crate::" ) { item } else { String::new() };
+        self.own.push( Export { cfg : cfg_attrs( attrs ), namespace, name, item } );
+      }
+    }
+
+    /// Register name of micro module.
+    pub fn mod_add( &mut self, name : &syn::Ident, attrs : &[ &AttributesOuter ], namespace : ClauseKind )
+    {
+      self.own.push( Export { cfg : cfg_attrs( attrs ), namespace, name : name.to_string(), item : String::new() } );
+    }
+
+    /// Register module namespaces of which are used.
+    pub fn source_add( &mut self, source : Source )
+    {
+      self.sources.push( source );
+    }
+
+    ///
+    /// Constant `__MOD_INTERFACE_EXPORTS` with names brought to parent and checks of names brought by different records.
+    /// A name brought by several layers is ambiguous in namespaces of the module, unless it is the same item.
+    /// Records of the module are checked by the compiler, which rejects a name defined twice.
+    /// If `warn_unused_layers`, a layer bringing nothing is warned about by a call of a deprecated method.
+    ///
+
+    pub fn generate( &self, warn_unused_layers : bool ) -> proc_macro2::TokenStream
+    {
+      let own : Vec< _ > = self.own.iter().filter( | export | export.namespace != ClauseKind::Protected ).collect();
+      let own_cfgs : Vec< _ > = own.iter().map( | export | &export.cfg ).collect();
+      let own_entries = own.iter().map( | export |
+      {
+        let Export { name, item, .. } = export;
+        let level = level( export.namespace );
+        qt!{ ( #level, #name, #item ) }
+      });
+      let own_list = qt!
+      {
+        /// Names brought by records of the module.
+        const OWN : &[ Export ] = &
+        {
+          const LEN : usize =
+          {
+            let mut n = 0;
+            #( #own_cfgs { n += 1; } )*
+            n
+          };
+          let mut list : [ Export; LEN ] = [ ( 0, "", "" ); LEN ];
+          let mut i = 0;
+          #( #own_cfgs { list[ i ] = #own_entries; i += 1; } )*
+          list
+        };
+      };
+
+      if self.sources.is_empty()
+      {
+        return qt!
+        {
+          #[ doc( hidden ) ]
+          #[ allow( dead_code, unused_mut, unused_assignments ) ]
+          mod __mod_interface_exports
+          {
+            /// Name : the narrowest namespace, the name or `*` if unknown and absolute path to the item or empty if unknown.
+            type Export = ( u8, &'static str, &'static str );
+            #own_list
+            pub const EXPORTS : &[ Export ] = OWN;
+          }
+          #[ doc( hidden ) ]
+          pub use self::__mod_interface_exports::EXPORTS as __MOD_INTERFACE_EXPORTS;
+        };
+      }
+
+      let sources : Vec< _ > = self.sources.iter().enumerate().map( | ( i, source ) |
+      {
+        ( format_ident!( "SOURCE_{}", i ), format_ident!( "EXCEPT_{}", i ), source )
+      }).collect();
+
+      let source_lists = sources.iter().map( | ( list, except, source ) |
+      {
+        let Source { cfg, path, except : names, .. } = source;
+        qt!
+        {
+          #cfg
+          const #list : &[ Export ] =
+          {
+            // module defined manually has no list, its content is unknown
+            const __MOD_INTERFACE_EXPORTS : &[ Export ] = &[ ( 3, "*", "" ) ];
+            {
+              use #path::*;
+              __MOD_INTERFACE_EXPORTS
+            }
+          };
+          #cfg
+          const #except : &[ &str ] = &[ #( #names ),* ];
+        }
+      });
+
+      let appends : Vec< _ > = sources.iter().map( | ( list, except, source ) |
+      {
+        let cfg = &source.cfg;
+        ( qt!{ #cfg { n += count( #list, #except ); } }, qt!{ #cfg { ( list, i ) = append( list, i, #list, #except, false ); } } )
+      }).collect();
+      let ( counts, appends ) : ( Vec< _ >, Vec< _ > ) = appends.into_iter().unzip();
+
+      // records are compared pairwise, the error points at the latter record of a pair
+      let own_record = ( qt!{}, qt!{ OWN }, qt!{ &[] }, "a record of the module".to_string() );
+      let records : Vec< _ > = std::iter::once( own_record ).chain( sources.iter().map( | ( list, except, source ) |
+      {
+        ( source.cfg.clone(), qt!{ #list }, qt!{ #except }, source.description.clone() )
+      })).collect();
+      let mut checks = vec![];
+      for ( j, source ) in self.sources.iter().enumerate()
+      {
+        let ( cfg2, list2, except2, description2 ) = &records[ j + 1 ];
+        for ( cfg1, list1, except1, description1 ) in &records[ ..j + 1 ]
+        {
+          let prefix = "Name `";
+          let suffix = format!
+          (
+            "` is brought into namespaces of the module by {} and by {}, so it is ambiguous. Exclude it with `except {{ .. }}` or rename it",
+            description2,
+            description1,
+          );
+          checks.push( quote::quote_spanned!
+          { source.span =>
+            #cfg1
+            #cfg2
+            const _ : () = match collision( #list1, #except1, #list2, #except2 )
+            {
+              ::core::option::Option::Some( name ) =>
+              {
+                let ( buffer, len ) = message( &[ #prefix, name, #suffix ] );
+                match ::core::str::from_utf8( buffer.split_at( len ).0 )
+                {
+                  ::core::result::Result::Ok( message ) => ::core::panic!( "{}", message ),
+                  ::core::result::Result::Err( _ ) => ::core::panic!( "Name is brought into namespaces of the module by several records" ),
+                }
+              },
+              ::core::option::Option::None => (),
+            };
+          });
+        }
+      }
+
+      let warnings = sources.iter().filter( | _ | warn_unused_layers ).filter_map( | ( list, _, source ) |
+      {
+        let name = source.layer.as_ref()?;
+        let cfg = &source.cfg;
+        let note = format!( "Layer `{}` contributes nothing to namespaces of the module, its namespaces have no items to use", name );
+        let warning = format_ident!( "unused_layer_{}", name, span = name.span() );
+        // the warning is a call of a deprecated method chosen by the list of names of the layer
+        Some( quote::quote_spanned!
+        { name.span() =>
+          #cfg
+          const _ : () =
+          {
+            struct Layer< const CONTRIBUTES : bool >;
+            impl Layer< true >
+            {
+              const fn #warning( self ) {}
+            }
+            impl Layer< false >
+            {
+              #[ deprecated( note = #note ) ]
+              const fn #warning( self ) {}
+            }
+            Layer::< { !#list.is_empty() } >.#warning()
+          };
+        })
+      });
+
+      qt!
+      {
+        #[ doc( hidden ) ]
+        #[ allow( dead_code, unused_imports, unused_mut, unused_assignments ) ]
+        mod __mod_interface_exports
+        {
+          /// Name : the narrowest namespace, the name or `*` if unknown and absolute path to the item or empty if unknown.
+          type Export = ( u8, &'static str, &'static str );
+
+          #own_list
+          #( #source_lists )*
+
+          /// Number of names brought to parent.
+          const LEN : usize =
+          {
+            let mut n = OWN.len();
+            #( #counts )*
+            n
+          };
+
+          /// Names brought to parent : names of records and names of used modules, except namespace `orphan` of them,
+          /// which is brought only to namespace `protected` of the module.
+          pub const EXPORTS : &[ Export ] = &
+          {
+            let ( mut list, mut i ) = append( [ ( 0, "", "" ); LEN ], 0, OWN, &[], true );
+            #( #appends )*
+            list
+          };
+
+          #( #checks )*
+          #( #warnings )*
+
+          /// Is name brought to parent.
+          const fn brought( export : &Export, except : &[ &str ], own : bool ) -> bool
+          {
+            ( own || export.0 > 1 ) && !contains( except, export.1 )
+          }
+
+          const fn count( list : &[ Export ], except : &[ &str ] ) -> usize
+          {
+            let mut n = 0;
+            let mut i = 0;
+            while i < list.len()
+            {
+              if brought( &list[ i ], except, false )
+              {
+                n += 1;
+              }
+              i += 1;
+            }
+            n
+          }
+
+          const fn append
+          (
+            mut list : [ Export; LEN ],
+            mut i : usize,
+            source : &[ Export ],
+            except : &[ &str ],
+            own : bool,
+          ) -> ( [ Export; LEN ], usize )
+          {
+            let mut j = 0;
+            while j < source.len()
+            {
+              if brought( &source[ j ], except, own )
+              {
+                list[ i ] = source[ j ];
+                i += 1;
+              }
+              j += 1;
+            }
+            ( list, i )
+          }
+
+          /// Name brought by both records, unless it is the same item. Namespace `orphan` of a used module has all its names
+          /// and is brought into namespace `protected`, so the names are ambiguous there whatever namespaces bring them.
+          const fn collision< 'a >
+          (
+            list1 : &[ Export ],
+            except1 : &[ &str ],
+            list2 : &'a [ Export ],
+            except2 : &[ &str ],
+          ) -> ::core::option::Option< &'a str >
+          {
+            let mut i = 0;
+            while i < list1.len()
+            {
+              let mut j = 0;
+              while j < list2.len()
+              {
+                let ( a, b ) = ( &list1[ i ], &list2[ j ] );
+                if !eq( a.1, "*" ) && eq( a.1, b.1 ) && !( !a.2.is_empty() && eq( a.2, b.2 ) )
+                && !contains( except1, a.1 ) && !contains( except2, b.1 )
+                {
+                  return ::core::option::Option::Some( b.1 );
+                }
+                j += 1;
+              }
+              i += 1;
+            }
+            ::core::option::Option::None
+          }
+
+          const fn contains( list : &[ &str ], name : &str ) -> bool
+          {
+            let mut i = 0;
+            while i < list.len()
+            {
+              if eq( list[ i ], name )
+              {
+                return true;
+              }
+              i += 1;
+            }
+            false
+          }
+
+          const fn eq( a : &str, b : &str ) -> bool
+          {
+            let ( a, b ) = ( a.as_bytes(), b.as_bytes() );
+            if a.len() != b.len()
+            {
+              return false;
+            }
+            let mut i = 0;
+            while i < a.len()
+            {
+              if a[ i ] != b[ i ]
+              {
+                return false;
+              }
+              i += 1;
+            }
+            true
+          }
+
+          /// Message joined from parts, the compiler could not format it in constant.
+          const fn message( parts : &[ &str ] ) -> ( [ u8; 1024 ], usize )
+          {
+            let mut buffer = [ 0; 1024 ];
+            let mut len = 0;
+            let mut i = 0;
+            while i < parts.len()
+            {
+              let part = parts[ i ].as_bytes();
+              let mut j = 0;
+              while j < part.len() && len < buffer.len()
+              {
+                buffer[ len ] = part[ j ];
+                len += 1;
+                j += 1;
+              }
+              i += 1;
+            }
+            ( buffer, len )
+          }
+        }
+        #[ doc( hidden ) ]
+        pub use self::__mod_interface_exports::EXPORTS as __MOD_INTERFACE_EXPORTS;
+      }
+    }
+
+  }
+
+  /// Level of namespace : the wider namespace the lower level.
+  fn level( namespace : ClauseKind ) -> u8
+  {
+    match namespace
+    {
+      ClauseKind::Orphan => 1,
+      ClauseKind::Exposed => 2,
+      ClauseKind::Prelude => 3,
+      _ => 0,
+    }
+  }
+
+  /// Names introduced by the use tree with paths to the items, glob introduces unknown names `*`.
+  fn use_tree_items( tree : &syn::UseTree, prefix : String, items : &mut Vec< ( String, String ) > )
+  {
+    let join = | ident : &syn::Ident | if prefix.is_empty() { ident.to_string() } else { format!( "{}::{}", prefix, ident ) };
+    match tree
+    {
+      syn::UseTree::Path( e ) => use_tree_items( &e.tree, join( &e.ident ), items ),
+      syn::UseTree::Name( e ) if e.ident == "self" =>
+      {
+        let name = prefix.rsplit( "::" ).next().unwrap_or_default().to_string();
+        items.push( ( name, prefix.clone() ) );
+      },
+      syn::UseTree::Name( e ) => items.push( ( e.ident.to_string(), join( &e.ident ) ) ),
+      syn::UseTree::Rename( e ) => items.push( ( e.rename.to_string(), join( &e.ident ) ) ),
+      syn::UseTree::Glob( _ ) => items.push( ( "*".to_string(), String::new() ) ),
+      syn::UseTree::Group( e ) => e.items.iter().for_each( | tree | use_tree_items( tree, prefix.clone(), items ) ),
+    }
+  }
+
+  /// Attributes of conditional compilation.
+  pub fn cfg_attrs( attrs : &[ &AttributesOuter ] ) -> proc_macro2::TokenStream
+  {
+    let attrs = attrs.iter().flat_map( | attrs | attrs.iter() ).filter( | attr | attr.path.is_ident( "cfg" ) );
+    qt!{ #( #attrs )* }
+  }
+
+}
+
+#[ allow( unused_imports ) ]
+pub use protected::*;
+
+/// Protected namespace of the module.
+pub mod protected
+{
+  #[ allow( unused_imports ) ]
+  pub use super::orphan::*;
+}
+
+/// Parented namespace of the module.
+pub mod orphan
+{
+  #[ allow( unused_imports ) ]
+  pub use super::exposed::*;
+}
+
+/// Exposed namespace of the module.
+pub mod exposed
+{
+  #[ allow( unused_imports ) ]
+  pub use super::prelude::*;
+  #[ allow( unused_imports ) ]
+  pub use super::private::
+  {
+    Export,
+    Source,
+    Exports,
+    cfg_attrs,
+  };
+}
+
+/// Prelude to use essentials: `use my_module::prelude::*`.
+pub mod prelude
+{
+  #[ allow( unused_imports ) ]
+  pub use super::private::
+  {
+  };
+}
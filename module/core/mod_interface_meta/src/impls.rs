@@ -113,6 +113,105 @@ pub( crate ) mod private
   {
     pub has_debug : bool,
     pub clauses_map : &'clauses_map mut HashMap< ClauseKind , Vec< proc_macro2::TokenStream > >,
+    #[ cfg( feature = "map" ) ]
+    pub map : &'clauses_map mut ModuleMap,
+    pub exports : &'clauses_map mut Exports,
+    /// Number of private modules generated to exclude names.
+    pub excepts : usize,
+  }
+
+  ///
+  /// Source of a namespace without excluded names. A private module generated next to namespaces re-exports glob of the source
+  /// and shadows each excluded name by a private item, so glob of the private module brings everything except them.
+  /// If `check`, each excluded name is also used from the source, so a misspelled name is reported by the compiler.
+  ///
+
+  fn source_except
+  (
+    c : &'_ mut RecordContext< '_ >,
+    attrs : &[ &AttributesOuter ],
+    module : &proc_macro2::TokenStream,
+    except : &Except,
+    check : bool,
+  )
+  ->
+  proc_macro2::TokenStream
+  {
+    let filter = format_ident!( "__mod_interface_except_{}", c.excepts );
+    c.excepts += 1;
+    let names : Vec< _ > = except.names.iter().collect();
+    // a check gets span of the name, so the compiler points at the misspelled name
+    let checks = names.iter().filter( | _ | check ).map( | name |
+    {
+      qt!{ use #module::#name as _; }.into_iter().map( | mut token |
+      {
+        token.set_span( name.span() );
+        token
+      })
+      .collect::< proc_macro2::TokenStream >()
+    });
+    c.clauses_map.get_mut( &ClauseImmediates::Kind() ).unwrap().push( qt!
+    {
+      #( #attrs )*
+      #[ doc( hidden ) ]
+      #[ allow( non_camel_case_types, dead_code, unused_imports, unused_macros, unknown_lints, hidden_glob_reexports ) ]
+      mod #filter
+      {
+        #[ doc( inline ) ]
+        pub use #module::*;
+        macro_rules! __mod_interface_except { () => {} }
+        #(
+          struct #names;
+          use __mod_interface_except as #names;
+        )*
+        #( #checks )*
+      }
+    });
+    qt!{ super::#filter::* }
+  }
+
+  /// Uses of namespaces `orphan`, `exposed` and `prelude` of a layer : globs, filtered if some names are excluded.
+  fn layer_sources
+  (
+    c : &'_ mut RecordContext< '_ >,
+    attrs : &[ &AttributesOuter ],
+    source : &proc_macro2::TokenStream,
+    except : &Option< Except >,
+  )
+  ->
+  Vec< proc_macro2::TokenStream >
+  {
+    let idents : [ syn::Ident; 3 ] = [ parse_qt!{ orphan }, parse_qt!{ exposed }, parse_qt!{ prelude } ];
+    match except
+    {
+      // namespace `orphan` has all names of the layer, so only it checks excluded names
+      Some( except ) => idents.iter().map( | namespace |
+      {
+        source_except( c, attrs, &qt!{ #source::#namespace }, except, namespace == "orphan" )
+      }).collect(),
+      None => idents.iter().map( | namespace | qt!{ #source::#namespace::* } ).collect(),
+    }
+  }
+
+  /// Names excluded by `except`.
+  fn except_names( except : &Option< Except > ) -> Vec< String >
+  {
+    except.iter().flat_map( | except | except.names.iter() ).map( | name | name.to_string() ).collect()
+  }
+
+  /// Suffix of entries of the map describing excluded names.
+  #[ cfg( feature = "map" ) ]
+  fn except_str( except : &Option< Except > ) -> String
+  {
+    match except
+    {
+      Some( except ) =>
+      {
+        let names : Vec< String > = except.names.iter().map( | name | name.to_string() ).collect();
+        format!( " except {{ {} }}", names.join( ", " ) )
+      },
+      None => String::new(),
+    }
   }
 
   ///
@@ -152,6 +251,30 @@ pub( crate ) mod private
 
     // println!( "adjsuted_path : {}", qt!{ #adjsuted_path } );
 
+    #[ cfg( feature = "map" ) ]
+    {
+      let map_path = path_str( &adjsuted_path );
+      let map_attrs = attrs_strs( &[ attrs1 ] );
+      let map_except = except_str( &record.except );
+      c.map.entry_add( ClauseKind::Protected, "use", format!( "{}::orphan::*{}", map_path, map_except ), map_attrs.clone() );
+      c.map.entry_add( ClauseKind::Exposed, "use", format!( "{}::exposed::*{}", map_path, map_except ), map_attrs.clone() );
+      c.map.entry_add( ClauseKind::Prelude, "use", format!( "{}::prelude::*{}", map_path, map_except ), map_attrs );
+    }
+
+    c.exports.source_add( Source
+    {
+      cfg : cfg_attrs( &[ attrs1 ] ),
+      path : { let leading_colon = &path.leading_colon; qt!{ #leading_colon #adjsuted_path } },
+      except : except_names( &record.except ),
+      description : format!( "use of `{}`", qt!{ #path }.to_string().replace( ' ', "" ) ),
+      layer : None,
+      span : record.span(),
+    });
+
+    // namespaces of the layer are used by globs, filtered if some names are excluded
+    let sources = layer_sources( c, &[ attrs1 ], &qt!{ #adjsuted_path }, &record.except );
+    let ( source_orphan, source_exposed, source_prelude ) = ( &sources[ 0 ], &sources[ 1 ], &sources[ 2 ] );
+
     if let Some( rename ) = &path.rename
     {
       let pure_path = path.pure_without_super_path()?;
@@ -186,7 +309,7 @@ pub( crate ) mod private
       #[ doc( inline ) ]
       #[ allow( unused_imports ) ]
       #attrs1
-      pub use #adjsuted_path::orphan::*;
+      pub use #source_orphan;
     });
 
     // clauses_map.get_mut( &VisExposed::Kind() ).unwrap().push( qt!
@@ -196,7 +319,7 @@ pub( crate ) mod private
       #[ doc( inline ) ]
       #[ allow( unused_imports ) ]
       #attrs1
-      pub use #adjsuted_path::exposed::*;
+      pub use #source_exposed;
     });
 
     // clauses_map.get_mut( &VisPrelude::Kind() ).unwrap().push( qt!
@@ -206,7 +329,7 @@ pub( crate ) mod private
       #[ doc( inline ) ]
       #[ allow( unused_imports ) ]
       #attrs1
-      pub use #adjsuted_path::prelude::*;
+      pub use #source_prelude;
     });
 
     Ok( () )
@@ -250,6 +373,14 @@ pub( crate ) mod private
     // };
 
     let adjsuted_path = path.adjsuted_explicit_path();
+    #[ cfg( feature = "map" ) ]
+    c.map.entry_add
+    (
+      vis.kind(),
+      "use",
+      format!( "{}{}", path_str( &adjsuted_path ), except_str( &record.except ) ),
+      attrs_strs( &[ attrs1 ] ),
+    );
 
     let vis2 = if vis.restriction().is_some()
     {
@@ -262,12 +393,36 @@ pub( crate ) mod private
 
     // clauses_map.get_mut( &vis.kind() ).unwrap().push( qt!
     // clause!( clauses_map, vis.kind ).push( qt!
+    c.exports.use_add( &path.tree, path.leading_colon.is_some(), &[ attrs1 ], vis.kind() );
+
+    // glob is used either directly or filtered, if some names are excluded
+    let source = match &record.except
+    {
+      Some( except ) =>
+      {
+        if !path.glob
+        {
+          return Err( syn_err!
+          (
+            except,
+            "`except` is applicable only to glob, like `use module::* except {{ Name }}`:\n  {}",
+            qt!{ #record },
+          ));
+        }
+        let pure_path = path.pure_path()?;
+        let leading_colon = &path.leading_colon;
+        let module = if path.prefix_is_needed() { qt!{ super::private::#pure_path } } else { qt!{ #leading_colon #pure_path } };
+        source_except( c, &[ attrs1 ], &module, except, true )
+      },
+      None => qt!{ #adjsuted_path },
+    };
+
     c.clauses_map.get_mut( &vis.kind() ).unwrap().push( qt!
     {
       #[ doc( inline ) ]
       #[ allow( unused_imports ) ]
       #attrs1
-      #vis2 use #adjsuted_path;
+      #vis2 use #source;
     });
 
     Ok( () )
@@ -311,6 +466,26 @@ pub( crate ) mod private
       ));
     }
 
+    if let Some( except ) = &record.except
+    {
+      return Err( syn_err!
+      (
+        except,
+        "`except` is applicable only to layers and globs, micro module does not bring its namespaces:\n  {}",
+        qt!{ #record },
+      ));
+    }
+
+    if let Some( name ) = path.get_ident()
+    {
+      c.exports.mod_add( name, &[ attrs1, attrs2 ], record.vis.kind() );
+    }
+    #[ cfg( feature = "map" ) ]
+    {
+      c.map.child_add( "mod", path, &[ attrs1, attrs2 ] );
+      c.map.entry_add( record.vis.kind(), "mod", path_str( path ), attrs_strs( &[ attrs1, attrs2 ] ) );
+    }
+
     // println!( "clauses_map.contains_key( {} ) : {}", record.vis.kind(), clauses_map.contains_key( &record.vis.kind() ) );
     // let fixes_list = clauses_map.get_mut( &record.vis.kind() ).ok_or_else( || syn_err!( "Error!" ) )?;
     // clauses_map.get_mut( &record.vis.kind() ).unwrap().push( qt!
@@ -355,6 +530,26 @@ pub( crate ) mod private
       ));
     }
 
+    c.exports.source_add( Source
+    {
+      cfg : cfg_attrs( &[ attrs1, attrs2 ] ),
+      path : qt!{ super::#path },
+      except : except_names( &record.except ),
+      description : format!( "layer `{}`", qt!{ #path }.to_string().replace( ' ', "" ) ),
+      layer : path.segments.last().map( | segment | segment.ident.clone() ),
+      span : path.span(),
+    });
+    #[ cfg( feature = "map" ) ]
+    {
+      let map_path = path_str( path );
+      let map_attrs = attrs_strs( &[ attrs1, attrs2 ] );
+      let map_except = except_str( &record.except );
+      c.map.child_add( "layer", path, &[ attrs1, attrs2 ] );
+      c.map.entry_add( ClauseKind::Protected, "layer", format!( "{}::orphan::*{}", map_path, map_except ), map_attrs.clone() );
+      c.map.entry_add( ClauseKind::Exposed, "layer", format!( "{}::exposed::*{}", map_path, map_except ), map_attrs.clone() );
+      c.map.entry_add( ClauseKind::Prelude, "layer", format!( "{}::prelude::*{}", map_path, map_except ), map_attrs );
+    }
+
     // clauses_map.get_mut( &ClauseImmediates::Kind() ).unwrap().push( qt!
     // clause!( clauses_map, ClauseImmediates::Kind ).push( qt!
     c.clauses_map.get_mut( &ClauseImmediates::Kind() ).unwrap().push( qt!
@@ -364,6 +559,10 @@ pub( crate ) mod private
       pub mod #path;
     });
 
+    // namespaces of the layer are used by globs, filtered if some names are excluded
+    let sources = layer_sources( c, &[ attrs1, attrs2 ], &qt!{ super::#path }, &record.except );
+    let ( source_orphan, source_exposed, source_prelude ) = ( &sources[ 0 ], &sources[ 1 ], &sources[ 2 ] );
+
     // clauses_map.get_mut( &VisProtected::Kind() ).unwrap().push( qt!
     // clause!( clauses_map, VisProtected::Kind ).push( qt!
     c.clauses_map.get_mut( &VisProtected::Kind() ).unwrap().push( qt!
@@ -372,7 +571,7 @@ pub( crate ) mod private
       #[ allow( unused_imports ) ]
       #attrs1
       #attrs2
-      pub use super::#path::orphan::*;
+      pub use #source_orphan;
     });
 
     // clauses_map.get_mut( &VisExposed::Kind() ).unwrap().push( qt!
@@ -383,7 +582,7 @@ pub( crate ) mod private
       #[ allow( unused_imports ) ]
       #attrs1
       #attrs2
-      pub use super::#path::exposed::*;
+      pub use #source_exposed;
     });
 
     // clauses_map.get_mut( &VisPrelude::Kind() ).unwrap().push( qt!
@@ -394,7 +593,7 @@ pub( crate ) mod private
       #[ allow( unused_imports ) ]
       #attrs1
       #attrs2
-      pub use super::#path::prelude::*;
+      pub use #source_prelude;
     });
 
     Ok( () )
@@ -426,10 +625,17 @@ pub( crate ) mod private
 
     // zzz : test case with several attrs
 
+    #[ cfg( feature = "map" ) ]
+    let mut map = ModuleMap::new();
+    let mut exports = Exports::new();
     let mut record_context = RecordContext::< '_ >
     {
       has_debug,
       clauses_map : &mut clauses_map,
+      #[ cfg( feature = "map" ) ]
+      map : &mut map,
+      exports : &mut exports,
+      excepts : 0,
     };
 
     document.records.0.iter().try_for_each( | record |
@@ -475,6 +681,9 @@ pub( crate ) mod private
       Result::Ok( () )
     })?;
 
+    let exports_clause = exports.generate( document.has_warn_unused_layers() );
+    clauses_map.get_mut( &ClauseImmediates::Kind() ).unwrap().push( exports_clause );
+
     let immediates_clause = clauses_map.get( &ClauseImmediates::Kind() ).unwrap();
     let protected_clause = clauses_map.get( &VisProtected::Kind() ).unwrap();
     let orphan_clause = clauses_map.get( &VisOrphan::Kind() ).unwrap();
@@ -525,6 +734,24 @@ pub( crate ) mod private
 
     };
 
+    // the map is a by-product, failure to write it should not break compilation of the module
+    #[ cfg( feature = "map" ) ]
+    let result = match map.write()
+    {
+      Ok( () ) => result,
+      Err( note ) => qt!
+      {
+        #result
+        const _ : () =
+        {
+          #[ deprecated( note = #note ) ]
+          #[ allow( non_upper_case_globals ) ]
+          const module_map_is_not_written : () = ();
+          module_map_is_not_written
+        };
+      },
+    };
+
     if has_debug
     {
 
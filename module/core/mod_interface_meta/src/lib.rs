@@ -27,6 +27,11 @@ mod visibility;
 use visibility::exposed::*;
 mod use_tree;
 use use_tree::exposed::*;
+mod map;
+#[ allow( unused_imports ) ]
+use map::exposed::*;
+mod exports;
+use exports::exposed::*;
 
 ///
 /// Protocol of modularity unifying interface of a module and introducing layers.
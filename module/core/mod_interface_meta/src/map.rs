@@ -0,0 +1,300 @@
+/// Internal namespace.
+pub( crate ) mod private
+{
+  #[ cfg( feature = "map" ) ]
+  use crate::visibility::ClauseKind;
+  #[ cfg( feature = "map" ) ]
+  use macro_tools::exposed::*;
+  #[ cfg( feature = "map" ) ]
+  use std::path::{ Path, PathBuf };
+
+  ///
+  /// Element of a namespace of a module : what is re-exported there and by which record.
+  ///
+
+  #[ cfg( feature = "map" ) ]
+  #[ derive( Debug, Clone ) ]
+  pub struct MapEntry
+  {
+    /// Kind of the record : `layer`, `mod` or `use`.
+    pub kind : &'static str,
+    /// Path of what is re-exported relative to the module, like `layer_a::orphan::*`.
+    pub path : String,
+    /// Attributes of the record except documentation, like `#[cfg(feature = "x")]`.
+    pub attrs : Vec< String >,
+  }
+
+  ///
+  /// Child module declared by the module : layer or micro module.
+  ///
+
+  #[ cfg( feature = "map" ) ]
+  #[ derive( Debug, Clone ) ]
+  pub struct MapChild
+  {
+    /// Kind of the record : `layer` or `mod`.
+    pub kind : &'static str,
+    /// Name of the child module.
+    pub name : String,
+    /// File of the child module, the first existing of candidates or the first candidate if none exists.
+    pub file : String,
+    /// Attributes of the record except documentation.
+    pub attrs : Vec< String >,
+  }
+
+  ///
+  /// Map of namespaces of a module produced by a single call of `mod_interface!`.
+  /// Written at build time as JSON if feature `map` is enabled.
+  ///
+
+  #[ cfg( feature = "map" ) ]
+  #[ derive( Debug, Clone ) ]
+  pub struct ModuleMap
+  {
+    /// Name of the crate being compiled.
+    pub crate_name : String,
+    /// File in which `mod_interface!` is called.
+    pub file : String,
+    /// Line of the file at which `mod_interface!` is called.
+    pub line : usize,
+    /// Declared layers and micro modules.
+    pub children : Vec< MapChild >,
+    /// Entries of namespaces `protected`, `orphan`, `exposed`, `prelude`.
+    pub namespaces : Vec< ( ClauseKind, Vec< MapEntry > ) >,
+  }
+
+  #[ cfg( feature = "map" ) ]
+  impl ModuleMap
+  {
+
+    /// Map of the module in which the macro is called.
+    pub fn new() -> Self
+    {
+      let file = call_site_file();
+      let line = proc_macro::Span::call_site().line();
+      let crate_name = std::env::var( "CARGO_CRATE_NAME" ).unwrap_or_default();
+      let namespaces = vec!
+      [
+        ( ClauseKind::Protected, vec![] ),
+        ( ClauseKind::Orphan, vec![] ),
+        ( ClauseKind::Exposed, vec![] ),
+        ( ClauseKind::Prelude, vec![] ),
+      ];
+      Self { crate_name, file, line, children : vec![], namespaces }
+    }
+
+    /// Register what is re-exported by namespace of the kind.
+    pub fn entry_add( &mut self, kind : ClauseKind, entry_kind : &'static str, path : String, attrs : Vec< String > )
+    {
+      if let Some( ( _, entries ) ) = self.namespaces.iter_mut().find( | ( k, _ ) | *k == kind )
+      {
+        entries.push( MapEntry { kind : entry_kind, path, attrs } );
+      }
+    }
+
+    /// Register child module declared by the module, resolving its file.
+    pub fn child_add( &mut self, kind : &'static str, name : &syn::Path, attrs : &[ &AttributesOuter ] )
+    {
+      let name = path_str( name );
+      let file = child_file( Path::new( &self.file ), &name, path_attr( attrs ).as_deref() );
+      self.children.push( MapChild { kind, name, file, attrs : attrs_strs( attrs ) } );
+    }
+
+    /// Serialize the map into JSON.
+    pub fn to_json( &self ) -> String
+    {
+      let children : Vec< serde_json::Value > = self.children.iter().map( | child |
+      {
+        serde_json::json!({ "kind" : child.kind, "name" : child.name, "file" : child.file, "attrs" : child.attrs })
+      }).collect();
+      let namespaces : serde_json::Map< String, serde_json::Value > = self.namespaces.iter().map( | ( kind, entries ) |
+      {
+        let entries : Vec< serde_json::Value > = entries.iter().map( | entry |
+        {
+          serde_json::json!({ "kind" : entry.kind, "path" : entry.path, "attrs" : entry.attrs })
+        }).collect();
+        ( namespace_name( *kind ), serde_json::Value::from( entries ) )
+      }).collect();
+      serde_json::json!
+      ({
+        "crate" : self.crate_name,
+        "file" : self.file,
+        "line" : self.line,
+        "children" : children,
+        "namespaces" : namespaces,
+      })
+      .to_string()
+    }
+
+    /// Write JSON of the map into directory of maps. Each call of the macro has its own file named by crate, file and line of the call.
+    /// Returns description of the failure, if the map could not be written.
+    pub fn write( &self ) -> core::result::Result< (), String >
+    {
+      let dir = map_dir();
+      let name : String = format!( "{}-{}-{}", self.crate_name, self.file, self.line )
+      .chars()
+      .map( | c | if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' } )
+      .collect();
+      let path = dir.join( format!( "{}.json", name ) );
+      std::fs::create_dir_all( &dir )
+      .and_then( | _ | std::fs::write( &path, self.to_json() ) )
+      .map_err( | err | format!( "Failed to write module map to {:?} : {}", path, err ) )
+    }
+
+  }
+
+  /// Name of namespace in the map.
+  #[ cfg( feature = "map" ) ]
+  fn namespace_name( kind : ClauseKind ) -> String
+  {
+    format!( "{:?}", kind ).to_lowercase()
+  }
+
+  /// File in which the macro is called.
+  #[ cfg( feature = "map" ) ]
+  pub fn call_site_file() -> String
+  {
+    let span = proc_macro::Span::call_site();
+    span.local_file()
+    .map( | path | path.to_string_lossy().into_owned() )
+    .unwrap_or_else( || span.file() )
+  }
+
+  /// Path as it is written, without spaces around `::` and without leading `super::`.
+  #[ cfg( feature = "map" ) ]
+  pub fn path_str< T : quote::ToTokens >( path : &T ) -> String
+  {
+    let result = qt!{ #path }.to_string().replace( " :: ", "::" ).replace( ":: ", "::" ).replace( " ,", "," );
+    match result.strip_prefix( "super::" )
+    {
+      Some( stripped ) => stripped.to_string(),
+      None => result,
+    }
+  }
+
+  /// Attributes except documentation as text.
+  #[ cfg( feature = "map" ) ]
+  pub fn attrs_strs( attrs : &[ &AttributesOuter ] ) -> Vec< String >
+  {
+    attrs.iter()
+    .flat_map( | attrs | attrs.iter() )
+    .filter( | attr | !attr.path.is_ident( "doc" ) )
+    .map( | attr | qt!{ #attr }.to_string() )
+    .collect()
+  }
+
+  /// Value of attribute `#[ path = "..." ]` if any.
+  #[ cfg( feature = "map" ) ]
+  pub fn path_attr( attrs : &[ &AttributesOuter ] ) -> Option< String >
+  {
+    attrs.iter().flat_map( | attrs | attrs.iter() ).find_map( | attr |
+    {
+      match attr.parse_meta()
+      {
+        Ok( syn::Meta::NameValue( syn::MetaNameValue { path, lit : syn::Lit::Str( lit ), .. } ) ) if path.is_ident( "path" ) =>
+        Some( lit.value() ),
+        _ => None,
+      }
+    })
+  }
+
+  /// File of a child module the way the compiler looks for it.
+  #[ cfg( feature = "map" ) ]
+  pub fn child_file( parent : &Path, name : &str, explicit : Option< &str > ) -> String
+  {
+    let dir = parent.parent().map( Path::to_path_buf ).unwrap_or_default();
+    if let Some( explicit ) = explicit
+    {
+      return dir.join( explicit ).to_string_lossy().into_owned();
+    }
+    let is_mod_rs = matches!
+    (
+      parent.file_name().and_then( | name | name.to_str() ),
+      Some( "mod.rs" ) | Some( "lib.rs" ) | Some( "main.rs" )
+    );
+    let base = if is_mod_rs
+    {
+      dir
+    }
+    else
+    {
+      dir.join( parent.file_stem().unwrap_or_default() )
+    };
+    let candidates = [ base.join( format!( "{}.rs", name ) ), base.join( name ).join( "mod.rs" ) ];
+    let found = candidates.iter().find( | candidate | candidate.exists() ).unwrap_or( &candidates[ 0 ] );
+    found.to_string_lossy().into_owned()
+  }
+
+  /// Directory to write maps to : `$MOD_INTERFACE_MAP_DIR` if defined,
+  /// otherwise `mod_interface_map` inside of the closest `target` directory of the crate being compiled.
+  #[ cfg( feature = "map" ) ]
+  pub fn map_dir() -> PathBuf
+  {
+    if let Ok( dir ) = std::env::var( "MOD_INTERFACE_MAP_DIR" )
+    {
+      return PathBuf::from( dir );
+    }
+    let manifest_dir = PathBuf::from( std::env::var( "CARGO_MANIFEST_DIR" ).unwrap_or_default() );
+    let target = match std::env::var( "CARGO_TARGET_DIR" )
+    {
+      Ok( dir ) => manifest_dir.join( dir ),
+      Err( _ ) => manifest_dir.ancestors()
+      .map( | dir | dir.join( "target" ) )
+      .find( | dir | dir.is_dir() )
+      .unwrap_or_else( || manifest_dir.join( "target" ) ),
+    };
+    target.join( "mod_interface_map" )
+  }
+
+}
+
+#[ allow( unused_imports ) ]
+pub use protected::*;
+
+/// Protected namespace of the module.
+pub mod protected
+{
+  #[ allow( unused_imports ) ]
+  pub use super::orphan::*;
+}
+
+/// Parented namespace of the module.
+pub mod orphan
+{
+  #[ allow( unused_imports ) ]
+  pub use super::exposed::*;
+}
+
+/// Exposed namespace of the module.
+pub mod exposed
+{
+  #[ allow( unused_imports ) ]
+  pub use super::prelude::*;
+  #[ cfg( feature = "map" ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::private::
+  {
+    MapEntry,
+    MapChild,
+    ModuleMap,
+    attrs_strs,
+  };
+  #[ cfg( feature = "map" ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::private::
+  {
+    call_site_file,
+    path_str,
+    path_attr,
+    child_file,
+  };
+}
+
+/// Prelude to use essentials: `use my_module::prelude::*`.
+pub mod prelude
+{
+  #[ allow( unused_imports ) ]
+  pub use super::private::
+  {
+  };
+}
@@ -12,6 +12,7 @@ pub( crate ) mod private
   pub mod kw
   {
     super::syn::custom_keyword!( layer );
+    super::syn::custom_keyword!( except );
   }
 
   ///
@@ -74,6 +75,52 @@ pub( crate ) mod private
     }
   }
 
+  ///
+  /// Names excluded from what a glob brings : `except { Name1, Name2 }`.
+  ///
+
+  #[ derive( Debug, PartialEq, Eq, Clone ) ]
+  pub struct Except
+  {
+    pub token : kw::except,
+    pub brace : syn::token::Brace,
+    pub names : syn::punctuated::Punctuated< syn::Ident, syn::token::Comma >,
+  }
+
+  //
+
+  impl syn::parse::Parse for Except
+  {
+    fn parse( input : ParseStream< '_ > ) -> Result< Self >
+    {
+      let token = input.parse()?;
+      let input2;
+      let brace = syn::braced!( input2 in input );
+      let names = syn::punctuated::Punctuated::parse_terminated( &input2 )?;
+      if names.is_empty()
+      {
+        return Err( syn_err!( token, "Expects at least one name to exclude in `except {{ .. }}`" ) );
+      }
+      Ok( Except
+      {
+        token,
+        brace,
+        names,
+      })
+    }
+  }
+
+  //
+
+  impl quote::ToTokens for Except
+  {
+    fn to_tokens( &self, tokens : &mut proc_macro2::TokenStream )
+    {
+      self.token.to_tokens( tokens );
+      self.brace.surround( tokens, | tokens | self.names.to_tokens( tokens ) );
+    }
+  }
+
   ///
   /// Record.
   ///
@@ -86,6 +133,7 @@ pub( crate ) mod private
     pub element_type : ElementType,
     pub elements : syn::punctuated::Punctuated< Pair< AttributesOuter, syn::Path >, syn::token::Comma >,
     pub use_elements : Option< crate::UseTree >,
+    pub except : Option< Except >,
     pub semi : Option< syn::token::Semi >,
   }
 
@@ -127,6 +175,8 @@ pub( crate ) mod private
         },
       }
 
+      let except = if input.peek( kw::except ) { Some( input.parse()? ) } else { None };
+
       let lookahead = input.lookahead1();
       if !lookahead.peek( Token![ ; ] )
       {
@@ -141,6 +191,7 @@ pub( crate ) mod private
         element_type,
         elements,
         use_elements,
+        except,
         semi,
       })
 
@@ -158,6 +209,8 @@ pub( crate ) mod private
       self.vis.to_tokens( tokens );
       self.element_type.to_tokens( tokens );
       self.elements.to_tokens( tokens );
+      self.use_elements.to_tokens( tokens );
+      self.except.to_tokens( tokens );
       self.semi.to_tokens( tokens );
     }
   }
@@ -195,7 +248,7 @@ pub( crate ) mod private
         // code_print!( attr.tokens );
 
         let good = true
-          && code_export_str!( attr.path ) == "debug"
+          && [ "debug", "warn_unused_layers" ].contains( &code_export_str!( attr.path ).as_str() )
           && code_export_str!( attr.tokens ).is_empty()
         ;
 
@@ -222,6 +275,15 @@ pub( crate ) mod private
         code_export_str!( attr.path ) == "debug"
       })
     }
+    /// Does the thesis has inner attribute asking to warn about layers contributing nothing.
+    #[ allow ( dead_code ) ]
+    pub fn has_warn_unused_layers( &self ) -> bool
+    {
+      self.head.iter().any( | attr |
+      {
+        code_export_str!( attr.path ) == "warn_unused_layers"
+      })
+    }
   }
 
   //
@@ -280,6 +342,7 @@ pub mod exposed
   pub use super::private::
   {
     ElementType,
+    Except,
     Record,
     Records,
     Thesis,
@@ -11,9 +11,15 @@ pub mod number;
 /// Parse string.
 #[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
 pub mod parse_request;
+/// Typed schema of parsed request.
+#[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
+pub mod parse_schema;
 /// Spit string with a delimeter.
 #[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
 pub mod split;
+/// Split data read from a reader with a delimeter.
+#[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
+pub mod split_stream;
 
 // /// Set of modules.
 // pub( crate ) mod modules
@@ -43,8 +49,13 @@ pub mod protected
   pub use super::number::orphan::*;
   #[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
   pub use super::parse_request::orphan::*;
+  #[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
+  pub use super::parse_schema::orphan::*;
   #[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
   pub use super::split::orphan::*;
+  #[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::split_stream::orphan::*;
 }
 
 /// Parented namespace of the module.
@@ -66,8 +77,13 @@ pub mod exposed
   pub use super::number::exposed::*;
   #[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
   pub use super::parse_request::exposed::*;
+  #[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
+  pub use super::parse_schema::exposed::*;
   #[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
   pub use super::split::exposed::*;
+  #[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::split_stream::exposed::*;
 }
 
 /// Namespace of the module to include with `use module::*`.
@@ -83,6 +99,11 @@ pub mod prelude
   pub use super::number::prelude::*;
   #[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
   pub use super::parse_request::prelude::*;
+  #[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
+  pub use super::parse_schema::prelude::*;
   #[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
   pub use super::split::prelude::*;
+  #[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
+  #[ allow( unused_imports ) ]
+  pub use super::split_stream::prelude::*;
 }
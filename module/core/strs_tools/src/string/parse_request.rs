@@ -129,7 +129,7 @@ pub( crate ) mod private
   ///
 
   #[ allow( dead_code ) ]
-  #[ derive( Debug, Default, PartialEq, Eq ) ]
+  #[ derive( Debug, Default ) ]
   pub struct Request< 'a >
   {
     /// Original request string.
@@ -146,6 +146,185 @@ pub( crate ) mod private
     pub map : HashMap<String, OpType<String>>,
     /// All options maps of the commands in request.
     pub maps : Vec<HashMap<String, OpType<String>>>,
+    /// Offsets in bytes in the original request string of subjects of the commands.
+    pub subjects_offsets : Vec< usize >,
+    /// Offsets in bytes in the original request string of keys and of their values in maps of the commands.
+    pub maps_offsets : Vec< HashMap< String, ( usize, usize ) > >,
+  }
+
+  /// Offsets are not compared : they are given by the original string, which is compared.
+  impl PartialEq for Request< '_ >
+  {
+    fn eq( &self, other : &Self ) -> bool
+    {
+      self.original == other.original
+      && self.key_val_delimeter == other.key_val_delimeter
+      && self.commands_delimeter == other.commands_delimeter
+      && self.subject == other.subject
+      && self.subjects == other.subjects
+      && self.map == other.map
+      && self.maps == other.maps
+    }
+  }
+
+  impl Eq for Request< '_ > {}
+
+  ///
+  /// Reason why a request could not be written so that parsing gives the same request.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub enum FormatError
+  {
+    /// Numbers of subjects and maps differ.
+    Inconsistent,
+    /// Delimeter of commands is whitespace, so only one command could be written.
+    SeveralCommands,
+    /// Delimeter of pairs is whitespace, so options could not be written.
+    Options,
+    /// The command has neither subject nor options, parser drops such commands.
+    EmptyCommand( usize ),
+    /// The subject contains delimeter of pairs or all kinds of quotes.
+    Subject( String ),
+    /// The key is empty or contains whitespace, delimeters or quotes.
+    Key( String ),
+    /// The value contains all kinds of quotes or, being an element of array, has leading or trailing whitespace.
+    Value( String ),
+    /// Hash maps are not produced by parser.
+    Map,
+  }
+
+  impl core::fmt::Display for FormatError
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      match self
+      {
+        FormatError::Inconsistent => write!( f, "Numbers of subjects and maps of the request differ" ),
+        FormatError::SeveralCommands => write!( f, "Only one command could be written if delimeter of commands is whitespace" ),
+        FormatError::Options => write!( f, "Options could not be written if delimeter of pairs is whitespace" ),
+        FormatError::EmptyCommand( index ) => write!( f, "Command #{} has neither subject nor options", index ),
+        FormatError::Subject( subject ) => write!( f, "Subject `{}` could not be written", subject ),
+        FormatError::Key( key ) => write!( f, "Key `{}` could not be written", key ),
+        FormatError::Value( value ) => write!( f, "Value `{}` could not be written", value ),
+        FormatError::Map => write!( f, "Hash map could not be written" ),
+      }
+    }
+  }
+
+  impl std::error::Error for FormatError {}
+
+  impl Request< '_ >
+  {
+    /// Write the request in canonical form : commands joined by delimeter of commands, each is the subject followed by
+    /// options sorted by key. Strings which could be misread are quoted. Parsing of the result with the same delimeters
+    /// gives the same subjects and maps.
+    pub fn format( &self ) -> Result< String, FormatError >
+    {
+      if self.subjects.len() != self.maps.len()
+      {
+        return Err( FormatError::Inconsistent );
+      }
+      if self.subjects.len() > 1 && self.commands_delimeter.trim().is_empty()
+      {
+        return Err( FormatError::SeveralCommands );
+      }
+
+      let mut commands = vec![];
+      for ( index, ( subject, map ) ) in self.subjects.iter().zip( &self.maps ).enumerate()
+      {
+        if subject.is_empty() && map.is_empty()
+        {
+          return Err( FormatError::EmptyCommand( index ) );
+        }
+        if !map.is_empty() && self.key_val_delimeter.trim().is_empty()
+        {
+          return Err( FormatError::Options );
+        }
+
+        let mut parts = vec![];
+        if !subject.is_empty()
+        {
+          if !self.key_val_delimeter.trim().is_empty() && subject.contains( self.key_val_delimeter )
+          {
+            return Err( FormatError::Subject( subject.clone() ) );
+          }
+          let quoted = str_quote_maybe( subject, self.key_val_delimeter, self.commands_delimeter, false );
+          parts.push( quoted.ok_or_else( || FormatError::Subject( subject.clone() ) )? );
+        }
+
+        let mut keys = map.keys().collect::< Vec< _ > >();
+        keys.sort();
+        for key in keys
+        {
+          if key.is_empty()
+          || key.contains( char::is_whitespace )
+          || key.contains( self.key_val_delimeter )
+          || ( !self.commands_delimeter.trim().is_empty() && key.contains( self.commands_delimeter ) )
+          || key.contains( QUOTES )
+          {
+            return Err( FormatError::Key( key.clone() ) );
+          }
+          let value = map[ key ].format( self.key_val_delimeter, self.commands_delimeter )?;
+          parts.push( format!( "{}{}{}", key, self.key_val_delimeter, value ) );
+        }
+
+        commands.push( parts.join( " " ) );
+      }
+
+      Ok( commands.join( &format!( " {} ", self.commands_delimeter.trim() ) ) )
+    }
+  }
+
+  impl OpType< String >
+  {
+    /// Write the value of option : primitive is quoted if it could be misread, vector is written as array `[ a, b ]`.
+    pub fn format( &self, key_val_delimeter : &str, commands_delimeter : &str ) -> Result< String, FormatError >
+    {
+      match self
+      {
+        OpType::Primitive( value ) =>
+        {
+          str_quote_maybe( value, key_val_delimeter, commands_delimeter, false ).ok_or_else( || FormatError::Value( value.clone() ) )
+        },
+        OpType::Vector( vector ) =>
+        {
+          let mut elements = vec![];
+          for element in vector
+          {
+            if element.trim() != element
+            {
+              return Err( FormatError::Value( element.clone() ) );
+            }
+            let quoted = str_quote_maybe( element, key_val_delimeter, commands_delimeter, true );
+            elements.push( quoted.ok_or_else( || FormatError::Value( element.clone() ) )? );
+          }
+          Ok( format!( "[{}]", elements.join( ", " ) ) )
+        },
+        OpType::Map( _ ) => Err( FormatError::Map ),
+      }
+    }
+  }
+
+  ///
+  /// Quote the string if parser could misread it, using a kind of quote the string does not contain.
+  ///
+
+  fn str_quote_maybe( src : &str, key_val_delimeter : &str, commands_delimeter : &str, element : bool ) -> Option< String >
+  {
+    let contains = | delimeter : &str | !delimeter.is_empty() && src.contains( delimeter );
+    let misread = src.is_empty()
+    || src.contains( char::is_whitespace )
+    || src.contains( QUOTES )
+    || contains( key_val_delimeter )
+    || contains( commands_delimeter )
+    || src.starts_with( '[' ) && src.ends_with( ']' )
+    || element && ( src.contains( ',' ) || src.contains( '[' ) || src.contains( ']' ) );
+    if !misread
+    {
+      return Some( src.to_string() );
+    }
+    QUOTES.iter().find( | quote | !src.contains( **quote ) ).map( | quote | format!( "{}{}{}", quote, src, quote ) )
   }
 
   ///
@@ -260,42 +439,44 @@ pub( crate ) mod private
         return result;
       }
 
+      // pieces of the request are slices of the original string, so their offsets are known
+      let original = result.original;
       let commands =
       if self.commands_delimeter.trim().is_empty()
       {
-        vec![ self.src().to_string() ]
+        vec![ self.src() ]
       }
       else
       {
-        let iter = split()
-        .src( self.src() )
-        .delimeter( self.commands_delimeter() )
-        .quoting( self.quoting() )
-        .stripping( true )
-        .preserving_empty( false )
-        .preserving_delimeters( false )
-        .perform();
-        iter.map( String::from ).collect::< Vec< _ > >()
+        split_outside_quotes( self.src(), self.commands_delimeter(), self.quoting() )
+        .into_iter()
+        .map( str::trim )
+        .filter( | command | !command.is_empty() )
+        .collect::< Vec< _ > >()
       };
 
       for command in commands
       {
+        let command_offset = offset_in( original, command ).unwrap_or( 0 );
+        let offset_of = | part : &str | offset_in( original, part ).unwrap_or( command_offset );
+
         let mut map_entries;
         if self.key_val_delimeter.trim().is_empty()
         {
-          map_entries =  ( command.as_str(), None, "" );
+          map_entries =  ( command, None, "" );
         }
         else
         {
           map_entries = match command.split_once( self.key_val_delimeter )
           {
             Some( entries ) => ( entries.0, Some( self.key_val_delimeter ), entries.1 ),
-            None => ( command.as_str(), None, "" ),
+            None => ( command, None, "" ),
           };
         }
 
         let subject;
         let mut map : HashMap<String, OpType<String>> = HashMap::new();
+        let mut offsets : HashMap< String, ( usize, usize ) > = HashMap::new();
 
         if map_entries.1.is_some()
         {
@@ -307,26 +488,17 @@ pub( crate ) mod private
           subject = subject_and_key.0;
           map_entries.0 = subject_and_key.2;
 
-          let mut join = String::from( map_entries.0 );
-          join.push_str( map_entries.1.unwrap() );
-          join.push_str( map_entries.2 );
-
-          let mut splits = split()
-          .src( join.as_str() )
-          .delimeter( self.key_val_delimeter )
-          .stripping( false )
-          .quoting( self.quoting )
-          .preserving_empty( true )
-          .preserving_delimeters( true )
-          .preserving_quoting( true )
-          .perform()
-          .map( String::from ).collect::< Vec< _ > >();
-
+          let mut splits = vec![ map_entries.0 ];
+          for piece in split_outside_quotes( map_entries.2, self.key_val_delimeter, self.quoting )
+          {
+            splits.push( self.key_val_delimeter );
+            splits.push( piece );
+          }
 
           let mut pairs = vec![];
           for a in ( 0..splits.len() - 2 ).step_by( 2 )
           {
-            let mut right = splits[ a + 2 ].clone();
+            let mut right = splits[ a + 2 ];
 
             while a < ( splits.len() - 3 )
             {
@@ -338,36 +510,24 @@ pub( crate ) mod private
 
               if cuts.1.is_none()
               {
-                let mut joined = splits[ a + 2 ].clone();
-                joined.push_str( splits[ a + 3 ].as_str() );
-                joined.push_str( splits[ a + 4 ].as_str() );
+                let joined = span( command, splits[ a + 2 ], splits[ a + 4 ] );
 
                 splits[ a + 2 ] = joined;
-                right = splits[ a + 2 ].clone();
+                right = splits[ a + 2 ];
                 splits.remove( a + 3 );
                 splits.remove( a + 4 );
                 continue;
               }
 
-              splits[ a + 2 ] = cuts.2.to_string();
-              right = cuts.0.to_string();
+              splits[ a + 2 ] = cuts.2;
+              right = cuts.0;
               break;
             }
 
-            let left = splits[ a ].clone();
-            let right = right.trim().to_string();
-            if self.unquoting
-            {
-              if left.contains( '\"' ) || left.contains( '\'' ) || right.contains( '\"' ) || right.contains( '\'' )
-              {
-                unimplemented!( "not implemented" );
-              }
-              // left = str_unquote( left );
-              // right = str_unquote( right );
-            }
+            let left = splits[ a ];
+            let right = right.trim();
 
-            pairs.push( left );
-            pairs.push( right );
+            pairs.push( ( left, right ) );
           }
 
           /* */
@@ -379,27 +539,29 @@ pub( crate ) mod private
               return None;
             }
 
-            let splits = split()
-            .src( &src[ 1..src.len() - 1 ] )
-            .delimeter( "," )
-            .stripping( true )
-            .quoting( self.quoting )
-            .preserving_empty( false )
-            .preserving_delimeters( false )
-            .preserving_quoting( false )
-            .perform()
-            .map( | e | String::from( e ).trim().to_owned() ).collect::< Vec<String> >();
+            let splits = split_outside_quotes( &src[ 1..src.len() - 1 ], ",", self.quoting )
+            .iter()
+            .map( | e | e.trim() )
+            .filter( | e | !e.is_empty() )
+            .map( | e | str_unquote( e ).to_owned() )
+            .collect::< Vec< String > >();
 
             Some( splits )
           };
 
           /* */
 
-          for a in ( 0..pairs.len() - 1 ).step_by( 2 )
+          for ( left, right_str ) in pairs
           {
-            let left = &pairs[ a ];
-            let right_str = &pairs[ a + 1 ];
-            let mut right = OpType::Primitive( pairs[ a + 1 ].to_string() );
+            offsets.entry( left.to_string() ).or_insert( ( offset_of( left ), offset_of( right_str ) ) );
+            let mut right = if self.unquoting
+            {
+              OpType::Primitive( str_unquote( right_str ).to_string() )
+            }
+            else
+            {
+              OpType::Primitive( right_str.to_string() )
+            };
 
             if self.parsing_arrays
             {
@@ -432,14 +594,9 @@ pub( crate ) mod private
           subject = map_entries.0;
         }
 
-        if self.unquoting
-        {
-          if subject.contains( '\"' ) || subject.contains( '\'' )
-          {
-            unimplemented!( "not implemented" );
-          }
-          // subject = _.strUnquote( subject );
-        }
+        // empty subject is not a slice of the command
+        let subject_offset = if subject.is_empty() { command_offset } else { offset_of( subject ) };
+        let subject = if self.unquoting { str_unquote( subject ) } else { subject };
 
         if self.subject_win_paths_maybe
         {
@@ -449,6 +606,8 @@ pub( crate ) mod private
 
         result.subjects.push( subject.to_string() );
         result.maps.push( map );
+        result.subjects_offsets.push( subject_offset );
+        result.maps_offsets.push( offsets );
       }
 
       if !result.subjects.is_empty()
@@ -464,6 +623,81 @@ pub( crate ) mod private
     }
   }
 
+  ///
+  /// Split the string by the delimeter, which is skipped inside of quotes. Pieces are not stripped and quotes are kept.
+  ///
+
+  fn split_outside_quotes< 'b >( src : &'b str, delimeter : &str, quoting : bool ) -> Vec< &'b str >
+  {
+    // pieces as ranges of the source, an empty piece is placed right after its delimeter
+    let mut pieces = vec![ ( 0, 0 ) ];
+    let iter = split()
+    .src( src )
+    .delimeter( delimeter )
+    .quoting( quoting )
+    .stripping( false )
+    .preserving_empty( true )
+    .preserving_delimeters( true )
+    .preserving_quoting( true )
+    .perform();
+    for e in iter
+    {
+      let Some( start ) = offset_in( src, e.string ) else { continue };
+      let end = start + e.string.len();
+      match e.typ
+      {
+        SplitType::Delimeter => pieces.push( ( end, end ) ),
+        SplitType::Delimeted if !e.string.is_empty() =>
+        {
+          let piece = pieces.last_mut().unwrap();
+          if piece.0 == piece.1
+          {
+            piece.0 = start;
+          }
+          piece.1 = end;
+        },
+        SplitType::Delimeted => {},
+      }
+    }
+    pieces.into_iter().map( | ( start, end ) | &src[ start..end ] ).collect()
+  }
+
+  /// Offset in bytes of the part in the whole string, if the part is a slice of it.
+  fn offset_in( whole : &str, part : &str ) -> Option< usize >
+  {
+    let start = ( part.as_ptr() as usize ).checked_sub( whole.as_ptr() as usize )?;
+    ( start + part.len() <= whole.len() ).then_some( start )
+  }
+
+  /// Slice of the whole string from the beginning of the first part to the end of the last part.
+  fn span< 'b >( whole : &'b str, first : &'b str, last : &'b str ) -> &'b str
+  {
+    match ( offset_in( whole, first ), offset_in( whole, last ) )
+    {
+      ( Some( start ), Some( end ) ) if start <= end => &whole[ start..end + last.len() ],
+      _ => first,
+    }
+  }
+
+  ///
+  /// Remove quotes around the string if it starts and ends with the same quote.
+  ///
+
+  pub( crate ) fn str_unquote( src : &str ) -> &str
+  {
+    for quote in QUOTES
+    {
+      if src.len() >= 2 && src.starts_with( quote ) && src.ends_with( quote )
+      {
+        return &src[ 1..src.len() - 1 ];
+      }
+    }
+    src
+  }
+
+  /// Quotes recognized by parser.
+  const QUOTES : [ char; 3 ] = [ '"', '\'', '`' ];
+
   ///
   /// Function to parse a string with command request.
   ///
@@ -489,6 +723,7 @@ pub mod protected
   {
     OpType,
     Request,
+    FormatError,
     ParseOptions,
     ParseOptionsAdapter,
     request_parse,
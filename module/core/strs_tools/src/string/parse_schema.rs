@@ -0,0 +1,735 @@
+/// Internal namespace.
+pub( crate ) mod private
+{
+  use crate::string::split::split;
+  use crate::string::parse_request::{ OpType, Request };
+  use crate::string::parse_request::private::str_unquote;
+  use std::collections::HashMap;
+  use std::path::PathBuf;
+
+  ///
+  /// Type of value of a key.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub enum ValueType
+  {
+    /// Any string.
+    String,
+    /// Signed integer number.
+    Int,
+    /// Floating point number.
+    Float,
+    /// Boolean : `true`, `false`, `1` or `0`.
+    Bool,
+    /// Non-empty path.
+    Path,
+    /// Array of values of the type, like `[ a, b ]`. A single value is a list of one element.
+    List( Box< ValueType > ),
+    /// One of the strings.
+    Enum( Vec< String > ),
+  }
+
+  impl core::fmt::Display for ValueType
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      match self
+      {
+        ValueType::String => write!( f, "string" ),
+        ValueType::Int => write!( f, "int" ),
+        ValueType::Float => write!( f, "float" ),
+        ValueType::Bool => write!( f, "bool" ),
+        ValueType::Path => write!( f, "path" ),
+        ValueType::List( element ) => write!( f, "list of {}", element ),
+        ValueType::Enum( variants ) =>
+        {
+          let variants = variants.iter().map( | variant | format!( "`{}`", variant ) ).collect::< Vec< _ > >();
+          write!( f, "one of {}", variants.join( ", " ) )
+        },
+      }
+    }
+  }
+
+  ///
+  /// Typed value of a key.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub enum Value
+  {
+    /// Any string.
+    String( String ),
+    /// Signed integer number.
+    Int( i64 ),
+    /// Floating point number.
+    Float( f64 ),
+    /// Boolean.
+    Bool( bool ),
+    /// Path.
+    Path( PathBuf ),
+    /// Array of values.
+    List( Vec< Value > ),
+    /// One of variants of the enum.
+    Enum( String ),
+  }
+
+  impl ValueType
+  {
+    /// Parse text of a single value, quotes around the text are removed.
+    pub fn parse( &self, src : &str ) -> Option< Value >
+    {
+      let src = str_unquote( src );
+      match self
+      {
+        ValueType::String => Some( Value::String( src.to_string() ) ),
+        ValueType::Int => src.parse().ok().map( Value::Int ),
+        ValueType::Float => src.parse().ok().map( Value::Float ),
+        ValueType::Bool => match src
+        {
+          "true" | "1" => Some( Value::Bool( true ) ),
+          "false" | "0" => Some( Value::Bool( false ) ),
+          _ => None,
+        },
+        ValueType::Path => if src.is_empty() { None } else { Some( Value::Path( PathBuf::from( src ) ) ) },
+        ValueType::List( element ) => element.parse( src ).map( | value | Value::List( vec![ value ] ) ),
+        ValueType::Enum( variants ) => variants.iter().find( | variant | *variant == src ).map( | variant | Value::Enum( variant.clone() ) ),
+      }
+    }
+
+    /// The same type with the innermost type replaced by enum of the variants, like list of strings by list of variants.
+    pub fn enumerate( self, variants : Vec< String > ) -> Self
+    {
+      match self
+      {
+        ValueType::List( element ) => ValueType::List( Box::new( element.enumerate( variants ) ) ),
+        _ => ValueType::Enum( variants ),
+      }
+    }
+  }
+
+  ///
+  /// Expected key of a request.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct Key
+  {
+    /// Name of the key.
+    pub name : String,
+    /// Type of the value.
+    pub typ : ValueType,
+    /// Text of default value, parsed the same way as the value in a request.
+    pub default : Option< String >,
+    /// Is the key required if it has no default.
+    pub required : bool,
+  }
+
+  impl Key
+  {
+    /// Required key without default.
+    pub fn new< S : Into< String > >( name : S, typ : ValueType ) -> Self
+    {
+      Self { name : name.into(), typ, default : None, required : true }
+    }
+
+    /// Set text of default value.
+    pub fn default< S : Into< String > >( mut self, default : S ) -> Self
+    {
+      self.default = Some( default.into() );
+      self
+    }
+
+    /// Make the key optional.
+    pub fn optional( mut self, optional : bool ) -> Self
+    {
+      self.required = !optional;
+      self
+    }
+  }
+
+  ///
+  /// Kind of error of applying schema.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub enum SchemaErrorKind
+  {
+    /// The key is not declared in the schema.
+    UnknownKey,
+    /// The required key is absent.
+    MissingKey,
+    /// The value could not be parsed as the type.
+    InvalidValue
+    {
+      /// Expected type.
+      expected : ValueType,
+      /// Text of the value.
+      value : String,
+    },
+    /// Default of the key could not be parsed as its type, that's a mistake in the schema.
+    InvalidDefault
+    {
+      /// Expected type.
+      expected : ValueType,
+      /// Text of the default.
+      value : String,
+    },
+  }
+
+  ///
+  /// Error of applying schema to a request, which points at offset in bytes in the original request string.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct SchemaError
+  {
+    /// Kind of the error.
+    pub kind : SchemaErrorKind,
+    /// Name of the key, the subject has name of its key in the schema.
+    pub key : String,
+    /// Offset in bytes in the original request string.
+    pub offset : usize,
+  }
+
+  impl core::fmt::Display for SchemaError
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      match &self.kind
+      {
+        SchemaErrorKind::UnknownKey => write!( f, "Unknown key `{}` at {}", self.key, self.offset ),
+        SchemaErrorKind::MissingKey => write!( f, "Missing key `{}` at {}", self.key, self.offset ),
+        SchemaErrorKind::InvalidValue { expected, value } =>
+        write!( f, "Invalid value `{}` of key `{}` at {}. Expects {}", value, self.key, self.offset, expected ),
+        SchemaErrorKind::InvalidDefault { expected, value } =>
+        write!( f, "Invalid default `{}` of key `{}`. Expects {}", value, self.key, expected ),
+      }
+    }
+  }
+
+  impl std::error::Error for SchemaError {}
+
+  ///
+  /// Parsed value with its position.
+  ///
+
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct Located
+  {
+    /// The value.
+    pub value : Value,
+    /// Text of the value in the request, or the default.
+    pub text : String,
+    /// Offset in bytes of the value in the original request string.
+    pub offset : usize,
+  }
+
+  ///
+  /// Values of a command checked by a schema.
+  ///
+
+  #[ derive( Debug, Clone, Default, PartialEq ) ]
+  pub struct Values
+  {
+    /// Parsed subject if the schema has key of subject and the subject is not empty.
+    pub subject : Option< Located >,
+    /// Parsed values of keys, either from the request or defaults.
+    pub map : HashMap< String, Located >,
+  }
+
+  impl Values
+  {
+    /// Value of the key.
+    pub fn get( &self, key : &str ) -> Option< &Value >
+    {
+      self.map.get( key ).map( | located | &located.value )
+    }
+
+    /// Take value of the key converted to the type. Returns `None` and collects error if the value does not fit the type.
+    pub fn take< T : FromValue >( &mut self, key : &str, errors : &mut Vec< SchemaError > ) -> Option< T >
+    {
+      let located = self.map.remove( key );
+      convert( key, located, errors )
+    }
+
+    /// Take subject converted to the type. Returns `None` and collects error if the subject does not fit the type.
+    pub fn take_subject< T : FromValue >( &mut self, key : &str, errors : &mut Vec< SchemaError > ) -> Option< T >
+    {
+      let located = self.subject.take();
+      convert( key, located, errors )
+    }
+  }
+
+  fn convert< T : FromValue >( key : &str, located : Option< Located >, errors : &mut Vec< SchemaError > ) -> Option< T >
+  {
+    let ( text, offset ) = match &located
+    {
+      Some( located ) => ( located.text.clone(), located.offset ),
+      None => ( String::new(), 0 ),
+    };
+    let result = T::from_value( located.map( | located | located.value ) );
+    if result.is_none()
+    {
+      errors.push( SchemaError
+      {
+        kind : SchemaErrorKind::InvalidValue { expected : T::value_type(), value : text },
+        key : key.to_string(),
+        offset,
+      });
+    }
+    result
+  }
+
+  ///
+  /// Schema of a command of request : expected keys with types and defaults and, optionally, type of the subject.
+  ///
+
+  #[ derive( Debug, Clone, Default, PartialEq ) ]
+  pub struct Schema
+  {
+    /// Expected keys.
+    pub keys : Vec< Key >,
+    /// Key of the subject, if the subject should be parsed.
+    pub subject : Option< Key >,
+  }
+
+  impl Schema
+  {
+    /// Schema without keys.
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Declare a key.
+    pub fn key( mut self, key : Key ) -> Self
+    {
+      self.keys.push( key );
+      self
+    }
+
+    /// Declare type of the subject.
+    pub fn subject( mut self, key : Key ) -> Self
+    {
+      self.subject = Some( key );
+      self
+    }
+
+    /// Check and parse values of the command of the request. Returns all errors found.
+    pub fn apply( &self, request : &Request< '_ >, command : usize ) -> Result< Values, Vec< SchemaError > >
+    {
+      let empty = HashMap::new();
+      let map = request.maps.get( command ).unwrap_or( &empty );
+      let subject = request.subjects.get( command ).map( String::as_str ).unwrap_or( "" );
+      let empty_offsets = HashMap::new();
+      let offsets = request.maps_offsets.get( command ).unwrap_or( &empty_offsets );
+      let ( start, end ) = command_span( request, command );
+
+      let mut values = Values::default();
+      let mut errors = vec![];
+
+      let mut keys = map.keys().collect::< Vec< _ > >();
+      keys.sort_by_key( | key | offsets.get( *key ).map( | ( k, _ ) | *k ) );
+      for key in keys
+      {
+        if !self.keys.iter().any( | k | &k.name == key )
+        {
+          let offset = offsets.get( key ).map_or( start, | ( k, _ ) | *k );
+          errors.push( SchemaError { kind : SchemaErrorKind::UnknownKey, key : key.clone(), offset } );
+        }
+      }
+
+      for key in &self.keys
+      {
+        let offset = offsets.get( &key.name ).map_or( start, | ( _, v ) | *v );
+        let parsed = match map.get( &key.name )
+        {
+          Some( op ) => parse_op( &key.typ, op ).map_err( | text | SchemaErrorKind::InvalidValue { expected : key.typ.clone(), value : text } ),
+          None => match &key.default
+          {
+            Some( default ) => key.typ.parse_list( default ).map( | value | ( value, default.clone() ) )
+            .ok_or_else( || SchemaErrorKind::InvalidDefault { expected : key.typ.clone(), value : default.clone() } ),
+            None =>
+            {
+              if key.required
+              {
+                errors.push( SchemaError { kind : SchemaErrorKind::MissingKey, key : key.name.clone(), offset : end } );
+              }
+              continue;
+            },
+          },
+        };
+        match parsed
+        {
+          Ok( ( value, text ) ) => { values.map.insert( key.name.clone(), Located { value, text, offset } ); },
+          Err( kind ) => errors.push( SchemaError { kind, key : key.name.clone(), offset } ),
+        }
+      }
+
+      if let Some( key ) = &self.subject
+      {
+        let offset = request.subjects_offsets.get( command ).copied().unwrap_or( start );
+        if subject.is_empty()
+        {
+          if key.required && key.default.is_none()
+          {
+            errors.push( SchemaError { kind : SchemaErrorKind::MissingKey, key : key.name.clone(), offset : start } );
+          }
+          else if let Some( default ) = &key.default
+          {
+            match key.typ.parse_list( default )
+            {
+              Some( value ) => values.subject = Some( Located { value, text : default.clone(), offset } ),
+              None => errors.push( SchemaError
+              {
+                kind : SchemaErrorKind::InvalidDefault { expected : key.typ.clone(), value : default.clone() },
+                key : key.name.clone(),
+                offset,
+              }),
+            }
+          }
+        }
+        else
+        {
+          match key.typ.parse_list( subject )
+          {
+            Some( value ) => values.subject = Some( Located { value, text : subject.to_string(), offset } ),
+            None => errors.push( SchemaError
+            {
+              kind : SchemaErrorKind::InvalidValue { expected : key.typ.clone(), value : subject.to_string() },
+              key : key.name.clone(),
+              offset,
+            }),
+          }
+        }
+      }
+
+      if errors.is_empty()
+      {
+        Ok( values )
+      }
+      else
+      {
+        errors.sort_by_key( | error | error.offset );
+        Err( errors )
+      }
+    }
+  }
+
+  impl ValueType
+  {
+    /// Parse text of a value which could be an array `[ a, b ]` if the type is a list.
+    fn parse_list( &self, src : &str ) -> Option< Value >
+    {
+      match self
+      {
+        ValueType::List( element ) =>
+        {
+          let src = src.trim();
+          if src.starts_with( '[' ) && src.ends_with( ']' )
+          {
+            split()
+            .src( &src[ 1..src.len() - 1 ] )
+            .delimeter( "," )
+            .stripping( true )
+            .quoting( true )
+            .preserving_empty( false )
+            .preserving_delimeters( false )
+            .perform()
+            .map( | e | element.parse( String::from( e ).trim() ) )
+            .collect::< Option< Vec< _ > > >()
+            .map( Value::List )
+          }
+          else
+          {
+            self.parse( src )
+          }
+        },
+        _ => self.parse( src ),
+      }
+    }
+  }
+
+  /// Parse value of a request. Returns the value with its text or the text which could not be parsed.
+  fn parse_op( typ : &ValueType, op : &OpType< String > ) -> Result< ( Value, String ), String >
+  {
+    match ( typ, op )
+    {
+      ( _, OpType::Primitive( src ) ) => typ.parse_list( src ).map( | value | ( value, src.clone() ) ).ok_or_else( || src.clone() ),
+      ( ValueType::List( element ), OpType::Vector( srcs ) ) =>
+      {
+        let text = format!( "[{}]", srcs.join( "," ) );
+        let mut list = vec![];
+        for src in srcs
+        {
+          match element.parse( src )
+          {
+            Some( value ) => list.push( value ),
+            None => return Err( src.clone() ),
+          }
+        }
+        Ok( ( Value::List( list ), text ) )
+      },
+      ( _, OpType::Vector( srcs ) ) => Err( format!( "[{}]", srcs.join( "," ) ) ),
+      ( _, OpType::Map( _ ) ) => Err( String::new() ),
+    }
+  }
+
+  /// Span of the command in the original request string, the same commands are produced by parser.
+  fn command_span( request : &Request< '_ >, command : usize ) -> ( usize, usize )
+  {
+    let original = request.original;
+    let trimmed = original.trim();
+    let base = trimmed.as_ptr() as usize - original.as_ptr() as usize;
+    if request.commands_delimeter.trim().is_empty()
+    {
+      return ( base, base + trimmed.len() );
+    }
+    split()
+    .src( trimmed )
+    .delimeter( request.commands_delimeter )
+    .quoting( true )
+    .stripping( true )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .perform()
+    .nth( command )
+    .map( | e |
+    {
+      let start = e.string.as_ptr() as usize - original.as_ptr() as usize;
+      ( start, start + e.string.len() )
+    })
+    .unwrap_or( ( original.len(), original.len() ) )
+  }
+
+  ///
+  /// Conversion of a value checked by schema into a field of a record.
+  ///
+
+  pub trait FromValue : Sized
+  {
+    /// Type of value of the key.
+    fn value_type() -> ValueType;
+    /// Is absence of the key allowed.
+    fn optional() -> bool
+    {
+      false
+    }
+    /// Convert the value, which is `None` if the key is absent. Returns `None` if the value does not fit.
+    fn from_value( value : Option< Value > ) -> Option< Self >;
+  }
+
+  macro_rules! impl_from_value_int
+  {
+    ( $( $Int : ty ),* ) =>
+    {
+      $(
+        impl FromValue for $Int
+        {
+          fn value_type() -> ValueType
+          {
+            ValueType::Int
+          }
+          fn from_value( value : Option< Value > ) -> Option< Self >
+          {
+            match value?
+            {
+              Value::Int( value ) => value.try_into().ok(),
+              _ => None,
+            }
+          }
+        }
+      )*
+    };
+  }
+
+  impl_from_value_int!( i8, i16, i32, i64, isize, u8, u16, u32, u64, usize );
+
+  impl FromValue for f64
+  {
+    fn value_type() -> ValueType
+    {
+      ValueType::Float
+    }
+    fn from_value( value : Option< Value > ) -> Option< Self >
+    {
+      match value?
+      {
+        Value::Float( value ) => Some( value ),
+        Value::Int( value ) => Some( value as f64 ),
+        _ => None,
+      }
+    }
+  }
+
+  impl FromValue for f32
+  {
+    fn value_type() -> ValueType
+    {
+      ValueType::Float
+    }
+    fn from_value( value : Option< Value > ) -> Option< Self >
+    {
+      f64::from_value( value ).map( | value | value as f32 )
+    }
+  }
+
+  impl FromValue for bool
+  {
+    fn value_type() -> ValueType
+    {
+      ValueType::Bool
+    }
+    fn from_value( value : Option< Value > ) -> Option< Self >
+    {
+      match value?
+      {
+        Value::Bool( value ) => Some( value ),
+        _ => None,
+      }
+    }
+  }
+
+  impl FromValue for String
+  {
+    fn value_type() -> ValueType
+    {
+      ValueType::String
+    }
+    fn from_value( value : Option< Value > ) -> Option< Self >
+    {
+      match value?
+      {
+        Value::String( value ) | Value::Enum( value ) => Some( value ),
+        _ => None,
+      }
+    }
+  }
+
+  impl FromValue for PathBuf
+  {
+    fn value_type() -> ValueType
+    {
+      ValueType::Path
+    }
+    fn from_value( value : Option< Value > ) -> Option< Self >
+    {
+      match value?
+      {
+        Value::Path( value ) => Some( value ),
+        _ => None,
+      }
+    }
+  }
+
+  impl< T : FromValue > FromValue for Vec< T >
+  {
+    fn value_type() -> ValueType
+    {
+      ValueType::List( Box::new( T::value_type() ) )
+    }
+    fn from_value( value : Option< Value > ) -> Option< Self >
+    {
+      match value?
+      {
+        Value::List( list ) => list.into_iter().map( | value | T::from_value( Some( value ) ) ).collect(),
+        _ => None,
+      }
+    }
+  }
+
+  impl< T : FromValue > FromValue for Option< T >
+  {
+    fn value_type() -> ValueType
+    {
+      T::value_type()
+    }
+    fn optional() -> bool
+    {
+      true
+    }
+    fn from_value( value : Option< Value > ) -> Option< Self >
+    {
+      match value
+      {
+        Some( value ) => T::from_value( Some( value ) ).map( Some ),
+        None => Some( None ),
+      }
+    }
+  }
+
+  ///
+  /// Typed record of a command of request. Implement it with derive `FromRequest` of feature `string_parse_request_derive`.
+  ///
+
+  pub trait FromRequest : Sized
+  {
+    /// Schema of the record.
+    fn schema() -> Schema;
+    /// Make the record from values checked by the schema.
+    fn from_values( values : Values ) -> Result< Self, Vec< SchemaError > >;
+
+    /// Make the record from the first command of the request.
+    fn from_request( request : &Request< '_ > ) -> Result< Self, Vec< SchemaError > >
+    {
+      Self::from_command( request, 0 )
+    }
+
+    /// Make the record from the command of the request.
+    fn from_command( request : &Request< '_ >, command : usize ) -> Result< Self, Vec< SchemaError > >
+    {
+      Self::from_values( Self::schema().apply( request, command )? )
+    }
+  }
+
+}
+
+#[ doc( inline ) ]
+#[ allow( unused_imports ) ]
+pub use protected::*;
+
+/// Protected namespace of the module.
+pub mod protected
+{
+  #[ allow( unused_imports ) ]
+  pub use super::orphan::*;
+  pub use super::private::
+  {
+    ValueType,
+    Value,
+    Key,
+    SchemaErrorKind,
+    SchemaError,
+    Located,
+    Values,
+    Schema,
+    FromValue,
+    FromRequest,
+  };
+  #[ cfg( feature = "string_parse_request_derive" ) ]
+  pub use ::strs_tools_meta::FromRequest;
+}
+
+/// Parented namespace of the module.
+pub mod orphan
+{
+  pub use super::exposed::*;
+}
+
+/// Exposed namespace of the module.
+pub mod exposed
+{
+  pub use super::private::
+  {
+    FromRequest,
+  };
+  #[ cfg( feature = "string_parse_request_derive" ) ]
+  pub use ::strs_tools_meta::FromRequest;
+}
+
+/// Namespace of the module to include with `use module::*`.
+pub mod prelude
+{
+  pub use super::private::FromRequest;
+}
@@ -2,18 +2,21 @@
 pub( crate ) mod private
 {
 
+  use core::borrow::Borrow;
+  use core::marker::PhantomData;
   use crate::string::parse_request::OpType;
 
   ///
   /// Either delimeter or delimeted with the slice on its string.
   ///
 
-  #[allow(dead_code)]
   #[ derive( Debug ) ]
   pub struct Split< 'a >
   {
-    string : &'a str,
-    typ : SplitType,
+    /// The slice.
+    pub string : &'a str,
+    /// Either delimeter or delimeted.
+    pub typ : SplitType,
   }
 
   impl< 'a > From< Split< 'a > > for String
@@ -28,7 +31,7 @@ pub( crate ) mod private
   /// Either delimeter or delimeted
   ///
 
-  #[ derive( Debug ) ]
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
   pub enum SplitType
   {
     /// Substring of the original string with text inbetween delimeters.
@@ -64,11 +67,19 @@ pub( crate ) mod private
   }
 
   impl Searcher for Vec<&str>
+  {
+    fn pos( &self, src : &str ) -> Option< ( usize, usize ) >
+    {
+      self.as_slice().pos( src )
+    }
+  }
+
+  impl Searcher for &[ &str ]
   {
     fn pos( &self, src : &str ) -> Option< ( usize, usize ) >
     {
       let mut r = vec![];
-      for pat in self
+      for pat in self.iter()
       {
         if let Some( x ) =  src.find( pat )
         {
@@ -95,57 +106,225 @@ pub( crate ) mod private
     }
   }
 
+  impl Searcher for char
+  {
+    fn pos( &self, src : &str ) -> Option< ( usize, usize ) >
+    {
+      src.find( *self ).map( | start | ( start, start + self.len_utf8() ) )
+    }
+  }
+
+  impl Searcher for &[ char ]
+  {
+    fn pos( &self, src : &str ) -> Option< ( usize, usize ) >
+    {
+      src.char_indices().find( | ( _, c ) | self.contains( c ) ).map( | ( start, c ) | ( start, start + c.len_utf8() ) )
+    }
+  }
+
   ///
-  /// Split iterator.
+  /// Delimeter is any single character for which the predicate returns true.
   ///
+  /// # Sample
+  /// ```
+  /// use strs_tools::string::split::{ Searcher, CharPredicate };
+  /// let searcher = CharPredicate( | c : char | c.is_ascii_punctuation() );
+  /// assert_eq!( searcher.pos( "ab;c" ), Some( ( 2, 3 ) ) );
+  /// ```
 
-  #[ derive( Debug ) ]
-  pub struct SplitFastIterator< 'a, D >
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct CharPredicate< F >( pub F );
+
+  impl< F > Searcher for CharPredicate< F >
   where
-    D : Searcher
+    F : Fn( char ) -> bool,
   {
-    iterable : &'a str,
-    counter : i32,
-    delimeter : D,
-    preserving_empty : bool,
-    preserving_delimeters : bool,
-    stop_empty : bool,
+    fn pos( &self, src : &str ) -> Option< ( usize, usize ) >
+    {
+      src.char_indices().find( | ( _, c ) | ( self.0 )( *c ) ).map( | ( start, c ) | ( start, start + c.len_utf8() ) )
+    }
   }
 
-  //
+  ///
+  /// Set of alternative delimeters. The leftmost match is found, among matches at the same position the longest one wins.
+  ///
+  /// # Sample
+  /// ```
+  /// use strs_tools::string::split::{ Searcher, Alternatives };
+  /// let searcher = Alternatives( vec![ "=", "==", "!=" ] );
+  /// assert_eq!( searcher.pos( "a == b" ), Some( ( 2, 4 ) ) );
+  /// ```
 
-  impl< 'a, D : Searcher + Clone > SplitFastIterator< 'a, D >
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct Alternatives< S >( pub Vec< S > );
+
+  impl< S > Searcher for Alternatives< S >
+  where
+    S : AsRef< str >,
   {
-    #[ allow( dead_code ) ]
-    fn new( o : impl SplitOptionsAdapter< 'a, D > ) -> Self
+    fn pos( &self, src : &str ) -> Option< ( usize, usize ) >
     {
-      Self
+      self.0.iter()
+      .filter_map( | pat | src.find( pat.as_ref() ).map( | start | ( start, start + pat.as_ref().len() ) ) )
+      .min_by( | a, b | a.0.cmp( &b.0 ).then( b.1.cmp( &a.1 ) ) )
+    }
+  }
+
+  #[ cfg( feature = "string_split_regex" ) ]
+  impl Searcher for regex::Regex
+  {
+    fn pos( &self, src : &str ) -> Option< ( usize, usize ) >
+    {
+      self.find( src ).map( | m | ( m.start(), m.end() ) )
+    }
+  }
+
+  ///
+  /// Delimeter of split iterator : the delimeter itself and prefixes of quotes. Matches which follow an escape character are skipped.
+  /// Borrows split options.
+  ///
+
+  #[ derive( Debug, Clone ) ]
+  pub struct Delimeters< 'a, D >
+  {
+    delimeter : &'a D,
+    quoting_prefixes : &'a [ &'a str ],
+    escape_chars : &'a [ char ],
+  }
+
+  impl< 'a, D > Searcher for Delimeters< 'a, D >
+  where
+    D : Searcher,
+  {
+    fn pos( &self, src : &str ) -> Option< ( usize, usize ) >
+    {
+      let mut offset = 0;
+      loop
       {
-        iterable : o.src(),
-        delimeter : o.delimeter(),
-        counter : 0,
-        preserving_empty : o.preserving_empty(),
-        preserving_delimeters : o.preserving_delimeters(),
-        stop_empty : false,
+        let rest = &src[ offset.. ];
+        let delimeter = self.delimeter.pos( rest );
+        let quote = self.quoting_prefixes.pos( rest );
+        let ( start, end ) = match ( delimeter, quote )
+        {
+          ( Some( d ), Some( q ) ) => if q.0 < d.0 || ( q.0 == d.0 && q.1 <= d.1 ) { q } else { d },
+          ( Some( d ), None ) => d,
+          ( None, Some( q ) ) => q,
+          ( None, None ) => return None,
+        };
+        if !escaped( src, offset + start, &self.escape_chars )
+        {
+          return Some( ( offset + start, offset + end ) );
+        }
+        match src[ offset + start.. ].chars().next()
+        {
+          Some( c ) => offset += start + c.len_utf8(),
+          None => return None,
+        }
       }
     }
   }
 
-  //
+  ///
+  /// Scan the source from the offset for the end of the last delimeter outside of quotes, which could not be changed by appending
+  /// more data, unless it is the end of the data. Returns the boundary if any and the offset to continue scanning from.
+  ///
 
-  impl< 'a, D > Iterator for SplitFastIterator< 'a, D >
+  pub( crate ) fn boundary< D >
+  (
+    delimeters : &Delimeters< '_, D >,
+    quoting_postfixes : &[ &str ],
+    src : &str,
+    mut offset : usize,
+    eof : bool,
+  ) -> ( Option< usize >, usize )
   where
-    D : Searcher
+    D : Searcher,
   {
-    type Item = Split< 'a >;
+    let mut boundary = None;
+    while let Some( ( start, end ) ) = delimeters.pos( &src[ offset.. ] )
+    {
+      let ( start, end ) = ( offset + start, offset + end );
+      if !eof && end >= src.len()
+      {
+        return ( boundary, start );
+      }
+      if let Some( index ) = delimeters.quoting_prefixes.iter().position( | &prefix | prefix == &src[ start..end ] )
+      {
+        let postfix = quoting_postfixes[ index ];
+        match find_unescaped( &src[ end.. ], postfix, &delimeters.escape_chars )
+        {
+          Some( close ) =>
+          {
+            offset = end + close + postfix.len();
+            continue;
+          },
+          None => return ( boundary, start ),
+        }
+      }
+      if start == end
+      {
+        match src[ end.. ].chars().next()
+        {
+          Some( c ) => offset = end + c.len_utf8(),
+          None => break,
+        }
+        continue;
+      }
+      boundary = Some( end );
+      offset = end;
+    }
+    ( boundary, offset )
+  }
 
-    fn next( &mut self ) -> Option< Self::Item >
+  /// Is character at the position preceded by an odd number of escape characters.
+  fn escaped( src : &str, pos : usize, escape_chars : &[ char ] ) -> bool
+  {
+    if escape_chars.is_empty()
+    {
+      return false;
+    }
+    src[ ..pos ].chars().rev().take_while( | c | escape_chars.contains( c ) ).count() % 2 == 1
+  }
+
+  /// Find the first pattern in the string which does not follow an escape character.
+  fn find_unescaped( src : &str, pat : &str, escape_chars : &[ char ] ) -> Option< usize >
+  {
+    let mut offset = 0;
+    while let Some( start ) = src[ offset.. ].find( pat )
+    {
+      if !escaped( src, offset + start, escape_chars )
+      {
+        return Some( offset + start );
+      }
+      offset += start + src[ offset + start.. ].chars().next()?.len_utf8();
+    }
+    None
+  }
+
+  ///
+  /// Rest of the source to split and whether delimeted or delimeter is next.
+  ///
+
+  #[ derive( Debug, Clone, Copy ) ]
+  struct SplitCursor< 'a >
+  {
+    iterable : &'a str,
+    counter : i32,
+    preserving_empty : bool,
+    preserving_delimeters : bool,
+    stop_empty : bool,
+  }
+
+  impl< 'a > SplitCursor< 'a >
+  {
+    /// The next split found with the delimeter.
+    fn next< S : Searcher >( &mut self, delimeter : &S ) -> Option< Split< 'a > >
     {
       self.counter += 1;
 
       if self.counter % 2 == 1
       {
-        let positions = self.delimeter.pos( self.iterable );
+        let positions = delimeter.pos( self.iterable );
         if let Some( ( mut start, end ) ) = positions
         {
           if self.iterable.is_empty() && start == end
@@ -164,21 +343,23 @@ pub( crate ) mod private
 
           if start == 0 && end != 0
           {
-            return self.next();
+            return self.next( delimeter );
           }
 
           let mut next = &self.iterable[ ..start ];
           if start == end && self.counter >= 3
           {
-            next = &self.iterable[ ..start + 1 ];
-            start += 1;
+            // empty delimeter splits off the next character, which may be wider than a byte
+            let width = self.iterable[ start.. ].chars().next().map_or( 0, char::len_utf8 );
+            next = &self.iterable[ ..start + width ];
+            start += width;
           }
 
           self.iterable = &self.iterable[ start.. ];
 
           if !self.preserving_empty && next.is_empty()
           {
-            return self.next();
+            return self.next( delimeter );
           }
 
           Some( Split { string : next, typ : SplitType::Delimeted } )
@@ -190,25 +371,25 @@ pub( crate ) mod private
         else
         {
           let r = Split { string : self.iterable, typ : SplitType::Delimeted };
-          self.iterable = "";
+          self.iterable = &self.iterable[ self.iterable.len().. ];
           Some( r )
         }
       }
       else
       {
-        if self.delimeter.pos( self.iterable ).is_none()
+        if delimeter.pos( self.iterable ).is_none()
         {
-          self.iterable = "";
+          self.iterable = &self.iterable[ self.iterable.len().. ];
           return None;
         }
 
-        let ( start, end ) = self.delimeter.pos( self.iterable ).unwrap();
+        let ( start, end ) = delimeter.pos( self.iterable ).unwrap();
         let string = &self.iterable[ start..end ];
         self.iterable = &self.iterable[ end.. ];
 
         if !self.preserving_empty && string.is_empty()
         {
-          return self.next();
+          return self.next( delimeter );
         }
 
         if self.preserving_delimeters
@@ -217,11 +398,60 @@ pub( crate ) mod private
         }
         else
         {
-          self.next()
+          self.next( delimeter )
           // return self.next_odd_split();
         }
       }
     }
+
+    /// Take the quoted part of the source if the split is a quoting prefix.
+    fn quoted_split< D >( &mut self, options : &SplitOptions< '_, D >, src : &'a str, split_str : &'a str ) -> Split< 'a >
+    where
+      D : Searcher + Clone,
+    {
+      match options.quoting_prefixes.iter().position( | &quote | quote == split_str )
+      {
+        Some( index ) =>
+        {
+          let postfix = options.quoting_postfixes[ index ];
+          let rest = self.iterable;
+          let pos = src.len() - rest.len();
+          let start = pos - split_str.len();
+          let end = find_unescaped( rest, postfix, &options.escape_chars );
+
+          if let Some( end ) = end
+          {
+            self.iterable = &rest[ end + postfix.len().. ];
+            if options.preserving_quoting
+            {
+              Split { string : &src[ start..pos + end + postfix.len() ], typ : SplitType::Delimeted }
+            }
+            else
+            {
+              Split { string : &src[ start + split_str.len() ..pos + end ], typ : SplitType::Delimeted }
+            }
+          }
+          else
+          {
+            self.iterable = &rest[ rest.len().. ];
+            Split { string : &src[ start.. ], typ : SplitType::Delimeted }
+          }
+        },
+        None => Split { string : split_str, typ : SplitType::Delimeter },
+      }
+    }
+  }
+
+  ///
+  /// Position of split iterator in its source, splitting can be resumed from it.
+  ///
+
+  #[ derive( Debug, Clone, Copy, Default ) ]
+  pub( crate ) struct SplitPosition
+  {
+    offset : usize,
+    counter : i32,
+    stop_empty : bool,
   }
 
   ///
@@ -229,108 +459,148 @@ pub( crate ) mod private
   ///
 
   #[ derive( Debug ) ]
-  pub struct SplitIterator< 'a >
+  pub struct SplitFastIterator< 'a, D >
+  where
+    D : Searcher
   {
-    iterator : SplitFastIterator< 'a, Vec< &'a str > >,
-    src : &'a str,
-    stripping : bool,
-    preserving_empty : bool,
-    preserving_delimeters : bool,
-    #[ allow( dead_code ) ]
-    preserving_quoting : bool,
-    quoting : bool,
-    quoting_prefixes : Vec< &'a str >,
-    quoting_postfixes : Vec< &'a str >,
+    cursor : SplitCursor< 'a >,
+    delimeter : D,
   }
 
   //
 
-  impl< 'a > SplitIterator< 'a >
+  impl< 'a, D : Searcher + Clone > SplitFastIterator< 'a, D >
   {
-    fn new( o : impl SplitOptionsAdapter< 'a, Vec< &'a str > > ) -> Self
+    #[ allow( dead_code ) ]
+    fn new( o : impl SplitOptionsAdapter< 'a, D > ) -> Self
     {
-      let iterator;
-      if !o.stripping() && !o.quoting() /* && !onDelimeter */
+      Self
       {
-        iterator = SplitFastIterator
+        cursor : SplitCursor
         {
           iterable : o.src(),
-          delimeter : o.delimeter(),
           counter : 0,
           preserving_empty : o.preserving_empty(),
           preserving_delimeters : o.preserving_delimeters(),
           stop_empty : false,
-        };
+        },
+        delimeter : o.delimeter(),
       }
-      else
-      {
-        let mut delimeter;
-        if o.quoting()
-        {
-          delimeter = o.quoting_prefixes().clone();
-          delimeter.extend( o.quoting_postfixes().clone() );
-          delimeter.extend( o.delimeter() );
-        }
-        else
-        {
-          delimeter = o.delimeter();
-        }
+    }
+  }
 
-        iterator = SplitFastIterator
-        {
-          iterable : o.src(),
-          delimeter,
-          counter : 0,
-          preserving_empty : true,
-          preserving_delimeters : true,
-          stop_empty : false,
-        };
-      }
+  //
 
-      Self
+  impl< 'a, D > Iterator for SplitFastIterator< 'a, D >
+  where
+    D : Searcher
+  {
+    type Item = Split< 'a >;
+
+    fn next( &mut self ) -> Option< Self::Item >
+    {
+      self.cursor.next( &self.delimeter )
+    }
+  }
+
+  ///
+  /// Split iterator. Owns its options or borrows them.
+  ///
+
+  #[ derive( Debug ) ]
+  pub struct SplitIterator< 'a, D = Vec< &'a str >, O = SplitOptions< 'a, D > >
+  where
+    D : Searcher + Clone,
+    O : Borrow< SplitOptions< 'a, D > >,
+  {
+    cursor : SplitCursor< 'a >,
+    src : &'a str,
+    options : O,
+    _delimeter : PhantomData< D >,
+  }
+
+  //
+
+  impl< 'a, D, O > SplitIterator< 'a, D, O >
+  where
+    D : Searcher + Clone,
+    O : Borrow< SplitOptions< 'a, D > >,
+  {
+    fn new( options : O ) -> Self
+    {
+      let src = options.borrow().src;
+      Self::resume( options, src, SplitPosition::default() )
+    }
+
+    /// Split the source with the options from the position, option `src` is ignored.
+    pub( crate ) fn resume( options : O, src : &'a str, position : SplitPosition ) -> Self
+    {
+      let o = options.borrow();
+      let fast = !o.stripping && !o.quoting;
+      let cursor = SplitCursor
+      {
+        iterable : &src[ position.offset.. ],
+        counter : position.counter,
+        preserving_empty : !fast || o.preserving_empty,
+        preserving_delimeters : !fast || o.preserving_delimeters,
+        stop_empty : position.stop_empty,
+      };
+      Self { cursor, src, options, _delimeter : PhantomData }
+    }
+
+    /// Position in the source to resume splitting from.
+    pub( crate ) fn position( &self ) -> SplitPosition
+    {
+      SplitPosition
       {
-        iterator,
-        src : o.src(),
-        stripping : o.stripping(),
-        preserving_empty : o.preserving_empty(),
-        preserving_delimeters : o.preserving_delimeters(),
-        preserving_quoting : o.preserving_quoting(),
-        quoting : o.quoting(),
-        quoting_prefixes : o.quoting_prefixes().clone(),
-        quoting_postfixes : o.quoting_postfixes().clone(),
+        offset : self.src.len() - self.cursor.iterable.len(),
+        counter : self.cursor.counter,
+        stop_empty : self.cursor.stop_empty,
       }
     }
+
+    /// Take the quoted part of the source if the split is a quoting prefix. Quoting postfix is the one paired with the prefix,
+    /// postfix after an escape character does not close quoting.
+    pub fn quoted_split( &mut self, split_str : &'a str ) -> Split< 'a >
+    {
+      self.cursor.quoted_split( self.options.borrow(), self.src, split_str )
+    }
   }
 
-  impl< 'a > Iterator for SplitIterator< 'a >
+  impl< 'a, D, O > Iterator for SplitIterator< 'a, D, O >
+  where
+    D : Searcher + Clone,
+    O : Borrow< SplitOptions< 'a, D > >,
   {
     type Item = Split< 'a >;
 
     fn next( &mut self ) -> Option< Self::Item >
     {
-      if let Some( mut split ) = self.iterator.next()
+      let options = self.options.borrow();
+      let delimeters = options.delimeters();
+      if let Some( mut split ) = self.cursor.next( &delimeters )
       {
-        if self.quoting
+        if options.quoting && matches!( split.typ, SplitType::Delimeter )
         {
-          split = self.quoted_split( split.string );
+          split = self.cursor.quoted_split( options, self.src, split.string );
         }
 
-        if self.stripping
+        if options.stripping
         {
           split.string = split.string.trim();
-          if !self.preserving_empty && split.string.is_empty()
+          if !options.preserving_empty && split.string.is_empty()
           {
             return self.next();
           }
         }
-        else if !self.quoting
+        else if !options.quoting
         {
           return Some( split );
         }
 
-        if !self.preserving_delimeters
+        if !options.preserving_delimeters
         {
-          match self.iterator.delimeter.pos( split.string )
+          match delimeters.pos( split.string )
           {
             Some( ( s, e ) ) =>
             {
@@ -350,7 +620,7 @@ pub( crate ) mod private
           }
         }
 
-        if !self.preserving_empty && split.string.is_empty()
+        if !options.preserving_empty && split.string.is_empty()
         {
           return self.next();
         }
@@ -364,42 +634,6 @@ pub( crate ) mod private
     }
   }
 
-  impl< 'a > SplitIterator< 'a >
-  {
-    pub fn quoted_split( &mut self, split_str : &'a str ) -> Split< 'a >
-    {
-      match self.quoting_prefixes.iter().position( | &quote | quote == split_str )
-      {
-        Some( index ) =>
-        {
-          let postfix = self.quoting_postfixes[ index ];
-          let pos = self.src.find( self.iterator.iterable ).unwrap();
-          let start = pos - split_str.len();
-          let end = self.iterator.iterable.find( postfix );
-
-          if let Some( end ) = end
-          {
-            while self.iterator.next().unwrap().string != postfix {}
-            if self.preserving_quoting
-            {
-              Split { string : &self.src[ start..pos + end + postfix.len() ], typ : SplitType::Delimeted }
-            }
-            else
-            {
-              Split { string : &self.src[ start + split_str.len() ..pos + end ], typ : SplitType::Delimeted }
-            }
-          }
-          else
-          {
-            self.iterator.iterable = "";
-            Split { string : &self.src[ start.. ], typ : SplitType::Delimeted }
-          }
-        },
-        None => Split { string : split_str, typ : SplitType::Delimeted },
-      }
-    }
-  }
-
   ///
   /// Options of function split.
   ///
@@ -407,7 +641,7 @@ pub( crate ) mod private
   #[ derive( Debug ) ]
   pub struct SplitOptions< 'a, D >
   where
-    D : Searcher + Default + Clone,
+    D : Searcher + Clone,
   {
     src : &'a str,
     delimeter : D,
@@ -418,23 +652,60 @@ pub( crate ) mod private
     quoting : bool,
     quoting_prefixes : Vec< &'a str >,
     quoting_postfixes : Vec< &'a str >,
+    escape_chars : Vec< char >,
   }
 
-  impl< 'a > SplitOptions< 'a, Vec< &'a str > >
+  impl< 'a, D > SplitOptions< 'a, D >
+  where
+    D : Searcher + Clone
   {
     /// Produces SplitIterator.
-    pub fn split( self ) -> SplitIterator< 'a >
+    pub fn split( self ) -> SplitIterator< 'a, D >
     where
       Self : Sized,
     {
       SplitIterator::new( self )
     }
-  }
 
-  impl< 'a, D > SplitOptions< 'a, D >
-  where
-    D : Searcher + Default + Clone
-  {
+    /// Produces SplitStream, which splits data read from the reader.
+    pub fn split_stream< R >( self, reader : R ) -> crate::string::split_stream::SplitStream< 'a, R, D >
+    where
+      R : std::io::BufRead,
+    {
+      crate::string::split_stream::SplitStream::new( self, reader )
+    }
+
+    /// Delimeter which also finds quotes, skipping escaped matches.
+    pub( crate ) fn delimeters( &self ) -> Delimeters< '_, D >
+    {
+      Delimeters
+      {
+        delimeter : &self.delimeter,
+        quoting_prefixes : if self.quoting { &self.quoting_prefixes } else { &[] },
+        escape_chars : &self.escape_chars,
+      }
+    }
+
+    /// Replace delimeter by a searcher, like `regex::Regex`, `CharPredicate` or `Alternatives`, keeping other options.
+    pub fn searcher< S >( self, searcher : S ) -> SplitOptions< 'a, S >
+    where
+      S : Searcher + Clone,
+    {
+      SplitOptions
+      {
+        src : self.src,
+        delimeter : searcher,
+        preserving_empty : self.preserving_empty,
+        preserving_delimeters : self.preserving_delimeters,
+        preserving_quoting : self.preserving_quoting,
+        stripping : self.stripping,
+        quoting : self.quoting,
+        quoting_prefixes : self.quoting_prefixes,
+        quoting_postfixes : self.quoting_postfixes,
+        escape_chars : self.escape_chars,
+      }
+    }
+
     /// Produces SplitFastIterator.
     pub fn split_fast( self ) -> SplitFastIterator< 'a, D >
     where
@@ -470,11 +741,16 @@ pub( crate ) mod private
     fn quoting_prefixes( &self ) -> &Vec< &'a str >;
     /// Quoting postfixes.
     fn quoting_postfixes( &self ) -> &Vec< &'a str >;
+    /// Characters which escape the following delimeter or quote.
+    fn escape_chars( &self ) -> &[ char ]
+    {
+      &[]
+    }
   }
 
   //
 
-  impl< 'a, D : Searcher + Clone + Default > SplitOptionsAdapter< 'a, D > for SplitOptions< 'a, D >
+  impl< 'a, D : Searcher + Clone > SplitOptionsAdapter< 'a, D > for SplitOptions< 'a, D >
   {
     fn src( &self ) -> &'a str
     {
@@ -512,6 +788,10 @@ pub( crate ) mod private
     {
       &self.quoting_postfixes
     }
+    fn escape_chars( &self ) -> &[ char ]
+    {
+      &self.escape_chars
+    }
   }
 
   //
@@ -554,6 +834,7 @@ pub( crate ) mod private
             quoting : self.quoting,
             quoting_prefixes : self.quoting_prefixes.clone(),
             quoting_postfixes : self.quoting_postfixes.clone(),
+            escape_chars : self.escape_chars.clone(),
           }
         }
       }
@@ -576,6 +857,7 @@ pub( crate ) mod private
     quoting : bool,
     quoting_prefixes : Vec< &'a str >,
     quoting_postfixes : Vec< &'a str >,
+    escape_chars : Vec< char >,
   }
   builder_impls_from!
   (
@@ -588,6 +870,7 @@ pub( crate ) mod private
     ( quoting, bool ),
     ( quoting_prefixes, Vec< &'a str > ),
     ( quoting_postfixes, Vec< &'a str > ),
+    ( escape_chars, Vec< char > ),
   );
 
   impl< 'a > SplitOptionsFormer< 'a >
@@ -606,6 +889,7 @@ pub( crate ) mod private
         quoting : true,
         quoting_prefixes : vec![],
         quoting_postfixes : vec![],
+        escape_chars : vec![],
       }
     }
 
@@ -622,6 +906,15 @@ pub( crate ) mod private
       let opts = self.form();
       opts.split()
     }
+
+    /// Split data read from the reader, option `src` is ignored.
+    pub fn perform_stream< R >( &mut self, reader : R ) -> crate::string::split_stream::SplitStream< 'a, R >
+    where
+      R : std::io::BufRead,
+    {
+      let opts = self.form();
+      opts.split_stream( reader )
+    }
   }
 
   ///
@@ -656,7 +949,11 @@ pub mod protected
   {
     Split,
     SplitType,
+    Searcher,
+    CharPredicate,
+    Alternatives,
     SplitFastIterator,
+    SplitIterator,
     SplitOptions,
     SplitOptionsAdapter,
     split,
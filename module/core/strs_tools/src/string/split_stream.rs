@@ -0,0 +1,341 @@
+/// Private namespace.
+pub( crate ) mod private
+{
+
+  use std::io::BufRead;
+  use crate::string::split::{ Split, SplitIterator, SplitOptions, SplitOptionsAdapter, Searcher };
+  use crate::string::split::private::{ SplitPosition, boundary };
+
+  ///
+  /// Split with its position in the stream.
+  ///
+
+  #[ derive( Debug ) ]
+  pub struct StreamSplit< 'b >
+  {
+    /// The split, its slice is borrowed from the buffer of the stream.
+    pub split : Split< 'b >,
+    /// Offset of the split in bytes from the start of the stream.
+    pub offset : usize,
+    /// Line of the split start, starting from 1.
+    pub line : usize,
+    /// Column of the split start in characters, starting from 1.
+    pub column : usize,
+  }
+
+  impl< 'b > From< StreamSplit< 'b > > for String
+  {
+    fn from( src : StreamSplit< '_ > ) -> Self
+    {
+      src.split.into()
+    }
+  }
+
+  ///
+  /// Position in the buffer with its line and column.
+  ///
+
+  #[ derive( Debug, Clone, Copy ) ]
+  struct Cursor
+  {
+    pos : usize,
+    line : usize,
+    column : usize,
+  }
+
+  impl Cursor
+  {
+    /// Move forward to the position counting lines and columns of the passed text.
+    fn advance( &mut self, text : &str, pos : usize )
+    {
+      for c in text[ self.pos..pos ].chars()
+      {
+        if c == '\n'
+        {
+          self.line += 1;
+          self.column = 1;
+        }
+        else
+        {
+          self.column += 1;
+        }
+      }
+      self.pos = pos;
+    }
+  }
+
+  ///
+  /// Splitter of data read from `std::io::BufRead`.
+  ///
+  /// Data is read into a reusable buffer and split by parts which end with a delimeter outside of quotes,
+  /// so splits are the same as splits of the whole data. Data which is split already is dropped from the buffer
+  /// when more data is read. Slices of splits borrow the buffer, that's why it is not an iterator :
+  /// call `next_split` until it returns `None`.
+  ///
+  /// A delimeter is considered found when some data follows it, so searchers which could match longer with more data,
+  /// like regular expression `\s+`, behave the same way as on the whole data.
+  ///
+  /// Quoting is on by default, and a part with an open quote ends only where the quote is closed, so a stray quote
+  /// could make the splitter read the rest of the stream. That's why data of an open quote is limited by `quote_limit`,
+  /// 64 KiB by default, and error `InvalidData` is returned if the quote is not closed within the limit.
+  /// Turn quoting off if quotes should not be treated specially.
+  ///
+
+  #[ derive( Debug ) ]
+  pub struct SplitStream< 'a, R, D = Vec< &'a str > >
+  where
+    D : Searcher + Clone,
+  {
+    reader : R,
+    options : SplitOptions< 'a, D >,
+    buffer : String,
+    /// Bytes of a character which is not read completely.
+    incomplete : Vec< u8 >,
+    eof : bool,
+    started : bool,
+    /// Offset to continue scanning for boundary from.
+    scanned : usize,
+    /// Start of the part of the buffer which splits are produced from.
+    start : usize,
+    /// End of the part.
+    part : usize,
+    /// Position of splitting in the part, if the part is not split completely.
+    position : Option< SplitPosition >,
+    /// Offset of the buffer in the stream.
+    offset : usize,
+    cursor : Cursor,
+    /// Maximal number of bytes of an open quote to buffer.
+    quote_limit : usize,
+  }
+
+  impl< 'a, R, D > SplitStream< 'a, R, D >
+  where
+    R : BufRead,
+    D : Searcher + Clone,
+  {
+
+    /// Splitter of data read from the reader with the options, option `src` is ignored.
+    pub fn new( options : SplitOptions< 'a, D >, reader : R ) -> Self
+    {
+      Self
+      {
+        reader,
+        options,
+        buffer : String::new(),
+        incomplete : Vec::with_capacity( 4 ),
+        eof : false,
+        started : false,
+        scanned : 0,
+        start : 0,
+        part : 0,
+        position : None,
+        offset : 0,
+        cursor : Cursor { pos : 0, line : 1, column : 1 },
+        quote_limit : 1 << 16,
+      }
+    }
+
+    /// Set maximal number of bytes of an open quote to buffer waiting for the quote to be closed.
+    pub fn quote_limit( mut self, quote_limit : usize ) -> Self
+    {
+      self.quote_limit = quote_limit;
+      self
+    }
+
+    /// The next split, slice of which is valid until the next call.
+    pub fn next_split( &mut self ) -> Option< std::io::Result< StreamSplit< '_ > > >
+    {
+      let ( start, end, typ ) = loop
+      {
+        if let Some( position ) = self.position
+        {
+          let src = &self.buffer[ self.start..self.part ];
+          let mut splits = SplitIterator::resume( &self.options, src, position );
+          if let Some( split ) = splits.next()
+          {
+            self.position = Some( splits.position() );
+            let start = self.start + ( split.string.as_ptr() as usize - src.as_ptr() as usize );
+            break ( start, start + split.string.len(), split.typ );
+          }
+          self.position = None;
+        }
+
+        match self.next_part()
+        {
+          Ok( true ) => (),
+          Ok( false ) => return None,
+          Err( err ) => return Some( Err( err ) ),
+        }
+      };
+
+      self.cursor.advance( &self.buffer, start );
+      Some( Ok( StreamSplit
+      {
+        split : Split { string : &self.buffer[ start..end ], typ },
+        offset : self.offset + start,
+        line : self.cursor.line,
+        column : self.cursor.column,
+      }))
+    }
+
+    /// Find the next part after the one which is split already. Returns false if there is no more data.
+    fn next_part( &mut self ) -> std::io::Result< bool >
+    {
+      self.start = self.part;
+
+      loop
+      {
+        let src = &self.buffer[ self.start.. ];
+        let ( found, scanned ) = boundary
+        (
+          &self.options.delimeters(),
+          self.options.quoting_postfixes(),
+          src,
+          self.scanned - self.start,
+          self.eof,
+        );
+        self.scanned = self.start + scanned;
+        let part = match found
+        {
+          Some( part ) => part,
+          None if self.eof =>
+          {
+            if self.started && src.is_empty()
+            {
+              return Ok( false );
+            }
+            src.len()
+          },
+          None =>
+          {
+            // scanning stops at an open quote
+            let rest = &src[ scanned.. ];
+            let quoted = self.options.quoting() && self.options.quoting_prefixes().iter().any( | prefix | rest.starts_with( prefix ) );
+            if quoted && rest.len() > self.quote_limit
+            {
+              return Err( std::io::Error::new
+              (
+                std::io::ErrorKind::InvalidData,
+                format!( "quote at offset {} is not closed within {} bytes", self.offset + self.scanned, self.quote_limit ),
+              ));
+            }
+            self.read()?;
+            continue;
+          },
+        };
+
+        self.started = true;
+        self.part = self.start + part;
+        self.scanned = self.scanned.max( self.part );
+        self.position = Some( SplitPosition::default() );
+        return Ok( true );
+      }
+    }
+
+    /// Drop data which is split already and append available data to the buffer.
+    fn read( &mut self ) -> std::io::Result< () >
+    {
+      if self.start > 0
+      {
+        self.cursor.advance( &self.buffer, self.start );
+        self.buffer.drain( ..self.start );
+        self.offset += self.start;
+        self.scanned -= self.start;
+        self.part -= self.start;
+        self.cursor.pos = 0;
+        self.start = 0;
+      }
+
+      let chunk = loop
+      {
+        match self.reader.fill_buf()
+        {
+          Ok( chunk ) => break chunk,
+          Err( err ) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+          Err( err ) => return Err( err ),
+        }
+      };
+
+      if chunk.is_empty()
+      {
+        self.eof = true;
+        if !self.incomplete.is_empty()
+        {
+          return Err( std::io::Error::new( std::io::ErrorKind::InvalidData, "stream did not contain valid UTF-8" ) );
+        }
+        return Ok( () );
+      }
+
+      let mut chunk = chunk;
+      let mut consumed = 0;
+      while !self.incomplete.is_empty() && !chunk.is_empty()
+      {
+        self.incomplete.push( chunk[ 0 ] );
+        chunk = &chunk[ 1.. ];
+        consumed += 1;
+        match std::str::from_utf8( &self.incomplete )
+        {
+          Ok( c ) =>
+          {
+            self.buffer.push_str( c );
+            self.incomplete.clear();
+          },
+          Err( err ) if err.error_len().is_none() => (),
+          Err( _ ) => return Err( std::io::Error::new( std::io::ErrorKind::InvalidData, "stream did not contain valid UTF-8" ) ),
+        }
+      }
+
+      match std::str::from_utf8( chunk )
+      {
+        Ok( text ) => self.buffer.push_str( text ),
+        Err( err ) if err.error_len().is_none() =>
+        {
+          let valid = err.valid_up_to();
+          // SAFETY : bytes up to `valid_up_to` are valid UTF-8.
+          self.buffer.push_str( unsafe { std::str::from_utf8_unchecked( &chunk[ ..valid ] ) } );
+          self.incomplete.extend_from_slice( &chunk[ valid.. ] );
+        },
+        Err( _ ) => return Err( std::io::Error::new( std::io::ErrorKind::InvalidData, "stream did not contain valid UTF-8" ) ),
+      }
+      consumed += chunk.len();
+
+      self.reader.consume( consumed );
+      Ok( () )
+    }
+
+  }
+
+}
+
+#[ doc( inline ) ]
+#[ allow( unused_imports ) ]
+pub use protected::*;
+
+/// Protected namespace of the module.
+pub mod protected
+{
+  #[ allow( unused_imports ) ]
+  pub use super::orphan::*;
+  pub use super::private::
+  {
+    StreamSplit,
+    SplitStream,
+  };
+}
+
+/// Parented namespace of the module.
+pub mod orphan
+{
+  #[ allow( unused_imports ) ]
+  pub use super::exposed::*;
+}
+
+/// Exposed namespace of the module.
+pub mod exposed
+{
+}
+
+/// Namespace of the module to include with `use module::*`.
+pub mod prelude
+{
+}
@@ -0,0 +1,144 @@
+use super::*;
+use super::TheModule::string::parse_request as parse;
+use std::collections::HashMap;
+use rand::{ Rng, SeedableRng };
+use rand::rngs::StdRng;
+
+/// Characters of generated strings, including delimeters, quotes and brackets which should be quoted.
+const ALPHABET : &[ char ] = &
+[
+  'a', 'b', 'z', '0', '9', '.', '/', '_', '-', 'ж', ' ', '\t',
+  ':', ';', '=', '>', '|', '&', ',', '[', ']', '"', '\'', '`',
+];
+
+fn string( rng : &mut StdRng, alphabet : &[ char ], len : std::ops::Range< usize > ) -> String
+{
+  let len = rng.gen_range( len );
+  ( 0..len ).map( | _ | alphabet[ rng.gen_range( 0..alphabet.len() ) ] ).collect()
+}
+
+fn value( rng : &mut StdRng ) -> parse::OpType< String >
+{
+  if rng.gen_bool( 0.7 )
+  {
+    parse::OpType::Primitive( string( rng, ALPHABET, 0..6 ) )
+  }
+  else
+  {
+    let len = rng.gen_range( 0..4 );
+    parse::OpType::Vector( ( 0..len ).map( | _ | string( rng, ALPHABET, 0..5 ) ).collect() )
+  }
+}
+
+/// Random request with random delimeters, `original` is empty.
+fn request( rng : &mut StdRng ) -> parse::Request< 'static >
+{
+  let key_val_delimeter = [ ":", "=", "->" ][ rng.gen_range( 0..3 ) ];
+  let commands_delimeter = [ ";", "|", "&&" ][ rng.gen_range( 0..3 ) ];
+  let mut request = parse::Request { key_val_delimeter, commands_delimeter, ..Default::default() };
+
+  for _ in 0..rng.gen_range( 1..4 )
+  {
+    let subject = if rng.gen_bool( 0.3 ) { String::new() } else { string( rng, ALPHABET, 1..8 ) };
+    let mut map = HashMap::new();
+    for _ in 0..rng.gen_range( 0..4 )
+    {
+      map.insert( string( rng, &[ 'a', 'b', 'k', '1', '_' ], 1..4 ), value( rng ) );
+    }
+    request.subjects.push( subject );
+    request.maps.push( map );
+  }
+  request.subject = request.subjects[ 0 ].clone();
+  request.map = request.maps[ 0 ].clone();
+  request
+}
+
+fn request_parse< 'a >( src : &'a str, key_val_delimeter : &'a str, commands_delimeter : &'a str ) -> parse::Request< 'a >
+{
+  TheModule::string::request_parse()
+  .src( src )
+  .key_val_delimeter( key_val_delimeter )
+  .commands_delimeter( commands_delimeter )
+  .perform()
+}
+
+//
+
+tests_impls!
+{
+  fn basic()
+  {
+    let src = "cmd1 a:1 b:[x, y] ; cmd2 c:'d e'";
+    let req = request_parse( src, ":", ";" );
+    let got = req.format().unwrap();
+    a_id!( got, r#"cmd1 a:1 b:[x, y] ; cmd2 c:"d e""# );
+    let req2 = request_parse( &got, ":", ";" );
+    a_id!( req2.subjects, req.subjects );
+    a_id!( req2.maps, req.maps );
+
+    let got = parse::OpType::Primitive( "[a]".to_string() ).format( ":", ";" ).unwrap();
+    a_id!( got, r#""[a]""# );
+    let got = parse::OpType::Vector( vec![ "a,b".to_string(), "c".to_string() ] ).format( ":", ";" ).unwrap();
+    a_id!( got, r#"["a,b", c]"# );
+    let got = parse::OpType::Primitive( r#"a"b'c`"#.to_string() ).format( ":", ";" );
+    a_id!( got, Err( parse::FormatError::Value( r#"a"b'c`"#.to_string() ) ) );
+  }
+
+  //
+
+  fn errors()
+  {
+    let mut req = request_parse( "a:b c:1", ":", ";" );
+    req.subjects[ 0 ] = "a:b".into();
+    a_id!( req.format(), Err( parse::FormatError::Subject( "a:b".into() ) ) );
+
+    let mut req = request_parse( "a k:1", ":", ";" );
+    req.maps[ 0 ].insert( "b c".into(), parse::OpType::Primitive( "1".into() ) );
+    a_id!( req.format(), Err( parse::FormatError::Key( "b c".into() ) ) );
+
+    let mut req = request_parse( "a ; b", ":", ";" );
+    req.subjects[ 1 ] = String::new();
+    a_id!( req.format(), Err( parse::FormatError::EmptyCommand( 1 ) ) );
+
+    let mut req = request_parse( "a k:1", ":", ";" );
+    req.maps[ 0 ].insert( "m".into(), parse::OpType::Map( HashMap::new() ) );
+    a_id!( req.format(), Err( parse::FormatError::Map ) );
+
+    let mut req = request_parse( "a", ":", ";" );
+    req.maps.push( HashMap::new() );
+    a_id!( req.format(), Err( parse::FormatError::Inconsistent ) );
+  }
+
+  //
+
+  fn round_trip_property()
+  {
+    let mut rng = StdRng::seed_from_u64( 13 );
+    let mut formatted = 0;
+    for case in 0..2000
+    {
+      let mut req = request( &mut rng );
+      let src = match req.format()
+      {
+        Ok( src ) => src,
+        Err( _ ) => continue,
+      };
+      formatted += 1;
+
+      req.original = &src;
+      let got = request_parse( &src, req.key_val_delimeter, req.commands_delimeter );
+      assert_eq!( got, req, "case : {}, formatted : {}", case, src );
+      assert_eq!( got.format().as_ref(), Ok( &src ), "case : {}", case );
+    }
+    assert!( formatted > 1000, "only {} requests of 2000 could be formatted", formatted );
+  }
+}
+
+//
+
+tests_index!
+{
+  basic,
+  errors,
+  round_trip_property,
+}
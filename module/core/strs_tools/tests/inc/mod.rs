@@ -16,7 +16,13 @@ mod indentation_test;
 mod isolate_test;
 #[ cfg( all( feature = "string_parse_number", not( feature = "no_std" ) ) ) ]
 mod number_test;
-#[ cfg( all( feature = "string_parse", not( feature = "no_std" ) ) ) ]
+#[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
 mod parse_test;
+#[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
+mod parse_schema_test;
+#[ cfg( all( feature = "string_parse_request", not( feature = "no_std" ) ) ) ]
+mod format_test;
 #[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
 mod split_test;
+#[ cfg( all( feature = "string_split", not( feature = "no_std" ) ) ) ]
+mod split_stream_test;
@@ -0,0 +1,208 @@
+use super::*;
+use super::TheModule::string::parse_schema as schema;
+use schema::{ Key, Schema, SchemaError, SchemaErrorKind, Value, ValueType };
+
+#[ cfg( feature = "string_parse_request_derive" ) ]
+mod facade
+{
+  pub use ::strs_tools as strs;
+}
+
+//
+
+tests_impls!
+{
+  fn value_type_parse()
+  {
+    a_id!( ValueType::Int.parse( "-13" ), Some( Value::Int( -13 ) ) );
+    a_id!( ValueType::Int.parse( "1.5" ), None );
+    a_id!( ValueType::Float.parse( "1.5" ), Some( Value::Float( 1.5 ) ) );
+    a_id!( ValueType::Bool.parse( "true" ), Some( Value::Bool( true ) ) );
+    a_id!( ValueType::Bool.parse( "0" ), Some( Value::Bool( false ) ) );
+    a_id!( ValueType::Bool.parse( "yes" ), None );
+    a_id!( ValueType::Path.parse( "'/a b/c'" ), Some( Value::Path( "/a b/c".into() ) ) );
+    a_id!( ValueType::Path.parse( "" ), None );
+    let typ = ValueType::String.enumerate( vec![ "fast".into(), "slow".into() ] );
+    a_id!( typ.parse( "slow" ), Some( Value::Enum( "slow".into() ) ) );
+    a_id!( typ.parse( "medium" ), None );
+    let typ = ValueType::List( Box::new( ValueType::Int ) );
+    a_id!( typ.parse( "1" ), Some( Value::List( vec![ Value::Int( 1 ) ] ) ) );
+    a_id!( typ.to_string(), "list of int" );
+  }
+
+  //
+
+  fn apply()
+  {
+    let schema = Schema::new()
+    .key( Key::new( "limit", ValueType::Int ) )
+    .key( Key::new( "ratio", ValueType::Float ).default( "0.5" ) )
+    .key( Key::new( "tags", ValueType::List( Box::new( ValueType::String ) ) ).default( "[]" ) )
+    .key( Key::new( "dry", ValueType::Bool ).optional( true ) )
+    .subject( Key::new( "subject", ValueType::Path ) );
+
+    let request = TheModule::string::request_parse()
+    .src( "run.sh limit:10 tags:[a, b]" )
+    .perform();
+    let values = schema.apply( &request, 0 ).unwrap();
+    a_id!( values.get( "limit" ), Some( &Value::Int( 10 ) ) );
+    a_id!( values.get( "ratio" ), Some( &Value::Float( 0.5 ) ) );
+    a_id!( values.get( "tags" ), Some( &Value::List( vec![ Value::String( "a".into() ), Value::String( "b".into() ) ] ) ) );
+    a_id!( values.get( "dry" ), None );
+    a_id!( values.subject.unwrap().value, Value::Path( "run.sh".into() ) );
+    a_id!( values.map[ "limit" ].offset, 13 );
+
+    let request = TheModule::string::request_parse()
+    .src( "run.sh ; limit:10 ratio:x" )
+    .perform();
+    let values = schema.apply( &request, 0 );
+    let exp = vec!
+    [
+      SchemaError { kind : SchemaErrorKind::MissingKey, key : "limit".into(), offset : 6 },
+    ];
+    a_id!( values, Err( exp ) );
+    let values = schema.apply( &request, 1 );
+    let exp = vec!
+    [
+      SchemaError
+      {
+        kind : SchemaErrorKind::InvalidValue { expected : ValueType::Float, value : "x".into() },
+        key : "ratio".into(),
+        offset : 24,
+      },
+      SchemaError { kind : SchemaErrorKind::MissingKey, key : "subject".into(), offset : 9 },
+    ];
+    let mut got = values.unwrap_err();
+    got.sort_by_key( | error | error.key.clone() );
+    a_id!( got, exp );
+  }
+
+  //
+
+  fn apply_unknown_and_invalid()
+  {
+    let schema = Schema::new()
+    .key( Key::new( "mode", ValueType::String.enumerate( vec![ "fast".into(), "slow".into() ] ) ) )
+    .key( Key::new( "level", ValueType::Int ).default( "x" ) );
+
+    let src = "cmd mode:medium verbose:1";
+    let request = TheModule::string::request_parse()
+    .src( src )
+    .perform();
+    let got = schema.apply( &request, 0 ).unwrap_err();
+    a_id!( got.len(), 3 );
+    a_id!( &src[ got[ 0 ].offset.. ], "cmd mode:medium verbose:1" );
+    a_id!( got[ 0 ].kind, SchemaErrorKind::InvalidDefault { expected : ValueType::Int, value : "x".into() } );
+    a_id!( &src[ got[ 1 ].offset.. ], "medium verbose:1" );
+    a_id!( got[ 1 ].to_string(), "Invalid value `medium` of key `mode` at 9. Expects one of `fast`, `slow`" );
+    a_id!( &src[ got[ 2 ].offset.. ], "verbose:1" );
+    a_id!( got[ 2 ].kind, SchemaErrorKind::UnknownKey );
+
+    let src = "cmd mode:[fast, slow]";
+    let request = TheModule::string::request_parse()
+    .src( src )
+    .perform();
+    let got = Schema::new().key( Key::new( "mode", ValueType::String ) ).apply( &request, 0 ).unwrap_err();
+    a_id!( got[ 0 ].kind, SchemaErrorKind::InvalidValue { expected : ValueType::String, value : "[fast,slow]".into() } );
+
+    /* offsets point at the key, not at the same text inside of a quoted value */
+    let src = r#"mode note:"x mode:fast" mode:medium"#;
+    let request = TheModule::string::request_parse()
+    .src( src )
+    .perform();
+    let schema = Schema::new()
+    .key( Key::new( "mode", ValueType::String.enumerate( vec![ "fast".into(), "slow".into() ] ) ) )
+    .subject( Key::new( "subject", ValueType::Int ) );
+    let got = schema.apply( &request, 0 ).unwrap_err();
+    a_id!( got.len(), 3 );
+    a_id!( &src[ got[ 0 ].offset.. ], src );
+    a_id!( got[ 0 ].key, "subject" );
+    a_id!( &src[ got[ 1 ].offset.. ], r#"note:"x mode:fast" mode:medium"# );
+    a_id!( got[ 1 ].kind, SchemaErrorKind::UnknownKey );
+    a_id!( &src[ got[ 2 ].offset.. ], "medium" );
+    a_id!( got[ 2 ].key, "mode" );
+  }
+
+  //
+
+  #[ cfg( feature = "string_parse_request_derive" ) ]
+  fn derive()
+  {
+    use schema::FromRequest;
+
+    #[ derive( Debug, Default, PartialEq, schema::FromRequest ) ]
+    struct Run
+    {
+      #[ schema( subject ) ]
+      script : std::path::PathBuf,
+      #[ schema( default = 10 ) ]
+      limit : u8,
+      #[ schema( default = -1.5 ) ]
+      shift : f32,
+      #[ schema( variants( "fast", "slow" ) ) ]
+      mode : Option< String >,
+      tags : Vec< String >,
+      dry : bool,
+    }
+
+    let request = TheModule::string::request_parse()
+    .src( "run.sh mode:fast tags:[a, b] dry:true" )
+    .perform();
+    let got = Run::from_request( &request ).unwrap();
+    let exp = Run
+    {
+      script : "run.sh".into(),
+      limit : 10,
+      shift : -1.5,
+      mode : Some( "fast".into() ),
+      tags : vec![ "a".into(), "b".into() ],
+      dry : true,
+    };
+    a_id!( got, exp );
+
+    let src = "run.sh limit:300 mode:medium tags:a";
+    let request = TheModule::string::request_parse()
+    .src( src )
+    .perform();
+    let got = Run::from_request( &request ).unwrap_err();
+    a_id!( got.len(), 2 );
+    a_id!( got[ 0 ].key, "mode" );
+    a_id!( got[ 1 ].key, "dry" );
+
+    let src = "run.sh limit:300 tags:a dry:0";
+    let request = TheModule::string::request_parse()
+    .src( src )
+    .perform();
+    let got = Run::from_request( &request ).unwrap_err();
+    a_id!( got[ 0 ].kind, SchemaErrorKind::InvalidValue { expected : ValueType::Int, value : "300".into() } );
+    a_id!( &src[ got[ 0 ].offset.. ], "300 tags:a dry:0" );
+
+    /* settings of keys do not collide with attributes of other derives */
+    a_id!( Run::default().limit, 0 );
+
+    /* path to the crate could be given */
+    #[ derive( Debug, PartialEq, schema::FromRequest ) ]
+    #[ schema( crate = facade::strs ) ]
+    struct Build
+    {
+      #[ schema( variants( "debug", "release" ) ) ]
+      profile : String,
+    }
+
+    let request = TheModule::string::request_parse()
+    .src( "build profile:release" )
+    .perform();
+    let got = Build::from_request( &request ).unwrap();
+    a_id!( got, Build { profile : "release".into() } );
+  }
+}
+
+//
+
+tests_index!
+{
+  value_type_parse,
+  apply,
+  apply_unknown_and_invalid,
+  derive,
+}
@@ -341,6 +341,26 @@ tests_impls!
     exp.commands_delimeter = ";";
     a_id!( req, exp );
   }
+
+  //
+
+  fn offsets()
+  {
+    let src = " subj a:1 b:'x a:2' ; c:[ 3 ] ";
+    let req = TheModule::string::request_parse()
+    .src( src )
+    .perform();
+    a_id!( req.subjects_offsets, vec![ 1, 22 ] );
+    let ( key, value ) = req.maps_offsets[ 0 ][ "a" ];
+    a_id!( &src[ key.. ], "a:1 b:'x a:2' ; c:[ 3 ] " );
+    a_id!( &src[ value.. ], "1 b:'x a:2' ; c:[ 3 ] " );
+    let ( key, value ) = req.maps_offsets[ 0 ][ "b" ];
+    a_id!( &src[ key.. ], "b:'x a:2' ; c:[ 3 ] " );
+    a_id!( &src[ value.. ], "'x a:2' ; c:[ 3 ] " );
+    let ( key, value ) = req.maps_offsets[ 1 ][ "c" ];
+    a_id!( &src[ key.. ], "c:[ 3 ] " );
+    a_id!( &src[ value.. ], "[ 3 ] " );
+  }
 }
 
 //
@@ -352,4 +372,5 @@ tests_index!
   with_subject_and_map,
   with_several_values,
   with_parsing_arrays,
+  offsets,
 }
@@ -0,0 +1,230 @@
+use super::*;
+
+/// Splits of the stream read by chunks of each size up to the length of the source and the splits of the whole source.
+fn check< F >( src : &'static str, form : F )
+where
+  F : Fn( &'static str ) -> TheModule::string::split::SplitOptions< 'static, Vec< &'static str > >,
+{
+  let exp : Vec< ( String, usize ) > = form( src ).split().map( | e |
+  {
+    let offset = e.string.as_ptr() as usize - src.as_ptr() as usize;
+    ( String::from( e ), offset )
+  }).collect();
+
+  for capacity in 1..=src.len().max( 1 )
+  {
+    let reader = std::io::BufReader::with_capacity( capacity, src.as_bytes() );
+    let mut stream = form( "" ).split_stream( reader );
+    let mut got = vec![];
+    while let Some( split ) = stream.next_split()
+    {
+      let split = split.unwrap();
+      got.push( ( split.split.string.to_string(), split.offset ) );
+    }
+    assert_eq!( got, exp, "capacity : {}", capacity );
+  }
+}
+
+//
+
+tests_impls!
+{
+  fn basic()
+  {
+    let src = "a b c";
+    let mut stream = TheModule::string::split()
+    .delimeter( " " )
+    .stripping( false )
+    .perform_stream( src.as_bytes() );
+    let mut got = vec![];
+    while let Some( split ) = stream.next_split()
+    {
+      got.push( String::from( split.unwrap() ) );
+    }
+    assert_eq!( got, vec![ "a", " ", "b", " ", "c" ] );
+  }
+
+  //
+
+  fn same_as_split()
+  {
+    let srcs =
+    [
+      "",
+      "abc",
+      "a b c",
+      "  a  b  ",
+      "a,,b,c,",
+      ",a, b ,c",
+      "one two\nthree four\n",
+      "кошка собака, ёж",
+    ];
+    for src in srcs
+    {
+      check( src, | src | TheModule::string::split().src( src ).delimeter( " " ).form() );
+      check( src, | src | TheModule::string::split().src( src ).delimeter( vec![ " ", "," ] ).preserving_empty( false ).form() );
+      check( src, | src | TheModule::string::split().src( src ).delimeter( vec![ " ", "\n" ] ).preserving_delimeters( false ).stripping( true ).form() );
+      check( src, | src | TheModule::string::split().src( src ).delimeter( ", " ).preserving_empty( false ).preserving_delimeters( false ).form() );
+    }
+  }
+
+  //
+
+  fn same_as_split_with_quoting()
+  {
+    let srcs =
+    [
+      r#"a "b c" d"#,
+      r#""a b" "c d""#,
+      r#"a "b c"#,
+      r#"'a "b' c "d 'e" f"#,
+      "a `b\nc` d\ne",
+    ];
+    for src in srcs
+    {
+      check( src, | src | TheModule::string::split().src( src ).delimeter( " " ).quoting( true ).preserving_empty( false ).form() );
+      check( src, | src | TheModule::string::split().src( src ).delimeter( " " ).quoting( true ).preserving_quoting( false ).preserving_delimeters( false ).form() );
+      check( src, | src | TheModule::string::split().src( src ).delimeter( vec![ " ", "\n" ] ).quoting( true ).stripping( true ).form() );
+    }
+  }
+
+  //
+
+  fn same_as_split_with_escape_chars()
+  {
+    let srcs =
+    [
+      r"a\ b c",
+      r"a\\ b c",
+      r#"a "b \" c" d"#,
+      r"\",
+    ];
+    for src in srcs
+    {
+      check( src, | src | TheModule::string::split().src( src ).delimeter( " " ).escape_chars( vec![ '\\' ] ).preserving_empty( false ).form() );
+      check( src, | src | TheModule::string::split().src( src ).delimeter( " " ).escape_chars( vec![ '\\' ] ).quoting( true ).preserving_empty( false ).form() );
+    }
+  }
+
+  //
+
+  fn position()
+  {
+    let src = "ab cd\nef\n  gh\nжж ij";
+    let reader = std::io::BufReader::with_capacity( 2, src.as_bytes() );
+    let mut stream = TheModule::string::split()
+    .delimeter( vec![ " ", "\n" ] )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .perform_stream( reader );
+    let mut got = vec![];
+    while let Some( split ) = stream.next_split()
+    {
+      let split = split.unwrap();
+      got.push( ( split.split.string.to_string(), split.offset, split.line, split.column ) );
+    }
+    let exp = vec!
+    [
+      ( "ab".to_string(), 0, 1, 1 ),
+      ( "cd".to_string(), 3, 1, 4 ),
+      ( "ef".to_string(), 6, 2, 1 ),
+      ( "gh".to_string(), 11, 3, 3 ),
+      ( "жж".to_string(), 14, 4, 1 ),
+      ( "ij".to_string(), 19, 4, 4 ),
+    ];
+    assert_eq!( got, exp );
+  }
+
+  //
+
+  fn invalid_utf8()
+  {
+    let src : &[ u8 ] = &[ b'a', b' ', b'b', b' ', b'c', 0xff ];
+    let reader = std::io::BufReader::with_capacity( 2, src );
+    let mut stream = TheModule::string::split()
+    .delimeter( " " )
+    .stripping( false )
+    .perform_stream( reader );
+    let mut got = vec![];
+    let mut err = None;
+    while let Some( split ) = stream.next_split()
+    {
+      match split
+      {
+        Ok( split ) => got.push( String::from( split ) ),
+        Err( e ) => { err = Some( e ); break },
+      }
+    }
+    assert_eq!( got, vec![ "a", " " ] );
+    assert_eq!( err.unwrap().kind(), std::io::ErrorKind::InvalidData );
+
+    let src : &[ u8 ] = &[ b'a', 0xd0 ];
+    let mut stream = TheModule::string::split()
+    .delimeter( " " )
+    .perform_stream( src );
+    assert_eq!( stream.next_split().unwrap().unwrap_err().kind(), std::io::ErrorKind::InvalidData );
+  }
+
+  //
+
+  fn unclosed_quote()
+  {
+    // quote is not closed, but the stream is short enough
+    check( "a 'b c d", | src | TheModule::string::split().src( src ).delimeter( " " ).quoting( true ).form() );
+
+    // quote is not closed within the limit, data after it is not read
+    let src = format!( "a b 'c{}", " d".repeat( 100 ) );
+    let reader = std::io::BufReader::with_capacity( 3, src.as_bytes() );
+    let mut stream = TheModule::string::split()
+    .delimeter( " " )
+    .quoting( true )
+    .preserving_delimeters( false )
+    .preserving_empty( false )
+    .perform_stream( reader )
+    .quote_limit( 16 );
+    let mut got = vec![];
+    let mut err = None;
+    while let Some( split ) = stream.next_split()
+    {
+      match split
+      {
+        Ok( split ) => got.push( String::from( split ) ),
+        Err( e ) => { err = Some( e ); break },
+      }
+    }
+    assert_eq!( got, vec![ "a", "b" ] );
+    let err = err.unwrap();
+    assert_eq!( err.kind(), std::io::ErrorKind::InvalidData );
+    assert_eq!( err.to_string(), "quote at offset 4 is not closed within 16 bytes" );
+
+    // closed quote longer than chunks but within the limit
+    let src = "a 'b c d' e";
+    let reader = std::io::BufReader::with_capacity( 2, src.as_bytes() );
+    let mut stream = TheModule::string::split()
+    .delimeter( " " )
+    .quoting( true )
+    .preserving_delimeters( false )
+    .preserving_empty( false )
+    .perform_stream( reader )
+    .quote_limit( 8 );
+    let mut got = vec![];
+    while let Some( split ) = stream.next_split()
+    {
+      got.push( String::from( split.unwrap() ) );
+    }
+    assert_eq!( got, vec![ "a", "'b c d'", "e" ] );
+  }
+}
+
+//
+
+tests_index!
+{
+  basic,
+  same_as_split,
+  same_as_split_with_quoting,
+  same_as_split_with_escape_chars,
+  position,
+  invalid_utf8,
+  unclosed_quote,
+}
@@ -378,6 +378,251 @@ tests_impls!
     .perform();
     assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", "b", "c" ] );
   }
+
+  //
+
+  fn split_with_searcher()
+  {
+    use TheModule::string::split::{ CharPredicate, Alternatives };
+
+    /* char */
+
+    let src = "a,b;c";
+    let iter = TheModule::string::split()
+    .src( src )
+    .stripping( false )
+    .form()
+    .searcher( ',' )
+    .split();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", ",", "b;c" ] );
+
+    /* set of chars */
+
+    let src = "a,b;c";
+    let iter = TheModule::string::split()
+    .src( src )
+    .stripping( false )
+    .preserving_delimeters( false )
+    .form()
+    .searcher( &[ ',', ';' ][ .. ] )
+    .split();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", "b", "c" ] );
+
+    /* predicate */
+
+    let src = "a  b\tc";
+    let iter = TheModule::string::split()
+    .src( src )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .form()
+    .searcher( CharPredicate( char::is_whitespace ) )
+    .split();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", "b", "c" ] );
+
+    /* alternatives, the longest wins */
+
+    let src = "a=b==c!=d";
+    let iter = TheModule::string::split()
+    .src( src )
+    .stripping( false )
+    .form()
+    .searcher( Alternatives( vec![ "=", "==", "!=" ] ) )
+    .split();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", "=", "b", "==", "c", "!=", "d" ] );
+
+    /* quoting with searcher */
+
+    let src = "a 'b c'  d";
+    let iter = TheModule::string::split()
+    .src( src )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .form()
+    .searcher( CharPredicate( char::is_whitespace ) )
+    .split();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", "'b c'", "d" ] );
+  }
+
+  //
+
+  #[ cfg( feature = "string_split_regex" ) ]
+  fn split_with_regex()
+  {
+    let src = "a1b22c333d";
+    let iter = TheModule::string::split()
+    .src( src )
+    .stripping( false )
+    .form()
+    .searcher( regex::Regex::new( "[0-9]+" ).unwrap() )
+    .split();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", "1", "b", "22", "c", "333", "d" ] );
+
+    let src = "a , b,c ,  d";
+    let iter = TheModule::string::split()
+    .src( src )
+    .preserving_delimeters( false )
+    .form()
+    .searcher( regex::Regex::new( r"\s*,\s*" ).unwrap() )
+    .split();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", "b", "c", "d" ] );
+
+    /* empty matches split off characters wider than a byte */
+    let src = "жaж";
+    let iter = TheModule::string::split()
+    .src( src )
+    .form()
+    .searcher( regex::Regex::new( "x*" ).unwrap() )
+    .split();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "", "", "ж", "", "a", "", "ж", "", "" ] );
+
+    let iter = TheModule::string::split()
+    .src( src )
+    .preserving_empty( false )
+    .form()
+    .searcher( regex::Regex::new( "x*" ).unwrap() )
+    .split();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "ж", "a", "ж" ] );
+  }
+
+  //
+
+  fn split_with_option_escape_chars()
+  {
+    /* escaped delimeter */
+
+    let src = r"a\ b c";
+    let iter = TheModule::string::split()
+    .src( src )
+    .delimeter( " " )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .escape_chars( vec![ '\\' ] )
+    .perform();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ r"a\ b", "c" ] );
+
+    /* escaped escape character does not escape */
+
+    let src = r"a\\ b";
+    let iter = TheModule::string::split()
+    .src( src )
+    .delimeter( " " )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .escape_chars( vec![ '\\' ] )
+    .perform();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ r"a\\", "b" ] );
+
+    /* escaped quote */
+
+    let src = r#"a "b \" c" d"#;
+    let iter = TheModule::string::split()
+    .src( src )
+    .delimeter( " " )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .preserving_quoting( false )
+    .escape_chars( vec![ '\\' ] )
+    .perform();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", r#"b \" c"#, "d" ] );
+
+    /* without escaping */
+
+    let src = r"a\ b c";
+    let iter = TheModule::string::split()
+    .src( src )
+    .delimeter( " " )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .perform();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ r"a\", "b", "c" ] );
+  }
+
+  //
+
+  fn split_with_quoting_pairs()
+  {
+    let src = r#"a [b c] "d e" 'f g'"#;
+    let iter = TheModule::string::split()
+    .src( src )
+    .delimeter( " " )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .quoting_prefixes( vec![ "[", "\"", "'" ] )
+    .quoting_postfixes( vec![ "]", "\"", "'" ] )
+    .perform();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a", "[b c]", r#""d e""#, "'f g'" ] );
+
+    /* quotes of other pairs are not special inside quoting */
+
+    let src = r#"[a "b] c"#;
+    let iter = TheModule::string::split()
+    .src( src )
+    .delimeter( " " )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .preserving_quoting( false )
+    .quoting_prefixes( vec![ "[", "\"" ] )
+    .quoting_postfixes( vec![ "]", "\"" ] )
+    .perform();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ r#"a "b"#, "c" ] );
+
+    /* postfix outside of quoting is not a delimeter */
+
+    let src = "a] b";
+    let iter = TheModule::string::split()
+    .src( src )
+    .delimeter( " " )
+    .preserving_empty( false )
+    .preserving_delimeters( false )
+    .quoting_prefixes( vec![ "[" ] )
+    .quoting_postfixes( vec![ "]" ] )
+    .perform();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "a]", "b" ] );
+
+    /* csv-like */
+
+    let src = r#"1,"a, b",[c,d]"#;
+    let iter = TheModule::string::split()
+    .src( src )
+    .delimeter( "," )
+    .stripping( false )
+    .preserving_delimeters( false )
+    .preserving_quoting( false )
+    .quoting_prefixes( vec![ "\"", "[" ] )
+    .quoting_postfixes( vec![ "\"", "]" ] )
+    .perform();
+    assert_eq!( iter.map( | e | String::from( e ) ).collect::< Vec< _ > >(), vec![ "1", "a, b", "c,d" ] );
+  }
+  //
+
+  fn split_types_with_quoting()
+  {
+    use TheModule::string::split::SplitType;
+
+    /* delimeters which are not quoting prefixes keep their type */
+
+    let src = "a 'b c' d";
+    let iter = TheModule::string::split()
+    .src( src )
+    .delimeter( vec![ " ", "'" ] )
+    .stripping( false )
+    .preserving_empty( false )
+    .preserving_delimeters( true )
+    .preserving_quoting( true )
+    .quoting( true )
+    .perform();
+    let got = iter.map( | e | ( String::from( e.string ), matches!( e.typ, SplitType::Delimeter ) ) ).collect::< Vec< _ > >();
+    let exp = vec!
+    [
+      ( "a".to_string(), false ),
+      ( " ".to_string(), true ),
+      ( "'b c'".to_string(), false ),
+      ( " ".to_string(), true ),
+      ( "d".to_string(), false ),
+    ];
+    assert_eq!( got, exp );
+  }
 }
 
 //
@@ -392,4 +637,9 @@ tests_index!
   split_with_option_stripping,
   split_with_option_quoting,
   basic_split_with_vector,
+  split_with_searcher,
+  split_with_regex,
+  split_with_option_escape_chars,
+  split_with_quoting_pairs,
+  split_types_with_quoting,
 }
@@ -0,0 +1,38 @@
+// #![ cfg_attr( feature = "no_std", no_std ) ]
+#![ doc( html_logo_url = "https://raw.githubusercontent.com/Wandalen/wTools/master/asset/img/logo_v3_trans_square.png" ) ]
+#![ doc( html_favicon_url = "https://raw.githubusercontent.com/Wandalen/wTools/alpha/asset/img/logo_v3_trans_square_icon_small_v2.ico" ) ]
+#![ doc( html_root_url = "https://docs.rs/strs_tools_meta/latest/strs_tools_meta/" ) ]
+// #![ deny( rust_2018_idioms ) ]
+// #![ deny( missing_debug_implementations ) ]
+// #![ deny( missing_docs ) ]
+
+//!
+//! Derive of typed records of requests parsed by strs_tools.
+//!
+
+#![ doc = include_str!( concat!( env!( "CARGO_MANIFEST_DIR" ), "/", "Readme.md" ) ) ]
+
+#[ cfg( feature = "enabled" ) ]
+mod meta_impl;
+
+///
+/// Derive `strs_tools::string::parse_schema::FromRequest` for a structure with named fields.
+///
+/// Each field is a key of the command, type of the field should implement `FromValue`. Field of `Option` is optional key.
+/// Attribute `#[ schema( .. ) ]` sets the key : `default = ..` sets its default, `variants( "a", "b" )` restricts value
+/// of a string field by the variants and `subject` marks the field which gets the subject of the command.
+/// Path to crate `strs_tools` used by generated code could be given by attribute of the structure, for example
+/// `#[ schema( crate = my_facade::strs_tools ) ]`.
+///
+
+#[ cfg( feature = "enabled" ) ]
+#[ proc_macro_derive( FromRequest, attributes( schema ) ) ]
+pub fn from_request( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
+{
+  let result = meta_impl::from_request( input );
+  match result
+  {
+    Ok( stream ) => stream.into(),
+    Err( err ) => err.to_compile_error().into(),
+  }
+}
@@ -0,0 +1,207 @@
+use macro_tools::prelude::*;
+use macro_tools::Token;
+use macro_tools::proc_macro2::TokenStream;
+pub type Result< T > = std::result::Result< T, syn::Error >;
+
+///
+/// Key of the command described by a field.
+///
+
+struct FieldKey
+{
+  ident : syn::Ident,
+  ty : syn::Type,
+  /// Name of the key, the same as name of the field.
+  name : String,
+  /// Text of default value.
+  default : Option< String >,
+  /// Variants of the value.
+  variants : Option< Vec< String > >,
+  /// Does the field get subject of the command.
+  subject : bool,
+}
+
+///
+/// Setting of the key in attribute `#[ schema( .. ) ]` of a field.
+///
+
+enum Setting
+{
+  /// `default = 10` sets text of default of the key.
+  Default( String ),
+  /// `variants( "a", "b" )` restricts value of a string field.
+  Variants( Vec< String > ),
+  /// `subject` marks the field which gets the subject of the command.
+  Subject,
+}
+
+impl syn::parse::Parse for Setting
+{
+  fn parse( input : syn::parse::ParseStream< '_ > ) -> Result< Self >
+  {
+    let name : syn::Ident = input.call( syn::ext::IdentExt::parse_any )?;
+    match name.to_string().as_str()
+    {
+      "default" =>
+      {
+        input.parse::< Token![ = ] >()?;
+        let expr : syn::Expr = input.parse()?;
+        let text = match &expr
+        {
+          syn::Expr::Lit( syn::ExprLit { lit : syn::Lit::Str( lit ), .. } ) => lit.value(),
+          _ => qt!{ #expr }.to_string().replace( ' ', "" ),
+        };
+        Ok( Setting::Default( text ) )
+      },
+      "variants" =>
+      {
+        let content;
+        syn::parenthesized!( content in input );
+        let variants = syn::punctuated::Punctuated::< syn::LitStr, Token![ , ] >::parse_terminated( &content )?;
+        if variants.is_empty()
+        {
+          return Err( syn_err!( name, "Expects at least one variant, like `#[ schema( variants( \"a\", \"b\" ) ) ]`" ) );
+        }
+        Ok( Setting::Variants( variants.iter().map( syn::LitStr::value ).collect() ) )
+      },
+      "subject" => Ok( Setting::Subject ),
+      _ => Err( syn_err!( name, "Unknown setting `{}` of key, expects `default = ..`, `variants( .. )` or `subject`", name ) ),
+    }
+  }
+}
+
+impl FieldKey
+{
+  fn from_field( field : &syn::Field ) -> Result< Self >
+  {
+    let ident = field.ident.clone().ok_or_else( || syn_err!( field, "Expects named field" ) )?;
+    let name = ident.to_string().trim_start_matches( "r#" ).to_string();
+    let mut result = Self { ident, ty : field.ty.clone(), name, default : None, variants : None, subject : false };
+
+    // settings are namespaced by `schema`, so they do not collide with attributes of other derives, like `Default`
+    for attr in field.attrs.iter().filter( | attr | attr.path.is_ident( "schema" ) )
+    {
+      let settings = attr.parse_args_with( syn::punctuated::Punctuated::< Setting, Token![ , ] >::parse_terminated )?;
+      for setting in settings
+      {
+        match setting
+        {
+          Setting::Default( text ) => result.default = Some( text ),
+          Setting::Variants( variants ) => result.variants = Some( variants ),
+          Setting::Subject => result.subject = true,
+        }
+      }
+    }
+
+    Ok( result )
+  }
+
+  /// Expression of key of schema, the module is path to module `parse_schema`.
+  fn key( &self, module : &TokenStream ) -> TokenStream
+  {
+    let FieldKey { ty, name, .. } = self;
+    let typ = match &self.variants
+    {
+      Some( variants ) => qt!{ < #ty as #module::FromValue >::value_type().enumerate( ::std::vec![ #( #variants.to_string() ),* ] ) },
+      None => qt!{ < #ty as #module::FromValue >::value_type() },
+    };
+    let default = self.default.as_ref().map( | default | qt!{ .default( #default ) } );
+    qt!
+    {
+      #module::Key::new( #name, #typ )
+      .optional( < #ty as #module::FromValue >::optional() )
+      #default
+    }
+  }
+}
+
+///
+/// Path to crate `strs_tools` used by generated code.
+///
+/// Given by option `crate` of attribute `#[ schema( .. ) ]` of the structure, for example `#[ schema( crate = my_facade::strs_tools ) ]`.
+/// By default `::strs_tools`.
+///
+
+fn crate_path( attrs : &[ syn::Attribute ] ) -> Result< syn::Path >
+{
+  let mut result = macro_tools::crate_path_default( "strs_tools" );
+  for attr in attrs.iter().filter( | attr | attr.path.is_ident( "schema" ) )
+  {
+    result = attr.parse_args_with( | input : syn::parse::ParseStream< '_ > |
+    {
+      let path = macro_tools::crate_option( input )?.ok_or_else( || input.error( "Unknown setting of structure, expects `crate = path`" ) )?;
+      input.parse::< Option< Token![ , ] > >()?;
+      Ok( path )
+    })?;
+  }
+  Ok( result )
+}
+
+//
+
+pub fn from_request( input : proc_macro::TokenStream ) -> Result< TokenStream >
+{
+  let input = syn::parse::< syn::DeriveInput >( input )?;
+  let fields = match &input.data
+  {
+    syn::Data::Struct( syn::DataStruct { fields : syn::Fields::Named( fields ), .. } ) => &fields.named,
+    _ => return Err( syn_err!( input.ident, "Expects structure with named fields" ) ),
+  };
+  let fields = fields.iter().map( FieldKey::from_field ).collect::< Result< Vec< _ > > >()?;
+
+  let mut subjects = fields.iter().filter( | field | field.subject );
+  let subject = subjects.next();
+  if let Some( extra ) = subjects.next()
+  {
+    return Err( syn_err!( extra.ident, "Only one field could be `#[ schema( subject ) ]`" ) );
+  }
+
+  let krate = crate_path( &input.attrs )?;
+  let module = qt!{ #krate::string::parse_schema };
+  let subject_key = subject.map( | field |
+  {
+    let key = field.key( &module );
+    qt!{ .subject( #key ) }
+  });
+  let keys = fields.iter().filter( | field | !field.subject ).map( | field | field.key( &module ) );
+  let takes = fields.iter().map( | field |
+  {
+    let FieldKey { ident, ty, name, .. } = field;
+    if field.subject
+    {
+      qt!{ let #ident = values.take_subject::< #ty >( #name, &mut errors ); }
+    }
+    else
+    {
+      qt!{ let #ident = values.take::< #ty >( #name, &mut errors ); }
+    }
+  });
+  let idents = fields.iter().map( | field | &field.ident );
+
+  let name = &input.ident;
+  let ( impl_generics, ty_generics, where_clause ) = input.generics.split_for_impl();
+  let result = qt!
+  {
+    impl #impl_generics #module::FromRequest for #name #ty_generics #where_clause
+    {
+      fn schema() -> #module::Schema
+      {
+        #module::Schema::new()
+        #( .key( #keys ) )*
+        #subject_key
+      }
+
+      fn from_values( mut values : #module::Values ) -> ::core::result::Result< Self, ::std::vec::Vec< #module::SchemaError > >
+      {
+        let mut errors = ::std::vec![];
+        #( #takes )*
+        if !errors.is_empty()
+        {
+          return ::core::result::Result::Err( errors );
+        }
+        ::core::result::Result::Ok( Self { #( #idents : #idents.unwrap() ),* } )
+      }
+    }
+  };
+  Ok( result )
+}
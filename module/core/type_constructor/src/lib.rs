@@ -48,6 +48,10 @@ pub mod type_constuctor;
 #[ cfg( feature = "enabled" ) ]
 pub mod dependency
 {
+  #[ cfg( feature = "proc_macro" ) ]
+  pub use ::type_constructor_meta;
+  #[ cfg( feature = "serde" ) ]
+  pub use ::serde;
 }
 
 #[ doc( inline ) ]
@@ -36,6 +36,21 @@ pub( crate ) mod private
     }}
   }
 
+  ///
+  /// Generate code only if type constructor `many` is available.
+  ///
+  /// Do not use manually.
+  ///
+
+  #[ macro_export ]
+  macro_rules! _if_many
+  {
+    ( $( $Rest : tt )* ) =>
+    {
+      $( $Rest )*
+    };
+  }
+
   ///
   /// Type constructor of many.
   ///
@@ -540,6 +555,7 @@ pub( crate ) mod private
 
   pub use _vec;
   pub use _many;
+  pub use _if_many;
 }
 
 /// Protected namespace of the module.
@@ -575,6 +591,7 @@ pub mod exposed
     _many,
     _vec,
     _Vec,
+    _if_many,
   };
 }
 
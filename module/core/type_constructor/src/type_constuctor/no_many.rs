@@ -25,7 +25,30 @@ pub( crate ) mod private
     }
   }
 
+  ///
+  /// Generate code only if type constructor `many` is available.
+  ///
+  /// Do not use manually.
+  ///
+
+  #[ macro_export ]
+  macro_rules! _if_many
+  {
+    ( $( $Rest:tt )* )
+    =>
+    {
+      compile_error!
+      (
+        concat!
+        (
+          "! Type constructor `many` is available if eiter feature `use_std` or feature `use_alloc` is enabled. Also feature `many` should be enabled.\n",
+        )
+      );
+    }
+  }
+
   pub use _many;
+  pub use _if_many;
 }
 
 /// Protected namespace of the module.
@@ -60,6 +83,7 @@ pub mod exposed
   pub use super::private::
   {
     _many,
+    _if_many,
   };
 
 }
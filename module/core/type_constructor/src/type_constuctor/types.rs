@@ -807,7 +807,49 @@ pub( crate ) mod private
 
   }
 
+  ///
+  /// Type constructor to define tuple wrapping a given type, implemented by procedural macro.
+  ///
+  /// Accepts the same syntax as macro `types` implemented by `macro_rules!`. Besides that, where-clause could be written
+  /// after the element to apply it to the type and all its implementations, and errors point to the place in the definition which caused them.
+  /// Macro `types` is not affected by feature `proc_macro`, so crates using it are not affected by other crates enabling the feature.
+  /// Only this macro accepts attribute `by_element( .. )` and implements `serde` traits with feature `serde`.
+  ///
+  /// ```rust
+  /// use type_constructor::prelude::*;
+  ///
+  /// types_proc!
+  /// {
+  ///   /// Wrapper of a vector, which elements could be compared.
+  ///   #[ derive( Debug, Clone, PartialEq ) ]
+  ///   pub single Wrapper : std::vec::Vec< T > where T : PartialEq;
+  /// }
+  ///
+  /// let x = Wrapper::from( vec![ 1, 2, 3 ] );
+  /// assert_eq!( x.len(), 3 );
+  /// ```
+  ///
+
+  #[ cfg( feature = "proc_macro" ) ]
+  #[ macro_export ]
+  macro_rules! types_proc
+  {
+    (
+      $( $Rest : tt )*
+    )
+    =>
+    {
+      $crate::dependency::type_constructor_meta::types!
+      {
+        $crate ;
+        $( $Rest )*
+      }
+    };
+  }
+
   pub use types;
+  #[ cfg( feature = "proc_macro" ) ]
+  pub use types_proc;
 }
 
 /// Protected namespace of the module.
@@ -851,4 +893,11 @@ pub mod prelude
   {
     types,
   };
+  #[ doc( inline ) ]
+  #[ allow( unused_imports ) ]
+  #[ cfg( feature = "proc_macro" ) ]
+  pub use super::private::
+  {
+    types_proc,
+  };
 }
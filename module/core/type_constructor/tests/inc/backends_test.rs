@@ -0,0 +1,128 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+// the same definitions are given to macro `types` and to macro `types_proc`, and types they generate are checked by the same test
+
+macro_rules! backends
+{
+  (
+    $Test : literal ;
+    $( $Definitions : tt )*
+  )
+  =>
+  {
+    mod declarative
+    {
+      #[ allow( unused_imports ) ]
+      use super::*;
+      TheModule::types!{ $( $Definitions )* }
+      include!( $Test );
+    }
+    mod procedural
+    {
+      #[ allow( unused_imports ) ]
+      use super::*;
+      TheModule::types_proc!{ $( $Definitions )* }
+      include!( $Test );
+    }
+  };
+}
+
+macro_rules! mk
+{
+  (
+    $( $Rest : tt )*
+  )
+  =>
+  {
+    mod1::Floats::from( $( $Rest )* )
+  };
+}
+
+mod mod1
+{
+
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct Floats< T1 : PartialEq + Copy, T2 : Default >
+  (
+    pub T1,
+    pub T2,
+  );
+
+  impl< T1 : PartialEq + Copy, T2 : Default > core::ops::Deref
+  for Floats< T1, T2 >
+  {
+    type Target = T1;
+    fn deref( &self ) -> &Self::Target
+    {
+      &self.0
+    }
+  }
+
+  impl< T1 : PartialEq + Copy, T2 : Default > From< T1 >
+  for Floats< T1, T2 >
+  {
+    fn from( src : T1 ) -> Self
+    {
+      Floats::< T1, T2 >( src, T2::default() )
+    }
+  }
+
+}
+
+mod single_parameter
+{
+  use super::*;
+  backends!
+  {
+    "./single/single_parameter_main_test_only.rs";
+    #[ derive( Debug, Clone ) ]
+    #[ derive( PartialEq, Default ) ]
+    single Single : < T >;
+  }
+}
+
+mod single_parametrized
+{
+  use super::*;
+  backends!
+  {
+    "./single/single_parametrized_main_test_only.rs";
+    #[ derive( Debug, Clone ) ]
+    #[ derive( PartialEq ) ]
+    single Single : mod1::Floats< T1 : PartialEq + std::marker::Copy, T2 : Default >;
+  }
+}
+
+mod pair_struct
+{
+  use super::*;
+  backends!
+  {
+    "./pair/pair_struct_main_test_only.rs";
+    pair Pair1 : f64, f32;
+    #[ derive( Debug ) ]
+    #[ derive( PartialEq, Clone ) ]
+    pair Pair2 : f32, f64;
+    #[ derive( Debug, Clone, PartialEq, Default ) ]
+    pair HomoPair : < T >;
+    #[ derive( Debug, Clone, PartialEq ) ]
+    pair Pair3 :
+      mod1::Floats< T1 : PartialEq + std::marker::Copy, T2 : Default >,
+      std::sync::Arc< T : Copy >,
+    ;
+  }
+}
+
+#[ cfg( any( feature = "many", feature = "dt_many" ) ) ]
+mod many_parametrized
+{
+  use super::*;
+  backends!
+  {
+    "./many/many_parametrized_main_test_only.rs";
+    #[ derive( Debug, Clone ) ]
+    #[ derive( PartialEq ) ]
+    many Many : mod1::Floats< T1 : PartialEq + std::marker::Copy, T2 : Default >;
+  }
+}
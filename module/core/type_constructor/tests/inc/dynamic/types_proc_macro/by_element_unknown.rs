@@ -0,0 +1,13 @@
+use type_constructor as TheModule;
+use TheModule::prelude::*;
+
+types_proc!
+{
+  #[ derive( Debug ) ]
+  #[ by_element( PartialEq, Debug ) ]
+  pair Pair : i32, i64;
+}
+
+fn main()
+{
+}
@@ -0,0 +1,11 @@
+use type_constructor as TheModule;
+use TheModule::prelude::*;
+
+types_proc!
+{
+  many Many : < 'a >;
+}
+
+fn main()
+{
+}
@@ -0,0 +1,12 @@
+use type_constructor as TheModule;
+use TheModule::prelude::*;
+
+types_proc!
+{
+  single Single1 : f32
+  single Single2 : f64;
+}
+
+fn main()
+{
+}
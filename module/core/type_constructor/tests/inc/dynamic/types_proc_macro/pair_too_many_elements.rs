@@ -0,0 +1,12 @@
+use type_constructor as TheModule;
+use TheModule::prelude::*;
+
+types_proc!
+{
+  #[ derive( Debug ) ]
+  pair Triple : f32, f64, i32;
+}
+
+fn main()
+{
+}
@@ -0,0 +1,11 @@
+use type_constructor as TheModule;
+use TheModule::prelude::*;
+
+types_proc!
+{
+  single Single : < T1, T2 >;
+}
+
+fn main()
+{
+}
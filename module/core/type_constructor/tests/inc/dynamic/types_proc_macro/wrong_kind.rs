@@ -0,0 +1,12 @@
+use type_constructor as TheModule;
+use TheModule::prelude::*;
+
+types_proc!
+{
+  single Single : f32;
+  wrong_kind Wrong : std::sync::Arc< T : Copy >;
+}
+
+fn main()
+{
+}
@@ -76,6 +76,27 @@ mod many
   mod many_parametrized_test;
 }
 
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "proc_macro" ) ]
+#[ cfg( any( not( feature = "no_std" ), feature = "use_alloc" ) ) ]
+mod proc_macro_test;
+
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "proc_macro" ) ]
+#[ cfg( any( not( feature = "no_std" ), feature = "use_alloc" ) ) ]
+mod backends_test;
+
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "proc_macro" ) ]
+#[ cfg( feature = "serde" ) ]
+#[ cfg( not( feature = "no_std" ) ) ]
+mod serde_test;
+
+#[ cfg( feature = "enabled" ) ]
+#[ cfg( feature = "proc_macro" ) ]
+#[ cfg( not( feature = "no_std" ) ) ]
+mod compiletime_test;
+
 // #[ cfg( feature = "enabled" ) ]
 // #[ cfg( any( feature = "make", feature = "dt_make" ) ) ]
 // mod make_interface_test;
@@ -2,17 +2,20 @@
 use super::*;
 
 // trace_macros!( true );
-// TheModule::types!
-// {
+#[ cfg( feature = "proc_macro" ) ]
+TheModule::types_proc!
+{
 
-//   pair Pair1 : f64, f32;
+  pair Pair1 : f64, f32;
 
-//   #[ derive( Debug ) ]
-//   #[ derive( PartialEq, Clone ) ]
-//   pair Pair2 : f32, f64;
+  #[ derive( Debug ) ]
+  #[ derive( PartialEq, Clone ) ]
+  pair Pair2 : f32, f64;
 
-// }
+}
 // trace_macros!( false );
 
-// include!( "./pair_parameter_main_test_only.rs" );
+#[ cfg( feature = "proc_macro" ) ]
+include!( "./pair_parameter_main_test_only.rs" );
+#[ cfg( not( feature = "proc_macro" ) ) ]
 include!( "./pair_parameter_main_manual_test.rs" );
@@ -0,0 +1,35 @@
+tests_impls!
+{
+  fn main()
+  {
+    use core::fmt;
+
+    /* test.case( "pair of types" ) */
+    let instance1 = Pair1( 13.0, 31.0 );
+    a_id!( instance1.0, 13.0 );
+    a_id!( instance1.1, 31.0 );
+    assert!( !implements!( instance1 => fmt::Debug ) );
+    let instance1 = Pair2( 13.0, 31.0 );
+    a_id!( instance1.clone(), Pair2( 13.0, 31.0 ) );
+    a_id!( format!( "{:?}", instance1 ), "Pair2(13.0, 31.0)" );
+
+    /* test.case( "homopair of parameter" ) */
+    let instance1 = HomoPair::< f32 >( 13.0, 31.0 );
+    a_id!( instance1.clone(), HomoPair( 13.0, 31.0 ) );
+    a_id!( format!( "{:?}", instance1 ), "HomoPair(13.0, 31.0)" );
+    a_id!( HomoPair::< f32 >::default(), HomoPair( 0.0, 0.0 ) );
+
+    /* test.case( "pair of parametrized types" ) */
+    let instance1 = Pair3::< f32, f64, f32 >( mk!( 13.0 ), std::sync::Arc::new( 31.0 ) );
+    a_id!( instance1.clone(), Pair3( mk!( 13.0 ), std::sync::Arc::new( 31.0 ) ) );
+    a_id!( *instance1.0, 13.0 );
+    a_id!( *instance1.1, 31.0 );
+  }
+}
+
+//
+
+tests_index!
+{
+  main,
+}
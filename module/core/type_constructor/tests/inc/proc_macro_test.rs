@@ -0,0 +1,221 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+mod mod1
+{
+  use super::*;
+
+  TheModule::types_proc!
+  {
+
+    ///
+    /// Single with where-clause.
+    ///
+
+    #[ derive( Debug, Clone, PartialEq ) ]
+    pub single Ordered : std::vec::Vec< T > where T : PartialOrd;
+
+    ///
+    /// Homopair with where-clause on its parameter.
+    ///
+
+    #[ derive( Debug, Clone, PartialEq, Default ) ]
+    pub pair Segment : < T > where T : Copy + Default;
+
+    ///
+    /// Pair of distinct parametrized elements, bounds of parameter used by both elements are joined.
+    ///
+
+    #[ derive( Debug, Clone, PartialEq ) ]
+    pub pair Keyed : std::sync::Arc< K : core::fmt::Debug >, std::collections::HashMap< K : core::hash::Hash + Eq, V : PartialEq >;
+
+    ///
+    /// Single with a lifetime.
+    ///
+
+    #[ derive( Debug, Clone, Copy, PartialEq ) ]
+    pub single Name : Ref< 'a >;
+
+    ///
+    /// Many with where-clause.
+    ///
+
+    #[ derive( Debug, Clone, PartialEq ) ]
+    pub many Numbers : Number< T > where T : Copy;
+
+    ///
+    /// Comparison and hashing implemented by elements.
+    ///
+
+    #[ derive( Debug, Clone ) ]
+    #[ by_element( PartialEq, Eq, PartialOrd, Ord, Hash ) ]
+    pub pair Version : < Major, Minor >;
+
+    ///
+    /// Many ordered and hashed by elements.
+    ///
+
+    #[ derive( Debug, Clone ) ]
+    #[ by_element( PartialEq, Eq, PartialOrd, Ord, Hash ) ]
+    pub many Path : < T >;
+
+  }
+
+  /// Reference to a string.
+  pub type Ref< 'a > = &'a str;
+
+  /// Number of generic type.
+  #[ derive( Debug, Clone, Copy, PartialEq ) ]
+  pub struct Number< T >( pub T );
+
+  impl< T > From< T > for Number< T >
+  {
+    fn from( src : T ) -> Self
+    {
+      Self( src )
+    }
+  }
+
+}
+
+//
+
+tests_impls!
+{
+
+  fn single_where()
+  {
+    use core::fmt;
+    use TheModule::{ AsTuple, CloneAsArray };
+
+    /* test.case( "from element" ) */
+    let got = mod1::Ordered::from( vec![ 1, 3, 2 ] );
+    a_id!( got.0, vec![ 1, 3, 2 ] );
+    a_id!( got.len(), 3 );
+    a_id!( got.as_tuple(), &( vec![ 1, 3, 2 ], ) );
+    a_id!( got.clone_as_array(), [ vec![ 1, 3, 2 ] ] );
+
+    /* test.case( "into element" ) */
+    let got : Vec< i32 > = mod1::Ordered::from( &vec![ 1, 2 ] ).into();
+    a_id!( got, vec![ 1, 2 ] );
+
+    /* test.case( "derives" ) */
+    let got = mod1::Ordered::from( vec![ 1.0 ] );
+    assert!( implements!( got => fmt::Debug ) );
+    assert!( implements!( got => Clone ) );
+    assert!( implements!( got => PartialEq ) );
+    assert!( !implements!( got => Default ) );
+  }
+
+  //
+
+  fn homopair_where()
+  {
+    use TheModule::{ AsArray, CloneAsTuple };
+
+    /* test.case( "from tuple and array" ) */
+    let got = mod1::Segment::from( ( 1, 2 ) );
+    a_id!( got, mod1::Segment( 1, 2 ) );
+    let got = mod1::Segment::from( [ 1, 2 ] );
+    a_id!( got, mod1::Segment( 1, 2 ) );
+    let got = mod1::Segment::from( 3 );
+    a_id!( got, mod1::Segment( 3, 3 ) );
+
+    /* test.case( "into tuple and array" ) */
+    let got : ( i32, i32 ) = mod1::Segment( 1, 2 ).into();
+    a_id!( got, ( 1, 2 ) );
+    let got : [ i32 ; 2 ] = mod1::Segment( 1, 2 ).into();
+    a_id!( got, [ 1, 2 ] );
+
+    /* test.case( "deref and as" ) */
+    let mut got = mod1::Segment( 1, 2 );
+    a_id!( *got, ( 1, 2 ) );
+    got.1 = 3;
+    a_id!( got.as_array(), &[ 1, 3 ] );
+    a_id!( got.clone_as_tuple(), ( 1, 3 ) );
+    a_id!( mod1::Segment::< u8 >::default(), mod1::Segment( 0, 0 ) );
+
+    /* test.case( "variadic constructor" ) */
+    let got : mod1::Segment< i32 > = TheModule::From_2::from_2( 1, 2 );
+    a_id!( got, mod1::Segment( 1, 2 ) );
+  }
+
+  //
+
+  fn pair_shared_parameter()
+  {
+    use std::sync::Arc;
+    use std::collections::HashMap;
+
+    let map : HashMap< &str, i32 > = HashMap::from( [ ( "a", 1 ) ] );
+    let got = mod1::Keyed::from( ( Arc::new( "a" ), map.clone() ) );
+    a_id!( *got.0, "a" );
+    a_id!( got.1, map );
+    let got : ( Arc< &str >, HashMap< &str, i32 > ) = got.into();
+    a_id!( got.1, map );
+  }
+
+  //
+
+  fn single_lifetime()
+  {
+    let src = String::from( "name" );
+    let got = mod1::Name::from( src.as_str() );
+    a_id!( *got, "name" );
+    let got : &str = got.into();
+    a_id!( got, "name" );
+  }
+
+  //
+
+  fn many_where()
+  {
+    use TheModule::AsSlice;
+
+    let got = mod1::Numbers::from( [ 1, 2, 3 ] );
+    a_id!( got.len(), 3 );
+    a_id!( got.as_slice(), &[ mod1::Number( 1 ), mod1::Number( 2 ), mod1::Number( 3 ) ] );
+    let got : mod1::Numbers< i32 > = TheModule::From_2::from_2( mod1::Number( 1 ), mod1::Number( 2 ) );
+    a_id!( got.0, vec![ mod1::Number( 1 ), mod1::Number( 2 ) ] );
+  }
+
+  //
+
+  fn by_element()
+  {
+    use std::collections::HashSet;
+    use std::collections::BTreeSet;
+
+    /* test.case( "pair" ) */
+    a_id!( mod1::Version( 1, 2 ), mod1::Version( 1, 2 ) );
+    a_true!( mod1::Version( 1, 2 ) < mod1::Version( 1, 3 ) );
+    a_true!( mod1::Version( 2, 0 ) > mod1::Version( 1, 3 ) );
+    let got : BTreeSet< _ > = [ mod1::Version( 2, 0 ), mod1::Version( 1, 3 ), mod1::Version( 1, 2 ) ].into_iter().collect();
+    let exp = vec![ mod1::Version( 1, 2 ), mod1::Version( 1, 3 ), mod1::Version( 2, 0 ) ];
+    a_id!( got.into_iter().collect::< Vec< _ > >(), exp );
+
+    /* test.case( "elements of different types" ) */
+    a_true!( mod1::Version( "a", 2.0 ) < mod1::Version( "b", 1.0 ) );
+    assert!( implements!( mod1::Version( 1, 2.0 ) => PartialOrd ) );
+    assert!( !implements!( mod1::Version( 1, 2.0 ) => Eq ) );
+
+    /* test.case( "many" ) */
+    let got : HashSet< _ > = [ mod1::Path::< &str >::from( [ "a", "b" ] ), mod1::Path::< &str >::from( [ "a", "b" ] ), mod1::Path::< &str >::from( [ "a" ] ) ].into_iter().collect();
+    a_id!( got.len(), 2 );
+    a_true!( got.contains( &mod1::Path::< &str >::from( [ "a" ] ) ) );
+    a_true!( mod1::Path::< &str >::from( [ "a" ] ) < mod1::Path::< &str >::from( [ "a", "b" ] ) );
+  }
+
+}
+
+//
+
+tests_index!
+{
+  single_where,
+  homopair_where,
+  pair_shared_parameter,
+  single_lifetime,
+  many_where,
+  by_element,
+}
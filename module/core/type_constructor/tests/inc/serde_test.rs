@@ -0,0 +1,107 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+mod mod1
+{
+  use super::*;
+
+  TheModule::types_proc!
+  {
+
+    ///
+    /// Single serialized as its element.
+    ///
+
+    #[ derive( Debug, Clone, PartialEq ) ]
+    pub single Id : < T >;
+
+    ///
+    /// Pair serialized as tuple.
+    ///
+
+    #[ derive( Debug, Clone, PartialEq ) ]
+    pub pair Point : f32, f64;
+
+    ///
+    /// Homopair serialized as tuple.
+    ///
+
+    #[ derive( Debug, Clone, PartialEq ) ]
+    pub pair Range : < T >;
+
+    ///
+    /// Many serialized as sequence.
+    ///
+
+    #[ derive( Debug, Clone, PartialEq ) ]
+    pub many Names : std::string::String;
+
+  }
+
+}
+
+//
+
+tests_impls!
+{
+
+  fn single_transparent()
+  {
+    let src = mod1::Id( 13 );
+    let got = serde_json::to_string( &src ).unwrap();
+    a_id!( got, "13" );
+    let got : mod1::Id< i32 > = serde_json::from_str( "13" ).unwrap();
+    a_id!( got, src );
+
+    let src = mod1::Id( String::from( "abc" ) );
+    let got = serde_json::to_string( &src ).unwrap();
+    a_id!( got, r#""abc""# );
+    let got : mod1::Id< String > = serde_json::from_str( &got ).unwrap();
+    a_id!( got, src );
+  }
+
+  //
+
+  fn pair_tuple()
+  {
+    let src = mod1::Point( 1.5, 2.5 );
+    let got = serde_json::to_string( &src ).unwrap();
+    a_id!( got, "[1.5,2.5]" );
+    let got : mod1::Point = serde_json::from_str( &got ).unwrap();
+    a_id!( got, src );
+
+    let src = mod1::Range( 1, 3 );
+    let got = serde_json::to_string( &src ).unwrap();
+    a_id!( got, "[1,3]" );
+    let got : mod1::Range< u8 > = serde_json::from_str( &got ).unwrap();
+    a_id!( got, src );
+
+    /* test.case( "wrong length" ) */
+    let got = serde_json::from_str::< mod1::Range< u8 > >( "[1,2,3]" );
+    a_true!( got.is_err() );
+  }
+
+  //
+
+  fn many_sequence()
+  {
+    let src = mod1::Names::from( [ "a", "b" ] );
+    let got = serde_json::to_string( &src ).unwrap();
+    a_id!( got, r#"["a","b"]"# );
+    let got : mod1::Names = serde_json::from_str( &got ).unwrap();
+    a_id!( got, src );
+
+    let got : mod1::Names = serde_json::from_str( "[]" ).unwrap();
+    a_id!( got.len(), 0 );
+  }
+
+}
+
+//
+
+tests_index!
+{
+  single_transparent,
+  pair_tuple,
+  many_sequence,
+}
@@ -0,0 +1,36 @@
+// #![ cfg_attr( feature = "no_std", no_std ) ]
+#![ doc( html_logo_url = "https://raw.githubusercontent.com/Wandalen/wTools/master/asset/img/logo_v3_trans_square.png" ) ]
+#![ doc( html_favicon_url = "https://raw.githubusercontent.com/Wandalen/wTools/alpha/asset/img/logo_v3_trans_square_icon_small_v2.ico" ) ]
+#![ doc( html_root_url = "https://docs.rs/type_constructor_meta/latest/type_constructor_meta/" ) ]
+// #![ deny( rust_2018_idioms ) ]
+// #![ deny( missing_debug_implementations ) ]
+// #![ deny( missing_docs ) ]
+
+//!
+//! Procedural macro of type constructors, like Single, Pair, Many.
+//!
+
+#![ doc = include_str!( concat!( env!( "CARGO_MANIFEST_DIR" ), "/", "Readme.md" ) ) ]
+
+#[ cfg( feature = "enabled" ) ]
+mod meta_impl;
+
+///
+/// Type constructors `single`, `pair` and `many` with the syntax of macro `type_constructor::types!`.
+///
+/// Input starts with path to crate `type_constructor` followed by `;`, what is done by macro `types_proc!` of `type_constructor`.
+/// Attributes, including derives and documentation, are passed to the generated structure. Where-clause written after
+/// the element is applied to the structure and all its implementations.
+///
+
+#[ cfg( feature = "enabled" ) ]
+#[ proc_macro ]
+pub fn types( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
+{
+  let result = meta_impl::types( input );
+  match result
+  {
+    Ok( stream ) => stream.into(),
+    Err( err ) => err.to_compile_error().into(),
+  }
+}
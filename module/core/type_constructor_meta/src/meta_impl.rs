@@ -0,0 +1,1132 @@
+
+use macro_tools::prelude::*;
+use macro_tools::{ quote_spanned, Token };
+use macro_tools::proc_macro2::TokenStream;
+use macro_tools::syn::parse::{ Parse, ParseStream };
+pub type Result< T > = std::result::Result< T, syn::Error >;
+
+///
+/// Kind of type constructor.
+///
+
+#[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+enum Kind
+{
+  Single,
+  Pair,
+  Many,
+}
+
+impl Kind
+{
+  /// Name of the type constructor for messages.
+  fn name( self ) -> &'static str
+  {
+    match self
+    {
+      Kind::Single => "Single",
+      Kind::Pair => "Pair",
+      Kind::Many => "Many",
+    }
+  }
+}
+
+/// Traits which attribute `#[ by_element( .. ) ]` could implement.
+const BY_ELEMENT : &[ &str ] = &[ "PartialEq", "Eq", "PartialOrd", "Ord", "Hash" ];
+
+///
+/// Element written as a path, like `std::sync::Arc< T : Copy >` or `f32`.
+///
+
+struct ElementPath
+{
+  leading_colon : Option< Token![ :: ] >,
+  segments : syn::punctuated::Punctuated< syn::Ident, Token![ :: ] >,
+  generics : syn::Generics,
+}
+
+impl Parse for ElementPath
+{
+  fn parse( input : ParseStream< '_ > ) -> Result< Self >
+  {
+    let leading_colon = input.parse()?;
+    let mut segments = syn::punctuated::Punctuated::new();
+    segments.push_value( input.parse()? );
+    while input.peek( Token![ :: ] )
+    {
+      segments.push_punct( input.parse()? );
+      segments.push_value( input.parse()? );
+    }
+    let generics = input.parse()?;
+    Ok( Self { leading_colon, segments, generics } )
+  }
+}
+
+impl ElementPath
+{
+  /// Type of the element : the path with names of its parameters instead of parameters with bounds.
+  fn ty( &self ) -> syn::Type
+  {
+    let leading_colon = &self.leading_colon;
+    let segments = &self.segments;
+    let args : Vec< TokenStream > = self.generics.params.iter().map( param_arg ).collect();
+    if args.is_empty()
+    {
+      parse_qt!{ #leading_colon #segments }
+    }
+    else
+    {
+      parse_qt!{ #leading_colon #segments < #( #args ),* > }
+    }
+  }
+}
+
+///
+/// Element or elements of the type.
+///
+
+enum Elements
+{
+  /// Each parameter is an element, like `< T1, T2 >`.
+  Parameters( syn::Generics ),
+  /// Each path is an element, like `f32, std::sync::Arc< T : Copy >`.
+  Paths( Vec< ElementPath > ),
+}
+
+///
+/// Definition of a single type : `pub single MySingle : std::sync::Arc< T : Copy > where T : Default;`.
+///
+
+struct TypeItem
+{
+  attrs : Vec< syn::Attribute >,
+  /// Traits implemented by comparing and hashing elements, listed by attribute `#[ by_element( .. ) ]`.
+  by_element : Vec< syn::Ident >,
+  vis : syn::Visibility,
+  kind : Kind,
+  kind_ident : syn::Ident,
+  name : syn::Ident,
+  elements : Elements,
+  where_clause : Option< syn::WhereClause >,
+}
+
+impl Parse for TypeItem
+{
+  fn parse( input : ParseStream< '_ > ) -> Result< Self >
+  {
+    let mut attrs = input.call( syn::Attribute::parse_outer )?;
+    let mut by_element = vec![];
+    for attr in attrs.iter().filter( | attr | attr.path.is_ident( "by_element" ) )
+    {
+      let traits = attr.parse_args_with( syn::punctuated::Punctuated::< syn::Ident, Token![ , ] >::parse_terminated )?;
+      for ident in traits
+      {
+        if !BY_ELEMENT.contains( &ident.to_string().as_str() )
+        {
+          return Err( syn_err!
+          (
+            ident,
+            "Trait `{}` could not be implemented by elements. Expects some of : `{}`",
+            ident,
+            BY_ELEMENT.join( "`, `" )
+          ));
+        }
+        by_element.push( ident );
+      }
+    }
+    attrs.retain( | attr | !attr.path.is_ident( "by_element" ) );
+    let vis = input.parse()?;
+    let kind_ident : syn::Ident = input.parse()?;
+    let kind = match kind_ident.to_string().as_str()
+    {
+      "single" => Kind::Single,
+      "pair" => Kind::Pair,
+      "many" => Kind::Many,
+      _ => return Err( syn_err!
+      (
+        kind_ident,
+        "Unknown type constructor `{}`. Expects one of : `single`, `pair`, `many`.\nFor example : `pub single MySingle : std::sync::Arc< T : Copy >;`",
+        kind_ident
+      )),
+    };
+    let name = input.parse()?;
+    input.parse::< Token![ : ] >()?;
+
+    let elements = if input.peek( Token![ < ] )
+    {
+      Elements::Parameters( input.parse()? )
+    }
+    else
+    {
+      let mut paths = vec![ input.parse()? ];
+      while input.peek( Token![ , ] )
+      {
+        input.parse::< Token![ , ] >()?;
+        if input.is_empty() || input.peek( Token![ ; ] ) || input.peek( Token![ where ] )
+        {
+          break;
+        }
+        paths.push( input.parse()? );
+      }
+      Elements::Paths( paths )
+    };
+
+    let where_clause = if input.peek( Token![ where ] ) { Some( input.parse()? ) } else { None };
+    if !input.is_empty()
+    {
+      input.parse::< Token![ ; ] >()?;
+    }
+
+    Ok( Self { attrs, by_element, vis, kind, kind_ident, name, elements, where_clause } )
+  }
+}
+
+///
+/// Input of the macro : path to crate `type_constructor` followed by `;` and definitions of types.
+///
+
+struct TypesInput
+{
+  krate : TokenStream,
+  items : Vec< TypeItem >,
+}
+
+impl Parse for TypesInput
+{
+  fn parse( input : ParseStream< '_ > ) -> Result< Self >
+  {
+    let mut krate = TokenStream::new();
+    while !input.peek( Token![ ; ] )
+    {
+      if input.is_empty()
+      {
+        return Err( input.error( "Expects path to crate `type_constructor` followed by `;`. Don't use the macro directly, use `type_constructor::types_proc!` instead" ) );
+      }
+      krate.extend( std::iter::once( input.parse::< proc_macro2::TokenTree >()? ) );
+    }
+    input.parse::< Token![ ; ] >()?;
+
+    let mut items = vec![];
+    loop
+    {
+      while input.peek( Token![ ; ] )
+      {
+        input.parse::< Token![ ; ] >()?;
+      }
+      if input.is_empty()
+      {
+        break;
+      }
+      items.push( input.parse()? );
+    }
+    Ok( Self { krate, items } )
+  }
+}
+
+//
+
+pub fn types( input : proc_macro::TokenStream ) -> Result< proc_macro2::TokenStream >
+{
+  let input = syn::parse::< TypesInput >( input )?;
+  let krate = &input.krate;
+  let mut result = TokenStream::new();
+  for item in &input.items
+  {
+    result.extend( item_generate( krate, item )? );
+  }
+  Ok( result )
+}
+
+/// Argument for a parameter of generics : the parameter without its bounds.
+fn param_arg( param : &syn::GenericParam ) -> TokenStream
+{
+  match param
+  {
+    syn::GenericParam::Type( param ) => { let ident = &param.ident; qt!{ #ident } },
+    syn::GenericParam::Lifetime( param ) => { let lifetime = &param.lifetime; qt!{ #lifetime } },
+    syn::GenericParam::Const( param ) => { let ident = &param.ident; qt!{ #ident } },
+  }
+}
+
+/// Name of a parameter of generics.
+fn param_name( param : &syn::GenericParam ) -> String
+{
+  param_arg( param ).to_string()
+}
+
+///
+/// Generics of the structure and its elements, checking number of elements the kind of type constructor may have.
+///
+
+fn generics_and_elements( item : &TypeItem ) -> Result< ( syn::Generics, Vec< syn::Type >, bool ) >
+{
+  let kind = item.kind;
+  let max = if kind == Kind::Pair { 2 } else { 1 };
+  match &item.elements
+  {
+    Elements::Parameters( generics ) =>
+    {
+      if let Some( param ) = generics.params.iter().nth( max )
+      {
+        return Err( if kind == Kind::Pair
+        {
+          syn_err!( param, "Parametrized element should be pair and have either two or single elements" )
+        }
+        else
+        {
+          syn_err!( param, "Parametrized element should be single, because {} has only one element", kind.name() )
+        });
+      }
+      let mut elements = vec![];
+      for param in &generics.params
+      {
+        match param
+        {
+          syn::GenericParam::Type( param ) => { let ident = &param.ident; elements.push( parse_qt!{ #ident } ) },
+          _ => return Err( syn_err!( param, "Expects parameter of type, because each parameter in `< .. >` is an element of {}", kind.name() ) ),
+        }
+      }
+      if elements.is_empty()
+      {
+        return Err( syn_err!( generics, "Expects at least one parameter of type, like `< T >`" ) );
+      }
+      Ok( ( generics.clone(), elements, true ) )
+    },
+    Elements::Paths( paths ) =>
+    {
+      if let Some( path ) = paths.get( max )
+      {
+        let segments = &path.segments;
+        return Err( if kind == Kind::Pair
+        {
+          syn_err!( segments, "Element should be pair and have either two or single elements" )
+        }
+        else
+        {
+          syn_err!( segments, "Element should be single, because {} has only one element", kind.name() )
+        });
+      }
+      // parameters of all elements, each parameter once with bounds of all its occurrences
+      let mut generics = syn::Generics::default();
+      for path in paths
+      {
+        for param in &path.generics.params
+        {
+          let name = param_name( param );
+          match generics.params.iter_mut().find( | existing | param_name( existing ) == name )
+          {
+            Some( syn::GenericParam::Type( existing ) ) =>
+            {
+              if let syn::GenericParam::Type( param ) = param
+              {
+                if existing.colon_token.is_none()
+                {
+                  existing.colon_token = param.colon_token;
+                }
+                existing.bounds.extend( param.bounds.iter().cloned() );
+              }
+            },
+            Some( _ ) => {},
+            None => generics.params.push( param.clone() ),
+          }
+        }
+      }
+      if !generics.params.is_empty()
+      {
+        generics.lt_token = Some( Default::default() );
+        generics.gt_token = Some( Default::default() );
+      }
+      let elements = paths.iter().map( ElementPath::ty ).collect();
+      Ok( ( generics, elements, false ) )
+    },
+  }
+}
+
+///
+/// Generate the structure and its implementations.
+///
+
+fn item_generate( krate : &TokenStream, item : &TypeItem ) -> Result< TokenStream >
+{
+  let ( mut generics, elements, parametrized ) = generics_and_elements( item )?;
+  generics.where_clause = item.where_clause.clone();
+
+  let attrs = &item.attrs;
+  let vis = &item.vis;
+  let name = &item.name;
+  let ( _, ty_generics, where_clause ) = generics.split_for_impl();
+  let mut fields : Vec< TokenStream > = elements.iter().map( | element | match item.kind
+  {
+    Kind::Many => qt!{ #krate::_Vec< #element > },
+    _ => qt!{ #element },
+  }).collect();
+  if item.kind == Kind::Pair && elements.len() == 1
+  {
+    fields.push( fields[ 0 ].clone() );
+  }
+
+  let structure = qt!
+  {
+    #( #attrs )*
+    #vis struct #name #generics ( #( pub #fields ),* ) #where_clause;
+  };
+
+  let context = Context
+  {
+    krate,
+    generics : &generics,
+    self_ty : qt!{ #name #ty_generics },
+    parametrized,
+  };
+
+  let by_element = by_element_impls( &context, &item.by_element, &fields );
+  let serde = if cfg!( feature = "serde" ) { serde_impls( &context, item.kind, &fields ) } else { qt!{} };
+  let structure = qt!{ #structure #by_element #serde };
+
+  let result = match item.kind
+  {
+    Kind::Single => { let impls = single_impls( &context, &elements[ 0 ] ); qt!{ #structure #impls } },
+    Kind::Pair if elements.len() == 1 => { let impls = homopair_impls( &context, &elements[ 0 ] ); qt!{ #structure #impls } },
+    Kind::Pair => { let impls = pair_impls( &context, &elements[ 0 ], &elements[ 1 ] ); qt!{ #structure #impls } },
+    Kind::Many =>
+    {
+      let impls = many_impls( &context, &elements[ 0 ] );
+      // `many` is not available without allocator, then `_if_many` reports it
+      let if_many = quote_spanned!{ item.kind_ident.span() => _if_many };
+      qt!
+      {
+        #krate::#if_many!
+        {
+          #structure
+          #impls
+        }
+      }
+    },
+  };
+
+  Ok( result )
+}
+
+///
+/// What implementations of a type have in common.
+///
+
+struct Context< 'a >
+{
+  krate : &'a TokenStream,
+  generics : &'a syn::Generics,
+  self_ty : TokenStream,
+  /// Is each element a parameter, like `< T >`, rather than a path, like `std::sync::Arc< T >`.
+  parametrized : bool,
+}
+
+impl Context< '_ >
+{
+
+  /// Generics of implementation with additional parameters of type or lifetimes, like `'de`.
+  fn impl_generics( &self, extra : &[ &str ] ) -> TokenStream
+  {
+    let mut generics = self.generics.clone();
+    for extra in extra
+    {
+      if extra.starts_with( '\'' )
+      {
+        let lifetime = syn::Lifetime::new( extra, proc_macro2::Span::call_site() );
+        generics.params.insert( 0, parse_qt!{ #lifetime } );
+      }
+      else
+      {
+        let ident = syn::Ident::new( extra, proc_macro2::Span::call_site() );
+        generics.params.push( parse_qt!{ #ident } );
+      }
+    }
+    let ( impl_generics, _, _ ) = generics.split_for_impl();
+    qt!{ #impl_generics }
+  }
+
+  /// Where-clause of the type with additional predicates.
+  fn where_clause( &self, extra : TokenStream ) -> TokenStream
+  {
+    let predicates = self.generics.where_clause.iter().flat_map( | where_clause | where_clause.predicates.iter() );
+    qt!{ where #( #predicates , )* #extra }
+  }
+
+  /// Where-clause requiring the bound from each field. Bounds are higher-ranked, so a field of a concrete type which does not
+  /// satisfy the bound leaves the implementation unusable instead of failing compilation of the type.
+  fn where_fields( &self, fields : &[ TokenStream ], bound : TokenStream ) -> TokenStream
+  {
+    self.where_clause( qt!{ #( for< '__x > #fields : #bound, )* } )
+  }
+
+}
+
+fn single_impls( c : &Context< '_ >, element : &syn::Type ) -> TokenStream
+{
+  let krate = c.krate;
+  let self_ty = &c.self_ty;
+  let ig = c.impl_generics( &[] );
+  let wc = c.where_clause( qt!{} );
+  let wc_clone = c.where_clause( qt!{ #element : Clone, } );
+
+  // if element is a parameter, it could be a reference itself, so only reference to the element is accepted,
+  // also the element could not be converted from the type because of orphan rules
+  let from_ref_and_path = if c.parametrized
+  {
+    qt!
+    {
+      impl #ig From< &#element > for #self_ty #wc_clone
+      {
+        #[ inline ]
+        fn from( src : &#element ) -> Self
+        {
+          Self( src.clone() )
+        }
+      }
+    }
+  }
+  else
+  {
+    let ig_from_ref = c.impl_generics( &[ "__FromRef" ] );
+    let wc_from_ref = c.where_clause( qt!{ __FromRef : Clone, Self : From< __FromRef >, } );
+    qt!
+    {
+      impl #ig_from_ref From< &__FromRef > for #self_ty #wc_from_ref
+      {
+        #[ inline ]
+        fn from( src : &__FromRef ) -> Self
+        {
+          From::from( ( *src ).clone() )
+        }
+      }
+
+      impl #ig From< #self_ty > for #element #wc
+      {
+        #[ inline ]
+        fn from( src : #self_ty ) -> Self
+        {
+          src.0
+        }
+      }
+
+      #krate::_if_from!
+      {
+        impl #ig #krate::From_1< #element > for #self_ty #wc
+        {
+          #[ inline ]
+          fn from_1( _0 : #element ) -> Self
+          {
+            Self( _0 )
+          }
+        }
+      }
+    }
+  };
+
+  qt!
+  {
+
+    impl #ig core::ops::Deref for #self_ty #wc
+    {
+      type Target = #element;
+      #[ inline ]
+      fn deref( &self ) -> &Self::Target
+      {
+        &self.0
+      }
+    }
+
+    impl #ig core::ops::DerefMut for #self_ty #wc
+    {
+      #[ inline ]
+      fn deref_mut( &mut self ) -> &mut Self::Target
+      {
+        &mut self.0
+      }
+    }
+
+    impl #ig From< #element > for #self_ty #wc
+    {
+      #[ inline ]
+      fn from( src : #element ) -> Self
+      {
+        Self( src )
+      }
+    }
+
+    #from_ref_and_path
+
+    impl #ig From< ( #element, ) > for #self_ty #wc
+    {
+      #[ inline ]
+      fn from( src : ( #element, ) ) -> Self
+      {
+        Self( src.0 )
+      }
+    }
+
+    impl #ig From< #self_ty > for ( #element, ) #wc
+    {
+      #[ inline ]
+      fn from( src : #self_ty ) -> Self
+      {
+        ( src.0, )
+      }
+    }
+
+    impl #ig From< [ #element ; 1 ] > for #self_ty #wc_clone
+    {
+      #[ inline ]
+      fn from( src : [ #element ; 1 ] ) -> Self
+      {
+        Self( src[ 0 ].clone() )
+      }
+    }
+
+    impl #ig From< #self_ty > for [ #element ; 1 ] #wc
+    {
+      #[ inline ]
+      fn from( src : #self_ty ) -> Self
+      {
+        [ src.0 ]
+      }
+    }
+
+    impl #ig From< &[ #element ] > for #self_ty #wc_clone
+    {
+      #[ inline ]
+      fn from( src : &[ #element ] ) -> Self
+      {
+        debug_assert_eq!( src.len(), 1 );
+        Self( src[ 0 ].clone() )
+      }
+    }
+
+    impl #ig #krate::CloneAsTuple< ( #element, ) > for #self_ty #wc_clone
+    {
+      #[ inline ]
+      fn clone_as_tuple( &self ) -> ( #element, )
+      {
+        ( self.0.clone(), )
+      }
+    }
+
+    impl #ig #krate::CloneAsArray< #element, 1 > for #self_ty #wc_clone
+    {
+      #[ inline ]
+      fn clone_as_array( &self ) -> [ #element ; 1 ]
+      {
+        [ self.0.clone() ]
+      }
+    }
+
+    impl #ig #krate::AsTuple< ( #element, ) > for #self_ty #wc
+    {
+      #[ inline ]
+      fn as_tuple( &self ) -> &( #element, )
+      {
+        /* Safety : in case of single elemet it is safe to assume that layout is the same. It does not have to have #[repr(C)]. */
+        unsafe
+        {
+          core::mem::transmute::< _, _ >( self )
+        }
+      }
+    }
+
+    impl #ig #krate::AsArray< #element, 1 > for #self_ty #wc
+    {
+      #[ inline ]
+      fn as_array( &self ) -> &[ #element ; 1 ]
+      {
+        /* Safety : in case of single elemet it is safe to assume that layout is the same. It does not have to have #[repr(C)]. */
+        unsafe
+        {
+          core::mem::transmute::< _, _ >( self )
+        }
+      }
+    }
+
+    impl #ig #krate::AsSlice< #element > for #self_ty #wc
+    {
+      #[ inline ]
+      fn as_slice( &self ) -> &[ #element ]
+      {
+        &#krate::AsArray::as_array( self )[ .. ]
+      }
+    }
+
+  }
+}
+
+fn pair_impls( c : &Context< '_ >, element1 : &syn::Type, element2 : &syn::Type ) -> TokenStream
+{
+  let krate = c.krate;
+  let self_ty = &c.self_ty;
+  let ig = c.impl_generics( &[] );
+  let wc = c.where_clause( qt!{} );
+
+  qt!
+  {
+
+    impl #ig From< ( #element1, #element2 ) > for #self_ty #wc
+    {
+      #[ inline ]
+      fn from( src : ( #element1, #element2 ) ) -> Self
+      {
+        Self( src.0, src.1 )
+      }
+    }
+
+    impl #ig From< #self_ty > for ( #element1, #element2 ) #wc
+    {
+      #[ inline ]
+      fn from( src : #self_ty ) -> Self
+      {
+        ( src.0, src.1 )
+      }
+    }
+
+    #krate::_if_from!
+    {
+      impl #ig #krate::From_2< #element1, #element2 > for #self_ty #wc
+      {
+        #[ inline ]
+        fn from_2( _0 : #element1, _1 : #element2 ) -> Self
+        {
+          Self( _0, _1 )
+        }
+      }
+    }
+
+  }
+}
+
+fn homopair_impls( c : &Context< '_ >, element : &syn::Type ) -> TokenStream
+{
+  let krate = c.krate;
+  let self_ty = &c.self_ty;
+  let ig = c.impl_generics( &[] );
+  let wc = c.where_clause( qt!{} );
+  let wc_clone = c.where_clause( qt!{ #element : Clone, } );
+
+  // single argument of variadic constructor could be a tuple if element is a parameter, what conflicts with `From_1< ( T, ) >`
+  let from_1 = if c.parametrized
+  {
+    qt!{}
+  }
+  else
+  {
+    qt!
+    {
+      impl #ig #krate::From_1< #element > for #self_ty #wc_clone
+      {
+        #[ inline ]
+        fn from_1( _0 : #element ) -> Self
+        {
+          Self( _0.clone(), _0 )
+        }
+      }
+    }
+  };
+
+  qt!
+  {
+
+    impl #ig core::ops::Deref for #self_ty #wc
+    {
+      type Target = ( #element, #element );
+      #[ inline ]
+      fn deref( &self ) -> &Self::Target
+      {
+        #[ cfg( debug_assertions ) ]
+        {
+          let layout1 = core::alloc::Layout::new::< Self >();
+          let layout2 = core::alloc::Layout::new::< Self::Target >();
+          debug_assert_eq!( layout1, layout2 );
+        }
+        /* Safety : in case of homopair it is safe to assume that layout is the same. It does not have to have #[repr(C)]. */
+        unsafe
+        {
+          core::mem::transmute::< _, _ >( self )
+        }
+      }
+    }
+
+    impl #ig core::ops::DerefMut for #self_ty #wc
+    {
+      #[ inline ]
+      fn deref_mut( &mut self ) -> &mut Self::Target
+      {
+        #[ cfg( debug_assertions ) ]
+        {
+          let layout1 = core::alloc::Layout::new::< Self >();
+          let layout2 = core::alloc::Layout::new::< Self::Target >();
+          debug_assert_eq!( layout1, layout2 );
+        }
+        /* Safety : in case of homopair it is safe to assume that layout is the same. It does not have to have #[repr(C)]. */
+        unsafe
+        {
+          core::mem::transmute::< _, _ >( self )
+        }
+      }
+    }
+
+    impl #ig From< ( #element, #element ) > for #self_ty #wc
+    {
+      #[ inline ]
+      fn from( src : ( #element, #element ) ) -> Self
+      {
+        Self( src.0, src.1 )
+      }
+    }
+
+    impl #ig From< #self_ty > for ( #element, #element ) #wc
+    {
+      #[ inline ]
+      fn from( src : #self_ty ) -> Self
+      {
+        ( src.0, src.1 )
+      }
+    }
+
+    impl #ig From< [ #element ; 2 ] > for #self_ty #wc_clone
+    {
+      #[ inline ]
+      fn from( src : [ #element ; 2 ] ) -> Self
+      {
+        Self( src[ 0 ].clone(), src[ 1 ].clone() )
+      }
+    }
+
+    impl #ig From< #self_ty > for [ #element ; 2 ] #wc
+    {
+      #[ inline ]
+      fn from( src : #self_ty ) -> Self
+      {
+        [ src.0, src.1 ]
+      }
+    }
+
+    impl #ig From< &[ #element ] > for #self_ty #wc_clone
+    {
+      #[ inline ]
+      fn from( src : &[ #element ] ) -> Self
+      {
+        debug_assert_eq!( src.len(), 2 );
+        Self( src[ 0 ].clone(), src[ 1 ].clone() )
+      }
+    }
+
+    impl #ig From< #element > for #self_ty #wc_clone
+    {
+      #[ inline ]
+      fn from( src : #element ) -> Self
+      {
+        Self( src.clone(), src )
+      }
+    }
+
+    impl #ig #krate::CloneAsTuple< ( #element, #element ) > for #self_ty #wc_clone
+    {
+      #[ inline ]
+      fn clone_as_tuple( &self ) -> ( #element, #element )
+      {
+        ( self.0.clone(), self.1.clone() )
+      }
+    }
+
+    impl #ig #krate::CloneAsArray< #element, 2 > for #self_ty #wc_clone
+    {
+      #[ inline ]
+      fn clone_as_array( &self ) -> [ #element ; 2 ]
+      {
+        [ self.0.clone(), self.1.clone() ]
+      }
+    }
+
+    impl #ig #krate::AsTuple< ( #element, #element ) > for #self_ty #wc
+    {
+      #[ inline ]
+      fn as_tuple( &self ) -> &( #element, #element )
+      {
+        /* Safety : in case of homopair it is safe to assume that layout is the same. It does not have to have #[repr(C)]. */
+        unsafe
+        {
+          core::mem::transmute::< _, _ >( self )
+        }
+      }
+    }
+
+    impl #ig #krate::AsArray< #element, 2 > for #self_ty #wc
+    {
+      #[ inline ]
+      fn as_array( &self ) -> &[ #element ; 2 ]
+      {
+        /* Safety : in case of homopair it is safe to assume that layout is the same. It does not have to have #[repr(C)]. */
+        unsafe
+        {
+          core::mem::transmute::< _, _ >( self )
+        }
+      }
+    }
+
+    impl #ig #krate::AsSlice< #element > for #self_ty #wc
+    {
+      #[ inline ]
+      fn as_slice( &self ) -> &[ #element ]
+      {
+        &#krate::AsArray::as_array( self )[ .. ]
+      }
+    }
+
+    #krate::_if_from!
+    {
+      #from_1
+
+      impl #ig #krate::From_2< #element, #element > for #self_ty #wc
+      {
+        #[ inline ]
+        fn from_2( _0 : #element, _1 : #element ) -> Self
+        {
+          Self( _0, _1 )
+        }
+      }
+    }
+
+  }
+}
+
+fn many_impls( c : &Context< '_ >, element : &syn::Type ) -> TokenStream
+{
+  let krate = c.krate;
+  let self_ty = &c.self_ty;
+  let ig = c.impl_generics( &[] );
+  let ig_collection = c.impl_generics( &[ "Collection", "IntoT" ] );
+  let wc = c.where_clause( qt!{} );
+  let wc_collection = c.where_clause( qt!{ Collection : IntoIterator< Item = IntoT >, IntoT : Into< #element >, } );
+
+  // variadic constructor of element which is a parameter conflicts with blanket implementations of `From_0` and `From_1< ( T, ) >`
+  let from_n = if c.parametrized
+  {
+    qt!{}
+  }
+  else
+  {
+    qt!
+    {
+      #krate::_if_from!
+      {
+
+        impl #ig #krate::From_0 for #self_ty #wc
+        {
+          #[ inline ]
+          fn from_0() -> Self
+          {
+            Self( #krate::_Vec::< #element >::new() )
+          }
+        }
+
+        impl #ig #krate::From_1< #element > for #self_ty #wc
+        {
+          #[ inline ]
+          fn from_1( _0 : #element ) -> Self
+          {
+            Self( #krate::_vec![ _0 ] )
+          }
+        }
+
+        impl #ig #krate::From_2< #element, #element > for #self_ty #wc
+        {
+          #[ inline ]
+          fn from_2( _0 : #element, _1 : #element ) -> Self
+          {
+            Self( #krate::_vec![ _0, _1 ] )
+          }
+        }
+
+        impl #ig #krate::From_3< #element, #element, #element > for #self_ty #wc
+        {
+          #[ inline ]
+          fn from_3( _0 : #element, _1 : #element, _2 : #element ) -> Self
+          {
+            Self( #krate::_vec![ _0, _1, _2 ] )
+          }
+        }
+
+      }
+    }
+  };
+
+  qt!
+  {
+
+    impl #ig core::ops::Deref for #self_ty #wc
+    {
+      type Target = #krate::_Vec< #element >;
+      #[ inline ]
+      fn deref( &self ) -> &Self::Target
+      {
+        &self.0
+      }
+    }
+
+    impl #ig core::ops::DerefMut for #self_ty #wc
+    {
+      #[ inline ]
+      fn deref_mut( &mut self ) -> &mut Self::Target
+      {
+        &mut self.0
+      }
+    }
+
+    impl #ig_collection From< Collection > for #self_ty #wc_collection
+    {
+      #[ inline ]
+      fn from( src : Collection ) -> Self
+      {
+        Self( src.into_iter().map( | e | e.into() ).collect::< #krate::_Vec< #element > >() )
+      }
+    }
+
+    impl #ig #krate::AsSlice< #element > for #self_ty #wc
+    {
+      #[ inline ]
+      fn as_slice( &self ) -> &[ #element ]
+      {
+        &self[ .. ]
+      }
+    }
+
+    #from_n
+
+  }
+}
+
+///
+/// Implement traits listed by attribute `#[ by_element( .. ) ]` comparing and hashing fields in order, what requires the traits only from elements.
+///
+
+fn by_element_impls( c : &Context< '_ >, traits : &[ syn::Ident ], fields : &[ TokenStream ] ) -> TokenStream
+{
+  let self_ty = &c.self_ty;
+  let ig = c.impl_generics( &[] );
+  let index : Vec< syn::Index > = ( 0..fields.len() ).map( syn::Index::from ).collect();
+
+  traits.iter().map( | ident |
+  {
+    let bound = if ident == "Hash" { qt!{ core::hash::Hash } } else { qt!{ #ident } };
+    let wc = c.where_fields( fields, bound );
+    match ident.to_string().as_str()
+    {
+      "PartialEq" => qt!
+      {
+        impl #ig PartialEq for #self_ty #wc
+        {
+          #[ inline ]
+          fn eq( &self, other : &Self ) -> bool
+          {
+            #( self.#index == other.#index )&&*
+          }
+        }
+      },
+      "Eq" => qt!
+      {
+        impl #ig Eq for #self_ty #wc
+        {
+        }
+      },
+      "PartialOrd" => qt!
+      {
+        impl #ig PartialOrd for #self_ty #wc
+        {
+          #[ inline ]
+          fn partial_cmp( &self, other : &Self ) -> Option< core::cmp::Ordering >
+          {
+            ( #( &self.#index, )* ).partial_cmp( &( #( &other.#index, )* ) )
+          }
+        }
+      },
+      "Ord" => qt!
+      {
+        impl #ig Ord for #self_ty #wc
+        {
+          #[ inline ]
+          fn cmp( &self, other : &Self ) -> core::cmp::Ordering
+          {
+            ( #( &self.#index, )* ).cmp( &( #( &other.#index, )* ) )
+          }
+        }
+      },
+      _ => qt!
+      {
+        impl #ig core::hash::Hash for #self_ty #wc
+        {
+          #[ inline ]
+          fn hash< __H : core::hash::Hasher >( &self, state : &mut __H )
+          {
+            #( core::hash::Hash::hash( &self.#index, state ); )*
+          }
+        }
+      },
+    }
+  }).collect()
+}
+
+///
+/// Implement `serde::Serialize` and `serde::Deserialize` : single is serialized as its element, pair as tuple, many as sequence.
+///
+
+fn serde_impls( c : &Context< '_ >, kind : Kind, fields : &[ TokenStream ] ) -> TokenStream
+{
+  let krate = c.krate;
+  let serde = qt!{ #krate::dependency::serde };
+  let self_ty = &c.self_ty;
+  let ig = c.impl_generics( &[] );
+  let ig_de = c.impl_generics( &[ "'__de" ] );
+  let wc = c.where_fields( fields, qt!{ #serde::Serialize } );
+  let wc_de = c.where_fields( fields, qt!{ #serde::Deserialize< '__de > } );
+
+  let ( serialized, deserialize ) = if kind == Kind::Pair
+  {
+    let f0 = &fields[ 0 ];
+    let f1 = &fields[ 1 ];
+    (
+      qt!{ &( &self.0, &self.1 ) },
+      qt!{ < ( #f0, #f1 ) as #serde::Deserialize< '__de > >::deserialize( deserializer ).map( | ( _0, _1 ) | Self( _0, _1 ) ) },
+    )
+  }
+  else
+  {
+    let f0 = &fields[ 0 ];
+    (
+      qt!{ &self.0 },
+      qt!{ < #f0 as #serde::Deserialize< '__de > >::deserialize( deserializer ).map( Self ) },
+    )
+  };
+
+  qt!
+  {
+
+    impl #ig #serde::Serialize for #self_ty #wc
+    {
+      #[ inline ]
+      fn serialize< __S >( &self, serializer : __S ) -> core::result::Result< __S::Ok, __S::Error >
+      where
+        __S : #serde::Serializer,
+      {
+        #serde::Serialize::serialize( #serialized, serializer )
+      }
+    }
+
+    impl #ig_de #serde::Deserialize< '__de > for #self_ty #wc_de
+    {
+      #[ inline ]
+      fn deserialize< __D >( deserializer : __D ) -> core::result::Result< Self, __D::Error >
+      where
+        __D : #serde::Deserializer< '__de >,
+      {
+        #deserialize
+      }
+    }
+
+  }
+}
@@ -121,83 +121,84 @@ pub( crate ) mod private
   // }
 
   ///
-  /// Constructor with two arguments.
+  /// Define traits of constructors with N arguments and implement [From_1] for tuples of N elements for all types implementing them.
+  ///
+  /// Each line of the listing defines constructor with one more argument than the previous line, the argument is given by the line.
   ///
 
-  #[ allow( non_camel_case_types ) ]
-  pub trait From_2< Arg1, Arg2 >
-  where
-    Self : Sized,
+  macro_rules! from_n
   {
-    // /// Constructor with two arguments.
-    // fn from( arg1 : Arg1, arg2 : Arg2 ) -> Self
-    // {
-    //   Self::from_2( arg1, arg2 )
-    // }
-    /// Constructor with two arguments.
-    fn from_2( arg1 : Arg1, arg2 : Arg2 ) -> Self;
-  }
 
-  impl< T1, T2, All > From_1< ( T1, T2 ) > for All
-  where
-    All : From_2< T1, T2 >,
-  {
-    fn from_1( arg : ( T1, T2 ) ) -> Self
+    (
+      @define $Trait : ident, $method : ident, $doc : literal,
+      $( $Arg : ident $arg : ident $index : tt ),+ $(,)?
+    )
+    =>
     {
-      From_2::< T1, T2 >::from_2( arg.0, arg.1 )
-    }
-  }
 
-  ///
-  /// Constructor with three arguments.
-  ///
+      #[ doc = $doc ]
+      #[ allow( non_camel_case_types ) ]
+      pub trait $Trait< $( $Arg ),+ >
+      where
+        Self : Sized,
+      {
+        #[ doc = $doc ]
+        #[ allow( clippy::too_many_arguments ) ]
+        fn $method( $( $arg : $Arg ),+ ) -> Self;
+      }
+
+      impl< $( $Arg, )+ All > From_1< ( $( $Arg, )+ ) > for All
+      where
+        All : $Trait< $( $Arg ),+ >,
+      {
+        fn from_1( arg : ( $( $Arg, )+ ) ) -> Self
+        {
+          $Trait::< $( $Arg ),+ >::$method( $( arg.$index ),+ )
+        }
+      }
 
-  #[ allow( non_camel_case_types ) ]
-  pub trait From_3< Arg1, Arg2, Arg3 >
-  where
-    Self : Sized,
-  {
-    // /// Constructor with three arguments.
-    // fn from( arg1 : Arg1, arg2 : Arg2, arg3 : Arg3 ) -> Self
-    // {
-    //   Self::from_3( arg1, arg2, arg3 )
-    // }
-    /// Constructor with three arguments.
-    fn from_3( arg1 : Arg1, arg2 : Arg2, arg3 : Arg3 ) -> Self;
-  }
+    };
 
-  impl< T1, T2, T3, All > From_1< ( T1, T2, T3 ) > for All
-  where
-    All : From_3< T1, T2, T3 >,
-  {
-    fn from_1( arg : ( T1, T2, T3 ) ) -> Self
+    (
+      [ $( $Arg : ident $arg : ident $index : tt )+ ]
+    )
+    =>
     {
-      From_3::< T1, T2, T3 >::from_3( arg.0, arg.1, arg.2 )
-    }
+    };
+
+    (
+      [ $( $Arg : ident $arg : ident $index : tt )+ ]
+      $Trait : ident $method : ident $doc : literal, $NewArg : ident $new_arg : ident $new_index : tt ;
+      $( $Rest : tt )*
+    )
+    =>
+    {
+      from_n!( @define $Trait, $method, $doc, $( $Arg $arg $index, )+ $NewArg $new_arg $new_index );
+      from_n!( [ $( $Arg $arg $index )+ $NewArg $new_arg $new_index ] $( $Rest )* );
+    };
+
   }
 
-//   ///
-//   /// Constructor with four arguments.
-//   ///
-//
-//   #[ allow( non_camel_case_types ) ]
-//   pub trait From_4< Arg1, Arg2, Arg3, Arg4 >
-//   where
-//     Self : Sized,
-//   {
-//     /// Constructor with four arguments.
-//     fn from( arg1 : Arg1, arg2 : Arg2, arg3 : Arg3, arg4 : Arg4 ) -> Self
-//     {
-//       Self::from_4( arg1, arg2, arg3, arg4 )
-//     }
-//     /// Constructor with four arguments.
-//     fn from_4( arg1 : Arg1, arg2 : Arg2, arg3 : Arg3, arg4 : Arg4 ) -> Self;
-//   }
+  from_n!
+  {
+    [ Arg1 arg1 0 ]
+    From_2 from_2 "Constructor with two arguments.", Arg2 arg2 1;
+    From_3 from_3 "Constructor with three arguments.", Arg3 arg3 2;
+    From_4 from_4 "Constructor with four arguments.", Arg4 arg4 3;
+    From_5 from_5 "Constructor with five arguments.", Arg5 arg5 4;
+    From_6 from_6 "Constructor with six arguments.", Arg6 arg6 5;
+    From_7 from_7 "Constructor with seven arguments.", Arg7 arg7 6;
+    From_8 from_8 "Constructor with eight arguments.", Arg8 arg8 7;
+    From_9 from_9 "Constructor with nine arguments.", Arg9 arg9 8;
+    From_10 from_10 "Constructor with ten arguments.", Arg10 arg10 9;
+    From_11 from_11 "Constructor with eleven arguments.", Arg11 arg11 10;
+    From_12 from_12 "Constructor with twelve arguments.", Arg12 arg12 11;
+  }
 
   ///
   /// Variadic constructor.
   ///
-  /// Implement traits [From_0], [From_1] up to MakeN to provide the interface to construct your structure with a different set of arguments.
+  /// Implement traits [From_0], [From_1] up to [From_12] to provide the interface to construct your structure with a different set of arguments.
   /// In this example structure, Struct1 could be constructed either without arguments, with a single argument, or with two arguments.
   /// - Constructor without arguments fills fields with zero.
   /// - Constructor with a single argument sets both fields to the value of the argument.
@@ -278,7 +279,7 @@ pub( crate ) mod private
     )
     =>
     {
-      $crate::wtools::From_0::from_0();
+      $crate::wtools::From_0::from_0()
     };
 
     (
@@ -286,51 +287,16 @@ pub( crate ) mod private
     )
     =>
     {
-      $crate::wtools::From_1::from_1( $Arg1 );
+      $crate::wtools::From_1::from_1( $Arg1 )
     };
 
+    // Several arguments are passed as a tuple, constructor `From_N` is called by implementation of [From_1] for tuple of N elements.
     (
-      $Arg1 : expr, $Arg2 : expr $(,)?
-    )
-    =>
-    {
-      $crate::wtools::From_2::from_2( $Arg1, $Arg2 );
-    };
-
-    (
-      $Arg1 : expr, $Arg2 : expr, $Arg3 : expr $(,)?
-    )
-    =>
-    {
-      $crate::wtools::From_3::from_3( $Arg1, $Arg2, $Arg3 );
-    };
-
-    // (
-    //   $Arg1 : expr, $Arg2 : expr, $Arg3 : expr, $Arg4 : expr $(,)?
-    // )
-    // =>
-    // {
-    //   $crate::wtools::From_4::from_4( $Arg1, $Arg2, $Arg3, $Arg4 );
-    // };
-
-    (
-      $( $Rest : tt )+
+      $( $Arg : expr ),+ $(,)?
     )
     =>
     {
-      compile_error!
-      (
-        concat!
-        (
-          "Variadic constructor supports up to 1 arguments.\n",
-          "Open an issue if you need more.\n",
-          "You passed:\n",
-          stringify!
-          (
-            from!( $( $Rest )+ )
-          )
-        )
-      );
+      $crate::wtools::From_1::from_1( ( $( $Arg, )+ ) )
     };
 
   }
@@ -390,6 +356,15 @@ pub mod prelude
     Into1,
     From_2,
     From_3,
+    From_4,
+    From_5,
+    From_6,
+    From_7,
+    From_8,
+    From_9,
+    From_10,
+    From_11,
+    From_12,
 
     from,
 
@@ -12,3 +12,6 @@ mod variadic_from_manual_beyond_test;
 
 #[ cfg( all( feature = "derive_variadic_from", feature = "type_variadic_from" ) ) ]
 mod variadic_from2_derive;
+
+#[ cfg( all( feature = "derive_variadic_from", feature = "type_variadic_from" ) ) ]
+mod variadic_from_arity_derive;
@@ -0,0 +1,142 @@
+#[ allow( unused_imports ) ]
+use super::*;
+
+/// Derive for the biggest supported number of fields.
+#[ test ]
+fn twelve_fields()
+{
+  #[ allow( unused_imports ) ]
+  use TheModule::exposed::*;
+
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct Struct12
+  {
+    a : i32, b : i32, c : i32, d : i32, e : i32, f : i32,
+    g : i32, h : i32, i : i32, j : i32, k : i32, l : i32,
+  }
+
+  let exp = Struct12{ a : 1, b : 2, c : 3, d : 4, e : 5, f : 6, g : 7, h : 8, i : 9, j : 10, k : 11, l : 12 };
+
+  let got : Struct12 = from!( 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12 );
+  a_id!( got, exp );
+
+  let got : Struct12 = Struct12::from_12( 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12 );
+  a_id!( got, exp );
+
+  let got : Struct12 = from!( ( 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12 ) );
+  a_id!( got, exp );
+
+  let got : Struct12 = ( 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12 ).into();
+  a_id!( got, exp );
+
+  let got : Struct12 = [ 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12 ].into();
+  a_id!( got, exp );
+
+}
+
+/// Every arity is reachable by `from!`.
+#[ test ]
+fn every_arity()
+{
+  #[ allow( unused_imports ) ]
+  use TheModule::exposed::*;
+
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct S1( i32 );
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct S4( i32, i32, i32, i32 );
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct S5( i32, i32, i32, i32, i32 );
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct S6( i32, i32, i32, i32, i32, i32 );
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct S7( i32, i32, i32, i32, i32, i32, i32 );
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct S8( i32, i32, i32, i32, i32, i32, i32, i32 );
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct S9( i32, i32, i32, i32, i32, i32, i32, i32, i32 );
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct S10( i32, i32, i32, i32, i32, i32, i32, i32, i32, i32 );
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct S11( i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32 );
+
+  let got : S1 = from!( 1 );
+  a_id!( got, S1( 1 ) );
+  let got : S1 = ( 1, ).into();
+  a_id!( got, S1( 1 ) );
+  let got : S1 = [ 1 ].into();
+  a_id!( got, S1( 1 ) );
+
+  let got : S4 = from!( 1, 2, 3, 4 );
+  a_id!( got, S4( 1, 2, 3, 4 ) );
+  let got : S5 = from!( 1, 2, 3, 4, 5 );
+  a_id!( got, S5( 1, 2, 3, 4, 5 ) );
+  let got : S6 = from!( 1, 2, 3, 4, 5, 6 );
+  a_id!( got, S6( 1, 2, 3, 4, 5, 6 ) );
+  let got : S7 = from!( 1, 2, 3, 4, 5, 6, 7 );
+  a_id!( got, S7( 1, 2, 3, 4, 5, 6, 7 ) );
+  let got : S8 = from!( 1, 2, 3, 4, 5, 6, 7, 8 );
+  a_id!( got, S8( 1, 2, 3, 4, 5, 6, 7, 8 ) );
+  let got : S9 = from!( 1, 2, 3, 4, 5, 6, 7, 8, 9 );
+  a_id!( got, S9( 1, 2, 3, 4, 5, 6, 7, 8, 9 ) );
+  let got : S10 = from!( 1, 2, 3, 4, 5, 6, 7, 8, 9, 10 );
+  a_id!( got, S10( 1, 2, 3, 4, 5, 6, 7, 8, 9, 10 ) );
+  let got : S11 = from!( 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, );
+  a_id!( got, S11( 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11 ) );
+
+}
+
+/// Tuple of real types of fields, no array if types differ.
+#[ test ]
+fn mixed_types()
+{
+  #[ allow( unused_imports ) ]
+  use TheModule::exposed::*;
+
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct Mixed
+  {
+    name : String,
+    age : u8,
+    score : f32,
+  }
+
+  let exp = Mixed{ name : "x".to_string(), age : 3, score : 0.5 };
+
+  let got : Mixed = from!( "x".to_string(), 3, 0.5 );
+  a_id!( got, exp );
+
+  let got : Mixed = ( "x".to_string(), 3u8, 0.5f32 ).into();
+  a_id!( got, exp );
+
+}
+
+/// Generic parameters are carried through.
+#[ test ]
+fn generics()
+{
+  #[ allow( unused_imports ) ]
+  use TheModule::exposed::*;
+
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct Pair< T >( T, T )
+  where
+    T : Clone;
+
+  #[ derive( Debug, PartialEq, VariadicFrom ) ]
+  struct Tagged< 'a, T : core::fmt::Debug >
+  {
+    tag : &'a str,
+    value : T,
+  }
+
+  let got : Pair< u8 > = from!( 1, 2 );
+  a_id!( got, Pair( 1, 2 ) );
+
+  let got : Pair< &str > = [ "a", "b" ].into();
+  a_id!( got, Pair( "a", "b" ) );
+
+  let got : Tagged< '_, i64 > = ( "t", 13 ).into();
+  a_id!( got, Tagged{ tag : "t", value : 13 } );
+
+}
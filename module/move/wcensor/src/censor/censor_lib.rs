@@ -19,7 +19,7 @@
   /// Result of parsing.
   #[ cfg( not( feature = "no_std" ) ) ]
   layer instruction;
-  /// Properties parsing.
+  /// Properties parsing. Function `parse_from_splits` of properties is used through the layer.
   #[ cfg( not( feature = "no_std" ) ) ]
-  layer props;
+  layer props except { parse_from_splits };
 }